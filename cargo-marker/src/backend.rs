@@ -10,7 +10,7 @@ use crate::error::prelude::*;
 use crate::observability::display::{self, print_stage};
 use crate::observability::prelude::*;
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     ffi::{OsStr, OsString},
     path::PathBuf,
 };
@@ -67,7 +67,7 @@ pub struct CheckInfo {
     pub env: Vec<(&'static str, OsString)>,
 }
 
-pub fn prepare_check(config: &Config) -> Result<CheckInfo> {
+pub fn prepare_check(config: &Config, additional_cargo_args: &[String]) -> Result<CheckInfo> {
     print_stage("compiling lints");
     let lints = lints::build_lints(config)?;
 
@@ -75,6 +75,7 @@ pub fn prepare_check(config: &Config) -> Result<CheckInfo> {
     let mut env = vec![
         ("RUSTC_WORKSPACE_WRAPPER", config.toolchain.driver_path.as_os_str().to_os_string()),
         ("MARKER_LINT_CRATES", to_marker_lint_crates_env(&lints)),
+        ("MARKER_DEPENDENCY_VERSIONS", dependency_versions_env(config, additional_cargo_args).into()),
     ];
     if let Some(toolchain) = &config.toolchain.cargo.toolchain {
         env.push(("RUSTUP_TOOLCHAIN", toolchain.into()));
@@ -119,3 +120,109 @@ pub fn to_marker_lint_crates_env(lints: &[LintCrate]) -> OsString {
         .collect();
     lint_paths.join(OsStr::new(";"))
 }
+
+/// Rustc doesn't know the versions of dependencies. They're therefore collected
+/// from `cargo metadata` and passed to the driver as a JSON object. It maps the
+/// manifest path of every workspace member to the versions of its direct
+/// dependencies, by the crate name of the dependency.
+///
+/// The versions are optional for the driver. An empty object is therefore
+/// returned, if `cargo metadata` fails.
+fn dependency_versions_env(config: &Config, additional_cargo_args: &[String]) -> String {
+    let metadata = match config
+        .toolchain
+        .cargo
+        .metadata()
+        .other_options(metadata_args(additional_cargo_args))
+        .exec()
+    {
+        Ok(metadata) => metadata,
+        Err(err) => {
+            warn!(%err, "Failed to get the dependency versions from `cargo metadata`");
+            return "{}".to_string();
+        },
+    };
+    let Some(resolve) = &metadata.resolve else {
+        return "{}".to_string();
+    };
+
+    let mut versions = BTreeMap::new();
+    for member in &metadata.workspace_members {
+        let Some(node) = resolve.nodes.iter().find(|node| &node.id == member) else {
+            continue;
+        };
+        let deps: BTreeMap<_, _> = node
+            .deps
+            .iter()
+            .filter_map(|dep| {
+                let package = &metadata[&dep.pkg];
+                // Rustc uses the name of the library target, regardless of
+                // renames in the manifest.
+                let lib = package.targets.iter().find(|target| {
+                    target
+                        .kind
+                        .iter()
+                        .any(|kind| matches!(kind.as_str(), "lib" | "rlib" | "dylib" | "proc-macro"))
+                })?;
+                Some((lib.name.replace('-', "_"), package.version.to_string()))
+            })
+            .collect();
+        versions.insert(metadata[member].manifest_path.to_string(), deps);
+    }
+
+    serde_json::to_string(&versions).expect("maps of strings can always be serialized")
+}
+
+/// Selects the cargo args, which affect the workspace or the resolved
+/// dependencies, like `--manifest-path` or `--features`. These are forwarded
+/// to `cargo metadata`.
+fn metadata_args(cargo_args: &[String]) -> Vec<String> {
+    const FLAGS: &[&str] = &[
+        "--all-features",
+        "--no-default-features",
+        "--frozen",
+        "--locked",
+        "--offline",
+    ];
+    const OPTIONS: &[&str] = &["--manifest-path", "--features", "-F"];
+
+    let mut metadata_args = vec![];
+    let mut args = cargo_args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            // The remaining args are passed to rustc
+            break;
+        } else if FLAGS.contains(&arg.as_str()) {
+            metadata_args.push(arg.clone());
+        } else if OPTIONS.contains(&arg.as_str()) {
+            metadata_args.push(arg.clone());
+            metadata_args.extend(args.next().cloned());
+        } else if OPTIONS
+            .iter()
+            .any(|option| arg.strip_prefix(option).is_some_and(|value| value.starts_with('=')))
+        {
+            metadata_args.push(arg.clone());
+        }
+    }
+    metadata_args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_args() {
+        let args = |args: &[&str]| metadata_args(&args.iter().map(ToString::to_string).collect::<Vec<_>>());
+
+        assert!(args(&["--release", "--all-targets"]).is_empty());
+        assert_eq!(
+            args(&["--manifest-path", "duck/Cargo.toml", "--release", "--features=quack"]),
+            ["--manifest-path", "duck/Cargo.toml", "--features=quack"]
+        );
+        assert_eq!(
+            args(&["--locked", "-F", "quack", "--", "--features", "ignored"]),
+            ["--locked", "-F", "quack"]
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use crate::config::{Config, LintDependency};
 use crate::error::prelude::*;
-use camino::Utf8Path;
-use clap::{Args, Parser, Subcommand};
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::collections::HashMap;
 
 /// Marker's CLI interface
@@ -45,6 +45,8 @@ pub enum CliCommand {
     Check(CheckArgs),
     /// Setup the rustc driver for Marker
     Setup(SetupArgs),
+    /// List all `allow` and `expect` attributes, which target `marker::` lints
+    Allows(AllowsArgs),
     /// **UNSTABLE** Setup the specified lint crate for ui tests
     #[command(hide = true)]
     TestSetup(CheckArgs),
@@ -65,6 +67,28 @@ pub struct CheckArgs {
     pub cargo_args: Vec<String>,
 }
 
+#[derive(Args, Debug)]
+#[command(override_usage = "cargo marker allows [OPTIONS] -- <CARGO ARGS>")]
+pub struct AllowsArgs {
+    /// The format of the printed report
+    #[arg(long, value_enum, default_value_t = ReportFormat::Table)]
+    pub format: ReportFormat,
+    /// Writes the report to the given file instead of stdout
+    #[arg(long)]
+    pub output: Option<Utf8PathBuf>,
+
+    #[clap(flatten)]
+    pub check_args: CheckArgs,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// A human readable table
+    Table,
+    /// A JSON array, with one object per lint name in an attribute
+    Json,
+}
+
 #[derive(Args, Debug)]
 pub struct SetupArgs {
     /// Automatically installs the required toolchain using rustup
@@ -128,4 +152,15 @@ mod tests {
             assert!(false, "the `check` subcommand was not detected");
         }
     }
+
+    #[test]
+    fn test_marker_cli_allows() {
+        let cli = MarkerCli::parse_from(["cargo-marker", "allows", "--format", "json", "--", "ducks"]);
+        let Some(CliCommand::Allows(allows_args)) = cli.command else {
+            panic!("the `allows` subcommand was not detected");
+        };
+        assert_eq!(allows_args.format, ReportFormat::Json);
+        assert!(allows_args.output.is_none());
+        assert_eq!(allows_args.check_args.cargo_args, ["ducks"]);
+    }
 }
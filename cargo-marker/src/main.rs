@@ -10,6 +10,7 @@ mod cli;
 mod config;
 mod error;
 mod observability;
+mod suppressions;
 mod utils;
 
 use error::prelude::*;
@@ -17,7 +18,7 @@ use std::{collections::HashMap, ffi::OsString};
 
 use crate::backend::driver::DriverVersionInfo;
 use backend::CheckInfo;
-use cli::{CheckArgs, CliCommand, MarkerCli};
+use cli::{AllowsArgs, CheckArgs, CliCommand, MarkerCli};
 use config::Config;
 use std::process::ExitCode;
 
@@ -51,18 +52,20 @@ fn try_main() -> Result {
             backend::driver::install_driver(args.auto_install_toolchain, rustc_flags)
         },
         Some(CliCommand::Check(args)) => run_check(args, config, CheckKind::Normal),
+        Some(CliCommand::Allows(args)) => run_check(&args.check_args, config, CheckKind::Allows(args)),
         Some(CliCommand::TestSetup(args)) => run_check(args, config, CheckKind::TestSetup),
         None => run_check(&cli.check_args, config, CheckKind::Normal),
     }
 }
 
 #[derive(Debug, Clone, Copy)]
-enum CheckKind {
+enum CheckKind<'a> {
     Normal,
+    Allows(&'a AllowsArgs),
     TestSetup,
 }
 
-fn run_check(args: &CheckArgs, config: Option<Config>, kind: CheckKind) -> Result {
+fn run_check(args: &CheckArgs, config: Option<Config>, kind: CheckKind<'_>) -> Result {
     // determine lints
     let lints: HashMap<_, _> = cli::collect_lint_deps(args)?
         .or_else(|| config.map(|config| config.lints))
@@ -89,11 +92,12 @@ fn run_check(args: &CheckArgs, config: Option<Config>, kind: CheckKind) -> Resul
     };
 
     // Prepare backend
-    let info = backend::prepare_check(&backend_conf)?;
+    let info = backend::prepare_check(&backend_conf, &args.cargo_args)?;
 
     // Run backend
     match kind {
         CheckKind::Normal => backend::run_check(&backend_conf, info, &args.cargo_args),
+        CheckKind::Allows(allows_args) => suppressions::run_report(&backend_conf, info, &args.cargo_args, allows_args),
         CheckKind::TestSetup => print_test_info(&backend_conf, &info),
     }
 }
@@ -0,0 +1,210 @@
+//! This module implements `cargo marker allows`. The driver writes a JSON report
+//! of all `allow` and `expect` attributes targeting `marker::` lints for every
+//! linted crate. These reports are collected, merged and printed here.
+
+use crate::backend::{self, CheckInfo};
+use crate::cli::{AllowsArgs, ReportFormat};
+use crate::error::prelude::*;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use yansi::Paint;
+
+/// A single lint name inside an `allow` or `expect` attribute, as reported by
+/// the driver.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SuppressionEntry {
+    lint: String,
+    level: String,
+    item: String,
+    reason: Option<String>,
+    used: bool,
+    file: String,
+    line: usize,
+    column: usize,
+}
+
+pub fn run_report(config: &backend::Config, mut info: CheckInfo, cargo_args: &[String], args: &AllowsArgs) -> Result {
+    // A new directory is used for every run. The driver tracks the value of the
+    // environment value, which forces a new check of crates that have been
+    // cached by Cargo. This ensures that the report is complete.
+    let report_dir = tempfile::tempdir().context(|| "Failed to create a directory for the suppression report")?;
+    info.env.push((
+        "MARKER_SUPPRESSION_REPORT_DIR",
+        report_dir.path().as_os_str().to_os_string(),
+    ));
+
+    // Lint crates might emit errors, the report should still be displayed in
+    // that case.
+    let check_result = backend::run_check(config, info, cargo_args);
+
+    let entries = read_reports(report_dir.path())?;
+    let report = match args.format {
+        ReportFormat::Table => format_table(&entries),
+        ReportFormat::Json => serde_json::to_string_pretty(&entries).context(|| "Failed to serialize the report")?,
+    };
+    match &args.output {
+        Some(path) => std::fs::write(path, report).context(|| format!("Failed to write the report to {path}"))?,
+        None => println!("{report}"),
+    }
+
+    check_result
+}
+
+/// Reads all reports in the given directory and merges them. A crate can be
+/// checked multiple times, for example, as a library and as a test target.
+/// An attribute is therefore marked as used, if it was used in any of the runs.
+fn read_reports(dir: &Path) -> Result<Vec<SuppressionEntry>> {
+    let mut entries: Vec<SuppressionEntry> = vec![];
+    let files = std::fs::read_dir(dir).context(|| format!("Failed to read the report directory {}", dir.display()))?;
+    for file in files {
+        let path = file
+            .context(|| format!("Failed to read the report directory {}", dir.display()))?
+            .path();
+        let content =
+            std::fs::read_to_string(&path).context(|| format!("Failed to read the report {}", path.display()))?;
+        let report: Vec<SuppressionEntry> =
+            serde_json::from_str(&content).context(|| format!("Failed to parse the report {}", path.display()))?;
+        entries.extend(report);
+    }
+
+    entries.sort_by(|a, b| (&a.file, a.line, a.column, &a.lint).cmp(&(&b.file, b.line, b.column, &b.lint)));
+    entries.dedup_by(|next, prev| {
+        let is_same =
+            (&next.file, next.line, next.column, &next.lint) == (&prev.file, prev.line, prev.column, &prev.lint);
+        if is_same {
+            prev.used |= next.used;
+        }
+        is_same
+    });
+    Ok(entries)
+}
+
+fn format_table(entries: &[SuppressionEntry]) -> String {
+    if entries.is_empty() {
+        return "No `allow` or `expect` attributes targeting `marker::` lints were found".to_string();
+    }
+
+    let header = ["Lint", "Level", "Used", "Reason", "Location", "Item"].map(ToString::to_string);
+    let rows = entries
+        .iter()
+        .map(|entry| {
+            [
+                entry.lint.clone(),
+                entry.level.clone(),
+                if entry.used { "yes" } else { "no" }.to_string(),
+                entry.reason.clone().unwrap_or_else(|| "-".to_string()),
+                format!("{}:{}:{}", entry.file, entry.line, entry.column),
+                entry.item.clone(),
+            ]
+        })
+        .collect_vec();
+
+    let mut widths = header.clone().map(|cell| cell.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |row: &[String; 6]| {
+        row.iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+    let mut table = format!("{}\n", format_row(&header).bold());
+    for row in &rows {
+        table.push_str(&format_row(row));
+        table.push('\n');
+    }
+
+    let unused = entries.iter().filter(|entry| !entry.used).count();
+    let missing_reason = entries.iter().filter(|entry| entry.reason.is_none()).count();
+    table.push_str(&format!(
+        "\n{} suppressions, {unused} didn't suppress anything, {missing_reason} without a reason",
+        entries.len()
+    ));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, line: usize, used: bool, reason: Option<&str>) -> SuppressionEntry {
+        SuppressionEntry {
+            lint: name.to_string(),
+            level: "allow".to_string(),
+            item: "function `main`".to_string(),
+            reason: reason.map(ToString::to_string),
+            used,
+            file: "src/main.rs".to_string(),
+            line,
+            column: 9,
+        }
+    }
+
+    fn write_report(dir: &Path, name: &str, entries: &[SuppressionEntry]) {
+        std::fs::write(dir.join(name), serde_json::to_string(entries).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_read_reports_merges_runs() {
+        let dir = tempfile::tempdir().unwrap();
+        // The same crate, checked as a library and as a test target
+        write_report(
+            dir.path(),
+            "lib-1.json",
+            &[
+                entry("marker::ducks", 10, false, None),
+                entry("marker::geese", 3, false, None),
+            ],
+        );
+        write_report(
+            dir.path(),
+            "lib-2.json",
+            &[
+                entry("marker::ducks", 10, true, None),
+                entry("marker::geese", 3, false, None),
+            ],
+        );
+
+        let entries = read_reports(dir.path()).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].lint, "marker::geese");
+        assert!(!entries[0].used);
+        assert_eq!(entries[1].lint, "marker::ducks");
+        assert!(entries[1].used);
+    }
+
+    #[test]
+    fn test_format_table() {
+        yansi::disable();
+        let table = format_table(&[
+            entry("marker::ducks", 10, true, Some("they quack")),
+            entry("marker::geese", 12, false, None),
+        ]);
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Lint           Level  Used  Reason      Location          Item"
+        );
+        assert_eq!(
+            lines[1],
+            "marker::ducks  allow  yes   they quack  src/main.rs:10:9  function `main`"
+        );
+        assert_eq!(
+            lines[2],
+            "marker::geese  allow  no    -           src/main.rs:12:9  function `main`"
+        );
+        assert_eq!(
+            lines[4],
+            "2 suppressions, 1 didn't suppress anything, 1 without a reason"
+        );
+
+        assert!(format_table(&[]).starts_with("No `allow` or `expect` attributes"));
+    }
+}
@@ -3,10 +3,12 @@
 
 use marker_api::{
     ast::{
+        expr::{Adjustment, ClosureCaptures, DesugarKind},
+        generic::{SemGenericArgs, SemGenericParam, SemPredicate, SemTraitBound},
         item::{Body, ItemKind},
-        ty::SemTyKind,
-        BodyId, ExpnId, ExpnInfo, ExprId, FileInfo, FilePos, ItemId, Span, SpanId, SpanPos, SpanSource, SymbolId,
-        TyDefId,
+        ty::{Layout, SemAdtDef, SemFnSig, SemTyKind},
+        BodyId, Comment, ConstValue, Docs, DriverTyId, ExpnId, ExpnInfo, ExprId, FileInfo, FilePos, GenericId, ItemId,
+        ItemPath, Span, SpanId, SpanPos, SpanSource, SymbolId, TyDefId,
     },
     context::DriverCallbacks,
     diagnostic::{Diagnostic, EmissionNode},
@@ -42,10 +44,33 @@ impl<'ast> DriverContextWrapper<'ast> {
             driver_context: unsafe { &*(self as *const DriverContextWrapper).cast::<()>() },
             lint_level_at,
             emit_diag,
+            lint_suppressed,
             item,
             body,
+            trait_item_for_impl_item,
             resolve_ty_ids,
+            resolve_item_ids,
+            item_path,
+            ty_def_path,
+            eval_const,
+            adt_def,
+            fn_sig,
+            predicates_of,
+            generic_param,
+            layout_of,
+            expr_adjustments,
+            closure_captures,
+            expr_desugaring,
+            comments_in,
+            comments_in_file,
+            comments_before,
             expr_ty,
+            peel_aliases,
+            subst_generics,
+            generic_name,
+            opaque_bounds,
+            coroutine_interior_tys,
+            docs,
             span,
             span_snippet,
             span_source,
@@ -67,6 +92,12 @@ extern "C" fn emit_diag<'a, 'ast>(data: &'ast (), diag: &Diagnostic<'a, 'ast>) {
     unsafe { as_driver_cx(data) }.emit_diag(diag);
 }
 
+// False positive because `EmissionNode` are non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn lint_suppressed<'ast>(data: &'ast (), lint: &'static Lint, node: EmissionNode) {
+    unsafe { as_driver_cx(data) }.lint_suppressed(lint, node);
+}
+
 // False positive because `ItemKind` is non-exhaustive
 #[allow(improper_ctypes_definitions)]
 extern "C" fn item<'ast>(data: &'ast (), id: ItemId) -> FfiOption<ItemKind<'ast>> {
@@ -77,16 +108,119 @@ extern "C" fn body<'ast>(data: &'ast (), id: BodyId) -> &'ast Body<'ast> {
     unsafe { as_driver_cx(data) }.body(id)
 }
 
+extern "C" fn trait_item_for_impl_item<'ast>(data: &'ast (), id: ItemId) -> FfiOption<ItemId> {
+    unsafe { as_driver_cx(data) }.trait_item_for_impl_item(id).into()
+}
+
+extern "C" fn resolve_item_ids<'ast>(data: &'ast (), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, ItemId> {
+    unsafe { as_driver_cx(data) }.resolve_item_ids((&path).into()).into()
+}
+
+extern "C" fn item_path<'ast>(data: &'ast (), id: ItemId) -> &'ast ItemPath<'ast> {
+    unsafe { as_driver_cx(data) }.item_path(id)
+}
+
+extern "C" fn ty_def_path<'ast>(data: &'ast (), id: TyDefId) -> &'ast ItemPath<'ast> {
+    unsafe { as_driver_cx(data) }.ty_def_path(id)
+}
+
 extern "C" fn resolve_ty_ids<'ast>(data: &'ast (), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, TyDefId> {
     unsafe { as_driver_cx(data) }.resolve_ty_ids((&path).into()).into()
 }
 
+extern "C" fn eval_const<'ast>(data: &'ast (), expr: ExprId) -> FfiOption<&'ast ConstValue<'ast>> {
+    unsafe { as_driver_cx(data) }.eval_const(expr).into()
+}
+
+extern "C" fn adt_def<'ast>(data: &'ast (), id: TyDefId) -> FfiOption<&'ast SemAdtDef<'ast>> {
+    unsafe { as_driver_cx(data) }.adt_def(id).into()
+}
+
+extern "C" fn fn_sig<'ast>(data: &'ast (), id: ItemId) -> FfiOption<&'ast SemFnSig<'ast>> {
+    unsafe { as_driver_cx(data) }.fn_sig(id).into()
+}
+
+extern "C" fn predicates_of<'ast>(data: &'ast (), id: ItemId) -> ffi::FfiSlice<'ast, SemPredicate<'ast>> {
+    unsafe { as_driver_cx(data) }.predicates_of(id).into()
+}
+
+extern "C" fn generic_param<'ast>(data: &'ast (), id: GenericId) -> &'ast SemGenericParam<'ast> {
+    unsafe { as_driver_cx(data) }.generic_param(id)
+}
+
+// False positive because `SemTyKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn layout_of<'ast>(data: &'ast (), ty: SemTyKind<'ast>) -> FfiOption<&'ast Layout<'ast>> {
+    unsafe { as_driver_cx(data) }.layout_of(ty).into()
+}
+
+extern "C" fn expr_adjustments<'ast>(data: &'ast (), expr: ExprId) -> ffi::FfiSlice<'ast, Adjustment<'ast>> {
+    unsafe { as_driver_cx(data) }.expr_adjustments(expr).into()
+}
+
+extern "C" fn closure_captures<'ast>(data: &'ast (), expr: ExprId) -> &'ast ClosureCaptures<'ast> {
+    unsafe { as_driver_cx(data) }.closure_captures(expr)
+}
+
+#[allow(improper_ctypes_definitions)]
+extern "C" fn expr_desugaring<'ast>(data: &'ast (), expr: ExprId) -> ffi::FfiOption<DesugarKind> {
+    unsafe { as_driver_cx(data) }.expr_desugaring(expr).into()
+}
+
+extern "C" fn comments_in<'ast>(data: &'ast (), span: &Span<'ast>) -> ffi::FfiSlice<'ast, Comment<'ast>> {
+    unsafe { as_driver_cx(data) }.comments_in(span).into()
+}
+
+extern "C" fn comments_in_file<'ast>(data: &'ast (), span: &Span<'ast>) -> ffi::FfiSlice<'ast, Comment<'ast>> {
+    unsafe { as_driver_cx(data) }.comments_in_file(span).into()
+}
+
+// False positive because `EmissionNode` are non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn comments_before<'ast>(data: &'ast (), node: EmissionNode) -> ffi::FfiSlice<'ast, Comment<'ast>> {
+    unsafe { as_driver_cx(data) }.comments_before(node).into()
+}
+
 // False positive because `SemTyKind` is non-exhaustive
 #[allow(improper_ctypes_definitions)]
 extern "C" fn expr_ty<'ast>(data: &'ast (), expr: ExprId) -> SemTyKind<'ast> {
     unsafe { as_driver_cx(data) }.expr_ty(expr)
 }
 
+// False positive because `SemTyKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn peel_aliases<'ast>(data: &'ast (), ty: SemTyKind<'ast>) -> SemTyKind<'ast> {
+    unsafe { as_driver_cx(data) }.peel_aliases(ty)
+}
+
+// False positive because `SemTyKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn subst_generics<'ast>(
+    data: &'ast (),
+    ty: SemTyKind<'ast>,
+    args: &SemGenericArgs<'ast>,
+) -> SemTyKind<'ast> {
+    unsafe { as_driver_cx(data) }.subst_generics(ty, args)
+}
+
+extern "C" fn generic_name<'ast>(data: &'ast (), id: GenericId) -> SymbolId {
+    unsafe { as_driver_cx(data) }.generic_name(id)
+}
+
+extern "C" fn opaque_bounds<'ast>(data: &'ast (), ty: DriverTyId) -> ffi::FfiSlice<'ast, SemTraitBound<'ast>> {
+    unsafe { as_driver_cx(data) }.opaque_bounds(ty).into()
+}
+
+extern "C" fn coroutine_interior_tys<'ast>(data: &'ast (), ty: DriverTyId) -> ffi::FfiSlice<'ast, SemTyKind<'ast>> {
+    unsafe { as_driver_cx(data) }.coroutine_interior_tys(ty).into()
+}
+
+// False positive because `EmissionNode` are non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn docs<'ast>(data: &'ast (), node: EmissionNode) -> &'ast Docs<'ast> {
+    unsafe { as_driver_cx(data) }.docs(node)
+}
+
 extern "C" fn span<'ast>(data: &'ast (), span_id: SpanId) -> &'ast Span<'ast> {
     unsafe { as_driver_cx(data) }.span(span_id)
 }
@@ -131,13 +265,36 @@ unsafe fn as_driver_cx<'ast>(data: &'ast ()) -> &'ast dyn DriverContext<'ast> {
 pub trait DriverContext<'ast> {
     fn lint_level_at(&'ast self, lint: &'static Lint, node: EmissionNode) -> Level;
     fn emit_diag(&'ast self, diag: &Diagnostic<'_, 'ast>);
+    fn lint_suppressed(&'ast self, lint: &'static Lint, node: EmissionNode);
 
     fn item(&'ast self, api_id: ItemId) -> Option<ItemKind<'ast>>;
     fn body(&'ast self, api_id: BodyId) -> &'ast Body<'ast>;
+    fn trait_item_for_impl_item(&'ast self, api_id: ItemId) -> Option<ItemId>;
 
     fn resolve_ty_ids(&'ast self, path: &str) -> &'ast [TyDefId];
+    fn resolve_item_ids(&'ast self, path: &str) -> &'ast [ItemId];
+    fn item_path(&'ast self, api_id: ItemId) -> &'ast ItemPath<'ast>;
+    fn ty_def_path(&'ast self, api_id: TyDefId) -> &'ast ItemPath<'ast>;
+    fn eval_const(&'ast self, expr: ExprId) -> Option<&'ast ConstValue<'ast>>;
+    fn adt_def(&'ast self, api_id: TyDefId) -> Option<&'ast SemAdtDef<'ast>>;
+    fn fn_sig(&'ast self, api_id: ItemId) -> Option<&'ast SemFnSig<'ast>>;
+    fn predicates_of(&'ast self, api_id: ItemId) -> &'ast [SemPredicate<'ast>];
+    fn generic_param(&'ast self, api_id: GenericId) -> &'ast SemGenericParam<'ast>;
+    fn layout_of(&'ast self, ty: SemTyKind<'ast>) -> Option<&'ast Layout<'ast>>;
+    fn expr_adjustments(&'ast self, expr: ExprId) -> &'ast [Adjustment<'ast>];
+    fn closure_captures(&'ast self, expr: ExprId) -> &'ast ClosureCaptures<'ast>;
+    fn expr_desugaring(&'ast self, expr: ExprId) -> Option<DesugarKind>;
+    fn comments_in(&'ast self, span: &Span<'ast>) -> &'ast [Comment<'ast>];
+    fn comments_in_file(&'ast self, span: &Span<'ast>) -> &'ast [Comment<'ast>];
+    fn comments_before(&'ast self, node: EmissionNode) -> &'ast [Comment<'ast>];
 
     fn expr_ty(&'ast self, expr: ExprId) -> SemTyKind<'ast>;
+    fn peel_aliases(&'ast self, ty: SemTyKind<'ast>) -> SemTyKind<'ast>;
+    fn subst_generics(&'ast self, ty: SemTyKind<'ast>, args: &SemGenericArgs<'ast>) -> SemTyKind<'ast>;
+    fn generic_name(&'ast self, id: GenericId) -> SymbolId;
+    fn opaque_bounds(&'ast self, ty: DriverTyId) -> &'ast [SemTraitBound<'ast>];
+    fn coroutine_interior_tys(&'ast self, ty: DriverTyId) -> &'ast [SemTyKind<'ast>];
+    fn docs(&'ast self, node: EmissionNode) -> &'ast Docs<'ast>;
     fn span(&'ast self, owner: SpanId) -> &'ast Span<'ast>;
     fn span_snippet(&'ast self, span: &Span<'_>) -> Option<&'ast str>;
     fn span_source(&'ast self, span: &Span<'_>) -> SpanSource<'ast>;
@@ -26,6 +26,12 @@ use marker_utils::visitor::{self, Visitor};
 use std::{cell::RefCell, ops::ControlFlow};
 
 pub const LINT_CRATES_ENV: &str = "MARKER_LINT_CRATES";
+/// If set, the driver writes a report of all `allow` and `expect` attributes
+/// targeting `marker::` lints into the directory specified by this value.
+pub const SUPPRESSION_REPORT_ENV: &str = "MARKER_SUPPRESSION_REPORT_DIR";
+/// A JSON object, set by `cargo-marker`, which maps the manifest path of every
+/// workspace member to the versions of its direct dependencies.
+pub const DEPENDENCY_VERSIONS_ENV: &str = "MARKER_DEPENDENCY_VERSIONS";
 
 /// This struct is the interface used by lint drivers to load lint crates, pass
 /// `marker_api` objects to external lint passes and all other magic you can think of.
@@ -64,10 +70,11 @@ impl Adapter {
         self.inner.borrow().external_lint_crates.collect_lint_pass_info()
     }
 
-    pub fn process_krate<'ast>(&self, cx: &'ast AstContext<'ast>, krate: &Crate<'ast>) {
+    pub fn process_krate<'ast>(&self, cx: &'ast AstContext<'ast>, krate: &'ast Crate<'ast>) {
         let inner = &mut *self.inner.borrow_mut();
 
         inner.external_lint_crates.set_ast_context(cx);
+        inner.external_lint_crates.check_crate(cx, krate);
 
         for item in krate.items() {
             visitor::traverse_item::<()>(cx, inner, *item);
@@ -1,7 +1,10 @@
 mod common;
 pub use common::*;
 
-use crate::ffi::FfiSlice;
+use crate::{
+    context::with_cx,
+    ffi::{FfiOption, FfiSlice, FfiStr},
+};
 
 use self::item::ItemKind;
 
@@ -12,17 +15,46 @@ pub mod pat;
 pub mod stmt;
 pub mod ty;
 
+/// The crate, which is currently being linted.
+///
+/// Besides the items, this also provides information about how the crate is
+/// being compiled, like the [`Edition`], [`CrateType`] and enabled `cfg`s.
 #[derive(Debug)]
 pub struct Crate<'ast> {
     id: CrateId,
+    name: SymbolId,
+    edition: Edition,
+    crate_type: CrateType,
+    is_test: bool,
+    cfgs: FfiSlice<'ast, CfgOption<'ast>>,
+    manifest_path: FfiOption<FfiStr<'ast>>,
+    dependencies: FfiSlice<'ast, Dependency<'ast>>,
     items: FfiSlice<'ast, ItemKind<'ast>>,
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> Crate<'ast> {
-    pub fn new(id: CrateId, items: &'ast [ItemKind<'ast>]) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: CrateId,
+        name: SymbolId,
+        edition: Edition,
+        crate_type: CrateType,
+        is_test: bool,
+        cfgs: &'ast [CfgOption<'ast>],
+        manifest_path: Option<&'ast str>,
+        dependencies: &'ast [Dependency<'ast>],
+        items: &'ast [ItemKind<'ast>],
+    ) -> Self {
         Self {
             id,
+            name,
+            edition,
+            crate_type,
+            is_test,
+            cfgs: cfgs.into(),
+            manifest_path: manifest_path.map(Into::into).into(),
+            dependencies: dependencies.into(),
             items: items.into(),
         }
     }
@@ -34,9 +66,158 @@ impl<'ast> Crate<'ast> {
         self.id
     }
 
+    /// The name of the crate, as it would be used in paths. Dashes in the
+    /// package name are replaced by underscores.
+    pub fn name(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.name))
+    }
+
+    /// The [`Edition`] this crate is compiled with.
+    pub fn edition(&self) -> Edition {
+        self.edition
+    }
+
+    /// The [`CrateType`] of this crate.
+    pub fn crate_type(&self) -> CrateType {
+        self.crate_type
+    }
+
+    /// Returns `true`, if this crate is compiled with `--test`. This is the case
+    /// for unit tests, integration tests and benchmarks.
+    pub fn is_test(&self) -> bool {
+        self.is_test
+    }
+
+    /// The `cfg` options, which are active for this crate. This includes the
+    /// enabled cargo features, which are represented as `feature = "name"`.
+    pub fn cfgs(&self) -> &[CfgOption<'ast>] {
+        self.cfgs.get()
+    }
+
+    /// The cargo features, which are enabled for this crate.
+    pub fn features(&self) -> impl Iterator<Item = &'ast str> + '_ {
+        self.cfgs()
+            .iter()
+            .filter(|cfg| cfg.name() == "feature")
+            .filter_map(CfgOption::value)
+    }
+
+    /// Returns `true`, if the given `cfg` option is active. The `value` has to
+    /// be [`None`] for `cfg`s without a value, like `test` or `unix`.
+    ///
+    /// ```ignore
+    /// if krate.is_cfg_active("target_os", Some("linux")) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn is_cfg_active(&self, name: &str, value: Option<&str>) -> bool {
+        self.cfgs().iter().any(|cfg| cfg.name() == name && cfg.value() == value)
+    }
+
+    /// The path of the `Cargo.toml` file, of the package this crate belongs to.
+    /// This will be [`None`], if the crate isn't compiled by Cargo.
+    pub fn manifest_path(&self) -> Option<&str> {
+        self.manifest_path.get().map(FfiStr::get)
+    }
+
+    /// The direct dependencies of this crate. This includes crates like `std`
+    /// and `core`, which are implicitly added by the compiler.
+    pub fn dependencies(&self) -> &[Dependency<'ast>] {
+        self.dependencies.get()
+    }
+
     /// This is a list of all items in the root file of the crate. Nested items
     /// will be represented in the form of items and sub-items
     pub fn items(&self) -> &[ItemKind<'ast>] {
         self.items.get()
     }
 }
+
+/// The type of a crate, which defines how the crate is compiled.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CrateType {
+    /// A binary crate with a `main` function.
+    Bin,
+    /// A library crate. This includes all kinds of libraries, like `rlib`,
+    /// `dylib`, `cdylib` and `staticlib`.
+    Lib,
+    /// A procedural macro crate.
+    ProcMacro,
+}
+
+/// A `cfg` option, like `unix`, `target_os = "linux"` or `feature = "serde"`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CfgOption<'ast> {
+    name: FfiStr<'ast>,
+    value: FfiOption<FfiStr<'ast>>,
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> CfgOption<'ast> {
+    pub fn new(name: &'ast str, value: Option<&'ast str>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.map(Into::into).into(),
+        }
+    }
+}
+
+impl<'ast> CfgOption<'ast> {
+    /// The name of the option, like `target_os` in `target_os = "linux"`.
+    pub fn name(&self) -> &'ast str {
+        self.name.get()
+    }
+
+    /// The value of the option, like `linux` in `target_os = "linux"`. This
+    /// will be [`None`] for options without a value, like `unix`.
+    pub fn value(&self) -> Option<&'ast str> {
+        self.value.get().map(FfiStr::get)
+    }
+}
+
+/// A direct dependency of the linted crate.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Dependency<'ast> {
+    id: CrateId,
+    name: FfiStr<'ast>,
+    version: FfiOption<FfiStr<'ast>>,
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> Dependency<'ast> {
+    pub fn new(id: CrateId, name: &'ast str, version: Option<&'ast str>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            version: version.map(Into::into).into(),
+        }
+    }
+}
+
+impl<'ast> Dependency<'ast> {
+    /// The [`CrateId`] of the dependency.
+    pub fn id(&self) -> CrateId {
+        self.id
+    }
+
+    /// The name of the dependency, as it would be used in paths. Renamed
+    /// dependencies are represented by the original crate name.
+    pub fn name(&self) -> &'ast str {
+        self.name.get()
+    }
+
+    /// The version of the dependency, like `1.0.188`. This will be [`None`],
+    /// if the driver is unable to determine the version. This is for example
+    /// the case for crates of the standard library.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver receives the versions from `cargo-marker`, which reads them from `cargo
+    ///   metadata`. The version is unknown, if the driver is used without `cargo-marker`.
+    pub fn version(&self) -> Option<&'ast str> {
+        self.version.get().map(FfiStr::get)
+    }
+}
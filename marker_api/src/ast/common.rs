@@ -6,17 +6,28 @@ mod callable;
 pub use callable::*;
 mod ast_path;
 pub use ast_path::*;
+mod const_value;
+pub use const_value::*;
+mod comment;
+pub use comment::*;
+mod docs;
+pub use docs::*;
+mod item_path;
+pub use item_path::*;
 
-use std::{fmt::Debug, marker::PhantomData};
+use std::fmt::Debug;
 
 use super::generic::SynGenericArgs;
 
+#[repr(C)]
 #[non_exhaustive]
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum Edition {
     Edition2015,
     Edition2018,
     Edition2021,
+    /// The 2024 edition is still unstable.
+    Edition2024,
 }
 
 #[non_exhaustive]
@@ -122,27 +133,3 @@ impl Syncness {
         matches!(self, Self::Async)
     }
 }
-
-/// The semantic equivalent of a [`ConstExpr`][super::expr::ConstExpr], at least
-/// theoretically. This part of the API is sadly not done yet, so this is just a
-/// placeholder.
-///
-/// See: rust-marker/marker#179
-#[repr(C)]
-pub struct ConstValue<'ast> {
-    _lifetime: PhantomData<&'ast ()>,
-}
-
-impl<'ast> Debug for ConstValue<'ast> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ConstValue {{ /* WIP: See rust-marker/marker#179 */}}")
-            .finish()
-    }
-}
-
-#[cfg(feature = "driver-api")]
-impl<'ast> ConstValue<'ast> {
-    pub fn new() -> Self {
-        Self { _lifetime: PhantomData }
-    }
-}
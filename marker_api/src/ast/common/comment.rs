@@ -0,0 +1,98 @@
+use crate::{context::with_cx, ffi::FfiOption, ffi::FfiStr};
+
+use super::{Span, SpanId};
+
+/// A comment in the source code, like `// TODO` or `/* ... */`.
+///
+/// Comments are not part of the AST. They can be requested from the
+/// [`AstContext`](crate::context::AstContext), with:
+/// * [`AstContext::comments_in`](crate::context::AstContext::comments_in)
+/// * [`AstContext::comments_in_file`](crate::context::AstContext::comments_in_file)
+/// * [`AstContext::comments_before`](crate::context::AstContext::comments_before)
+///
+/// Doc comments are also available as [`Docs`](super::Docs) of the documented
+/// item. Comments inside macro expansions are not provided.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Comment<'ast> {
+    kind: CommentKind,
+    doc_style: FfiOption<DocStyle>,
+    text: FfiStr<'ast>,
+    span: SpanId,
+}
+
+impl<'ast> Comment<'ast> {
+    pub fn kind(&self) -> CommentKind {
+        self.kind
+    }
+
+    /// Returns `true`, if this is a doc comment, like `/// Docs` or `//! Docs`.
+    pub fn is_doc(&self) -> bool {
+        self.doc_style.is_some()
+    }
+
+    /// The [`DocStyle`] of doc comments or [`None`] for normal comments.
+    pub fn doc_style(&self) -> Option<DocStyle> {
+        self.doc_style.copy()
+    }
+
+    /// The full text of the comment, including the comment delimiters, like
+    /// `// SAFETY: ...`.
+    pub fn text(&self) -> &'ast str {
+        self.text.get()
+    }
+
+    /// The content of the comment without the comment delimiters. For example:
+    /// * `// SAFETY: ...` -> ` SAFETY: ...`
+    /// * `/// Docs` -> ` Docs`
+    /// * `/* Note */` -> ` Note `
+    pub fn content(&self) -> &'ast str {
+        let text = self.text.get();
+        let marker_len = if self.is_doc() { 3 } else { 2 };
+        let content = text.get(marker_len..).unwrap_or_default();
+        match self.kind {
+            CommentKind::Line => content,
+            CommentKind::Block => content.strip_suffix("*/").unwrap_or(content),
+        }
+    }
+
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> Comment<'ast> {
+    pub fn new(kind: CommentKind, doc_style: Option<DocStyle>, text: &'ast str, span: SpanId) -> Self {
+        Self {
+            kind,
+            doc_style: doc_style.into(),
+            text: text.into(),
+            span,
+        }
+    }
+
+    pub fn span_id(&self) -> SpanId {
+        self.span
+    }
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CommentKind {
+    /// A line comment, like `// Comment` or `/// Docs`
+    Line,
+    /// A block comment, like `/* Comment */` or `/** Docs */`
+    Block,
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DocStyle {
+    /// Outer docs, like `/// Docs` or `/** Docs */`, which document the following item
+    Outer,
+    /// Inner docs, like `//! Docs` or `/*! Docs */`, which document the surrounding item
+    Inner,
+}
@@ -0,0 +1,231 @@
+use crate::{
+    ast::ty::NumKind,
+    ffi::{FfiSlice, FfiStr},
+};
+
+/// The semantic value of a constant, like the result of evaluating a
+/// [`ConstExpr`](crate::ast::expr::ConstExpr). Values can be computed with
+/// [`AstContext::eval_const`](crate::context::AstContext::eval_const).
+///
+/// Marker only represents a subset of the values, which can be computed at
+/// compile time. Values of other types, like ADTs or references to anything other
+/// than string or byte slices, are not represented.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub enum ConstValue<'ast> {
+    Int(ConstInt),
+    Float(ConstFloat),
+    Bool(bool),
+    Char(char),
+    /// A string slice, like the value of `"Hello Marker"`
+    Str(ConstStr<'ast>),
+    /// A byte string or slice, like the value of `b"Hello Marker"`
+    ByteStr(ConstByteStr<'ast>),
+    Tuple(ConstValues<'ast>),
+    Array(ConstValues<'ast>),
+    /// The value is unknown. This is used for semantic types, where the
+    /// value can't be evaluated, for example, since it depends on a generic
+    /// parameter. [`AstContext::eval_const`](crate::context::AstContext::eval_const)
+    /// will never return this variant.
+    Unknown,
+}
+
+impl<'ast> ConstValue<'ast> {
+    /// Returns the value as a `u128`, if this is an integer value, which
+    /// fits into an `u128`.
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            ConstValue::Int(int) => int.as_u128(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a `i128`, if this is an integer value, which
+    /// fits into an `i128`.
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            ConstValue::Int(int) => int.as_i128(),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ConstValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&'ast str> {
+        match self {
+            ConstValue::Str(value) => Some(value.value()),
+            _ => None,
+        }
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, ConstValue::Unknown)
+    }
+}
+
+/// An integer value, with the type it belongs to.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstInt {
+    /// The value of the integer. Values of signed integers are stored
+    /// as sign extended `i128` values.
+    value: u128,
+    kind: NumKind,
+}
+
+impl ConstInt {
+    /// The type of the integer value.
+    pub fn kind(&self) -> NumKind {
+        self.kind
+    }
+
+    pub fn is_signed(&self) -> bool {
+        self.kind.is_signed()
+    }
+
+    /// Returns the value as a `u128`, if it's not negative.
+    #[allow(clippy::cast_possible_wrap)] // Signed values are stored sign extended
+    pub fn as_u128(&self) -> Option<u128> {
+        if self.is_signed() {
+            u128::try_from(self.value as i128).ok()
+        } else {
+            Some(self.value)
+        }
+    }
+
+    /// Returns the value as a `i128`, if it fits into an `i128`. This is
+    /// always the case for signed integers.
+    #[allow(clippy::cast_possible_wrap)] // Signed values are stored sign extended
+    pub fn as_i128(&self) -> Option<i128> {
+        if self.is_signed() {
+            Some(self.value as i128)
+        } else {
+            i128::try_from(self.value).ok()
+        }
+    }
+}
+
+impl std::fmt::Debug for ConstInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut builder = f.debug_struct("ConstInt");
+        if let (true, Some(value)) = (self.is_signed(), self.as_i128()) {
+            builder.field("value", &value);
+        } else {
+            builder.field("value", &self.value);
+        }
+        builder.field("kind", &self.kind).finish()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl ConstInt {
+    /// Values of signed integers have to be sign extended.
+    pub fn new(value: u128, kind: NumKind) -> Self {
+        debug_assert!(kind.is_integer());
+        Self { value, kind }
+    }
+}
+
+/// A floating point value, with the type it belongs to. The results of float
+/// operations can be hardware-dependent. It might be better to check for a
+/// range around the value in question.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConstFloat {
+    value: f64,
+    kind: NumKind,
+}
+
+impl ConstFloat {
+    /// The type of the float value.
+    pub fn kind(&self) -> NumKind {
+        self.kind
+    }
+
+    /// The value of the float. Values of `f32` constants are converted to
+    /// `f64` losslessly.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl std::hash::Hash for ConstFloat {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.kind.hash(state);
+        // `0.0` and `-0.0` are equal and should therefore have the same hash
+        let value = if self.value == 0.0 { 0.0 } else { self.value };
+        value.to_bits().hash(state);
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl ConstFloat {
+    pub fn new(value: f64, kind: NumKind) -> Self {
+        debug_assert!(kind.is_float());
+        Self { value, kind }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct ConstStr<'ast> {
+    value: FfiStr<'ast>,
+}
+
+impl<'ast> ConstStr<'ast> {
+    pub fn value(&self) -> &'ast str {
+        self.value.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> ConstStr<'ast> {
+    pub fn new(value: &'ast str) -> Self {
+        Self { value: value.into() }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct ConstByteStr<'ast> {
+    value: FfiSlice<'ast, u8>,
+}
+
+impl<'ast> ConstByteStr<'ast> {
+    pub fn value(&self) -> &'ast [u8] {
+        self.value.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> ConstByteStr<'ast> {
+    pub fn new(value: &'ast [u8]) -> Self {
+        Self { value: value.into() }
+    }
+}
+
+/// The values of a tuple or array.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct ConstValues<'ast> {
+    values: FfiSlice<'ast, ConstValue<'ast>>,
+}
+
+impl<'ast> ConstValues<'ast> {
+    pub fn values(&self) -> &'ast [ConstValue<'ast>] {
+        self.values.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> ConstValues<'ast> {
+    pub fn new(values: &'ast [ConstValue<'ast>]) -> Self {
+        Self { values: values.into() }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    context::with_cx,
+    ffi::{FfiSlice, FfiStr},
+};
+
+use super::{Span, SpanId};
+
+/// The documentation of an item, field or enum variant.
+///
+/// The documentation is combined from all doc comments (`///` and `/** */`) and
+/// `#[doc = "..."]` attributes of a node. The common indentation of the lines
+/// is removed, like rustdoc would do it.
+///
+/// ```
+/// /// This is the first line.
+/// ///
+/// /// # Panics
+/// /// This is the third line.
+/// fn documented() {}
+/// ```
+///
+/// The documentation above would result in the following string:
+/// ```text
+/// This is the first line.
+///
+/// # Panics
+/// This is the third line.
+/// ```
+///
+/// [`marker_utils`](https://docs.rs/marker_utils) provides a Markdown view, to
+/// inspect the sections and code blocks of the documentation.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Docs<'ast> {
+    text: FfiStr<'ast>,
+    lines: FfiSlice<'ast, DocLine<'ast>>,
+}
+
+impl<'ast> Docs<'ast> {
+    /// The combined documentation, with the lines separated by `\n`.
+    pub fn as_str(&self) -> &'ast str {
+        self.text.get()
+    }
+
+    /// The lines of the documentation. The lines correspond to the lines of
+    /// [`as_str()`](Docs::as_str). The index of a line in this slice is
+    /// therefore the same, as the index in `docs.as_str().split('\n')`.
+    pub fn lines(&self) -> &[DocLine<'ast>] {
+        self.lines.get()
+    }
+
+    /// Returns `true`, if the node has no documentation.
+    pub fn is_empty(&self) -> bool {
+        self.lines.get().is_empty()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> Docs<'ast> {
+    pub fn new(text: &'ast str, lines: &'ast [DocLine<'ast>]) -> Self {
+        Self {
+            text: text.into(),
+            lines: lines.into(),
+        }
+    }
+}
+
+/// A single line of [`Docs`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct DocLine<'ast> {
+    text: FfiStr<'ast>,
+    span: SpanId,
+}
+
+impl<'ast> DocLine<'ast> {
+    /// The text of this line, without the comment syntax and indentation.
+    pub fn text(&self) -> &'ast str {
+        self.text.get()
+    }
+
+    /// The [`Span`] of the doc comment or attribute, which contains this line.
+    /// For lines of block doc comments and `#[doc]` attributes with multiple
+    /// lines, this is the span of the entire comment or attribute.
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> DocLine<'ast> {
+    pub fn new(text: &'ast str, span: SpanId) -> Self {
+        Self {
+            text: text.into(),
+            span,
+        }
+    }
+}
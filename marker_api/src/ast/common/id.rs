@@ -171,3 +171,21 @@ new_id! {
     #[cfg_attr(feature = "driver-api", visibility::make(pub))]
     pub(crate) LetStmtId: u64
 }
+
+new_id! {
+    /// **Unstable**
+    ///
+    /// This id is used by drivers to identify semantic types. This type is only
+    /// intended for internal use.
+    #[cfg_attr(feature = "driver-api", visibility::make(pub))]
+    pub(crate) DriverTyId: u64
+}
+
+new_id! {
+    /// **Unstable**
+    ///
+    /// This id is used by drivers to identify semantic generic args. This type
+    /// is only intended for internal use.
+    #[cfg_attr(feature = "driver-api", visibility::make(pub))]
+    pub(crate) DriverGenericArgsId: u64
+}
@@ -0,0 +1,79 @@
+use std::fmt::Display;
+
+use crate::ffi::{FfiSlice, FfiStr};
+
+/// The canonical path of an item, like `my_crate::net::Client::connect`.
+///
+/// The path describes where an item has been defined. Re-exports are ignored,
+/// the path of `std::vec::Vec` is therefore `alloc::vec::Vec`. The first segment
+/// is usually the name of the crate, which defines the item. This is also the
+/// case for items of the currently linted crate.
+///
+/// Items inside inherent `impl` blocks use the path of the implemented type as
+/// a prefix, like `my_crate::net::Client::connect`. Items inside trait
+/// implementations are the exception to the rule above. Their first segment is
+/// a qualified path, which names the implemented type and trait:
+/// `<my_crate::net::Client as my_crate::Connect>::connect`. Inherent `impl`
+/// blocks of types without a path, like slices, are named like `<[T]>`.
+///
+/// The implemented type and trait of `impl` blocks include their generic
+/// arguments. Lifetimes are omitted, while defaulted arguments are included,
+/// even if they are not written in the `impl` header. This allows to
+/// distinguish `impl` blocks for different instantiations of the same type.
+/// Inherent `impl` blocks for the generic type itself, like `impl<T> Option<T>`,
+/// are the exception. They use the plain path of the type, which allows to pass
+/// these paths to
+/// [`AstContext::resolve_item_ids`](crate::context::AstContext::resolve_item_ids):
+///
+/// | `impl` block                 | Path of `fn get` inside the block                                  |
+/// |------------------------------|--------------------------------------------------------------------|
+/// | `impl<T> Wrapper<T>`         | `my_crate::Wrapper::get`                                           |
+/// | `impl Wrapper<u8>`           | `my_crate::Wrapper<u8>::get`                                       |
+/// | `impl<'a, T> Wrapper<&'a T>` | `my_crate::Wrapper<&T>::get`                                       |
+/// | `impl<T> Get for Vec<T>`     | `<alloc::vec::Vec<T, alloc::alloc::Global> as my_crate::Get>::get` |
+/// | `impl<T> Get for [T]`        | `<[T] as my_crate::Get>::get`                                      |
+///
+/// Paths of other items, like structs or functions, don't include generic
+/// arguments. Anonymous items, like closures, are represented by segments like
+/// `{closure#0}`.
+///
+/// Paths of items can be requested via
+/// [`AstContext::item_path`](crate::context::AstContext::item_path) and
+/// [`AstContext::ty_def_path`](crate::context::AstContext::ty_def_path).
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct ItemPath<'ast> {
+    segments: FfiSlice<'ast, FfiStr<'ast>>,
+    path: FfiStr<'ast>,
+}
+
+impl<'ast> ItemPath<'ast> {
+    /// The segments of this path, starting with the crate name or the
+    /// `<Type as Trait>` segment of a trait implementation.
+    pub fn segments(&self) -> impl Iterator<Item = &'ast str> + 'ast {
+        self.segments.get().iter().map(FfiStr::get)
+    }
+
+    /// The path as a string, with the segments separated by `::`. This string
+    /// can be compared with the expected path, to check for specific items.
+    pub fn as_str(&self) -> &'ast str {
+        self.path.get()
+    }
+}
+
+impl Display for ItemPath<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> ItemPath<'ast> {
+    pub fn new(segments: &'ast [FfiStr<'ast>], path: &'ast str) -> Self {
+        Self {
+            segments: segments.into(),
+            path: path.into(),
+        }
+    }
+}
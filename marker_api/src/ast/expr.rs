@@ -4,19 +4,25 @@ use super::{ty::SemTyKind, ExprId, Span, SpanId};
 
 use std::{fmt::Debug, marker::PhantomData};
 
+mod adjustment;
 mod block_expr;
 mod call_exprs;
+mod capture;
 mod control_flow_expr;
 mod ctor_expr;
+mod desugar;
 mod lit_expr;
 mod op_exprs;
 mod path_expr;
 mod place_expr;
 mod unstable_expr;
+pub use adjustment::*;
 pub use block_expr::*;
 pub use call_exprs::*;
+pub use capture::*;
 pub use control_flow_expr::*;
 pub use ctor_expr::*;
+pub use desugar::*;
 pub use lit_expr::*;
 pub use op_exprs::*;
 pub use path_expr::*;
@@ -343,8 +349,8 @@ use impl_expr_data;
 /// An expression that is evaluated at compile time. These show up in array
 /// indices and constant generics.
 ///
-/// Marker currently doesn't provide a way to calculate the result of a constant
-/// expression.
+/// The value of a constant expression can be calculated with
+/// [`AstContext::eval_const`](crate::context::AstContext::eval_const).
 #[repr(C)]
 #[derive(Debug)]
 pub struct ConstExpr<'ast> {
@@ -384,7 +390,7 @@ mod test {
         assert_eq!(40, size_of::<UnaryOpExpr<'_>>(), "UnaryOpExpr<'_>");
         assert_eq!(40, size_of::<RefExpr<'_>>(), "RefExpr<'_>");
         assert_eq!(56, size_of::<BinaryOpExpr<'_>>(), "BinaryOpExpr<'_>");
-        assert_eq!(32, size_of::<QuestionMarkExpr<'_>>(), "QuestionMarkExpr<'_>");
+        assert_eq!(40, size_of::<QuestionMarkExpr<'_>>(), "QuestionMarkExpr<'_>");
         assert_eq!(80, size_of::<AssignExpr<'_>>(), "AssignExpr<'_>");
         assert_eq!(48, size_of::<AsExpr<'_>>(), "AsExpr<'_>");
         assert_eq!(96, size_of::<PathExpr<'_>>(), "PathExpr<'_>");
@@ -402,7 +408,7 @@ mod test {
         assert_eq!(72, size_of::<BreakExpr<'_>>(), "BreakExpr<'_>");
         assert_eq!(40, size_of::<ReturnExpr<'_>>(), "ReturnExpr<'_>");
         assert_eq!(48, size_of::<ContinueExpr<'_>>(), "ContinueExpr<'_>");
-        assert_eq!(112, size_of::<ForExpr<'_>>(), "ForExpr<'_>");
+        assert_eq!(128, size_of::<ForExpr<'_>>(), "ForExpr<'_>");
         assert_eq!(56, size_of::<LoopExpr<'_>>(), "LoopExpr<'_>");
         assert_eq!(72, size_of::<WhileExpr<'_>>(), "WhileExpr<'_>");
         assert_eq!(24, size_of::<UnstableExpr<'_>>(), "UnstableExpr<'_>");
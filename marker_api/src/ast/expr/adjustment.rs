@@ -0,0 +1,80 @@
+use crate::ast::{ty::SemTyKind, Mutability};
+
+/// An implicit conversion, which the compiler applied to an expression. The
+/// adjustments of an expression can be requested via
+/// [`AstContext::expr_adjustments`](crate::context::AstContext::expr_adjustments).
+///
+/// ```
+/// let vec = vec![1, 2, 3];
+/// vec.len();
+/// // ^^^ The receiver is borrowed, to call `Vec::len(&self)`:
+/// //     [Borrow(Unmut) -> &Vec<i32>]
+///
+/// let string = String::new();
+/// let _: &str = &string;
+/// //            ^^^^^^^ The `&String` is deref-coerced to `&str`:
+/// //                    [Deref -> String, OverloadedDeref(Unmut) -> str, Borrow(Unmut) -> &str]
+/// ```
+#[repr(C)]
+#[derive(Debug)]
+pub struct Adjustment<'ast> {
+    kind: AdjustmentKind,
+    target: SemTyKind<'ast>,
+}
+
+impl<'ast> Adjustment<'ast> {
+    pub fn kind(&self) -> AdjustmentKind {
+        self.kind
+    }
+
+    /// The type of the expression after this adjustment has been applied.
+    pub fn target(&self) -> SemTyKind<'ast> {
+        self.target
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> Adjustment<'ast> {
+    pub fn new(kind: AdjustmentKind, target: SemTyKind<'ast>) -> Self {
+        Self { kind, target }
+    }
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AdjustmentKind {
+    /// A value of the [`!`](prim@never) type is coerced to any other type.
+    NeverToAny,
+    /// A builtin dereference of a reference or pointer, like the auto-deref of
+    /// method receivers or the first step of a deref coercion.
+    Deref,
+    /// A dereference using the `Deref` or `DerefMut` trait, depending on the
+    /// given mutability.
+    OverloadedDeref(Mutability),
+    /// An automatic borrow, like the auto-ref of method receivers.
+    Borrow(Mutability),
+    /// An automatic conversion of a reference into a raw pointer.
+    RawBorrow(Mutability),
+    /// An unsizing coercion, like `&[T; N]` to `&[T]` or `Box<T>` to
+    /// `Box<dyn Trait>`.
+    Unsize,
+    /// A pointer coercion, other than [`AdjustmentKind::Unsize`].
+    Pointer(PointerCoercion),
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PointerCoercion {
+    /// A function item is coerced to a function pointer.
+    ReifyFn,
+    /// A safe function pointer is coerced to an unsafe function pointer.
+    UnsafeFn,
+    /// A non-capturing closure is coerced to a function pointer.
+    ClosureFn,
+    /// A `*mut T` pointer is coerced to a `*const T` pointer.
+    MutToConst,
+    /// A `*const [T; N]` pointer is coerced to a `*const T` pointer.
+    ArrayToElement,
+}
@@ -1,4 +1,8 @@
-use crate::{ast::AstPathSegment, ffi::FfiSlice};
+use crate::{
+    ast::{AstPathSegment, ItemId},
+    context::with_cx,
+    ffi::FfiSlice,
+};
 
 use super::{CommonExprData, ExprKind};
 
@@ -73,14 +77,12 @@ impl<'ast> MethodExpr<'ast> {
         &self.method
     }
 
-    // FIXME(xFrednet): Add this method again, once `resolve_method_target` is
-    // supported by rustc's driver
-    //
-    // /// This method resolves the [`ItemId`] of the method being called by this
-    // /// expression.
-    // pub fn resolve(&self) -> ItemId {
-    //     with_cx(self, |cx| cx.resolve_method_target(self.data.id))
-    // }
+    /// This method resolves the [`ItemId`] of the method being called by this
+    /// expression. For trait methods, this is the id of the method in the trait
+    /// definition, like `Iterator::next`, and not the one of the implementation.
+    pub fn resolve(&self) -> ItemId {
+        with_cx(self, |cx| cx.resolve_method_target(self.data.id))
+    }
 
     /// The arguments given to the operand.
     pub fn args(&self) -> &[ExprKind<'ast>] {
@@ -0,0 +1,168 @@
+use crate::{
+    ast::{ty::SemTyKind, Span, SpanId, VarId},
+    context::with_cx,
+    ffi::{FfiSlice, FfiStr},
+};
+
+/// The variables captured by a [`ClosureExpr`](super::ClosureExpr) and the
+/// trait used to call it. The captures can be requested via
+/// [`AstContext::closure_captures`](crate::context::AstContext::closure_captures).
+///
+/// ```
+/// let mut counter = 0;
+/// let data = (String::new(), vec![1, 2, 3]);
+/// let mut closure = || {
+///     counter += data.1.len();
+/// };
+/// // Captures:
+/// // * `counter` by mutable reference
+/// // * `data.1` by reference
+/// // The closure implements `FnMut`, since it mutates `counter`.
+/// # closure();
+/// ```
+#[repr(C)]
+#[derive(Debug)]
+pub struct ClosureCaptures<'ast> {
+    kind: ClosureKind,
+    captures: FfiSlice<'ast, CapturedPlace<'ast>>,
+}
+
+impl<'ast> ClosureCaptures<'ast> {
+    /// The most general trait implemented by the closure, or
+    /// [`ClosureKind::Coroutine`] for coroutines.
+    pub fn kind(&self) -> ClosureKind {
+        self.kind
+    }
+
+    /// The places captured by the closure.
+    pub fn captures(&self) -> &[CapturedPlace<'ast>] {
+        self.captures.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> ClosureCaptures<'ast> {
+    pub fn new(kind: ClosureKind, captures: &'ast [CapturedPlace<'ast>]) -> Self {
+        Self {
+            kind,
+            captures: captures.into(),
+        }
+    }
+}
+
+/// The traits, which can be implemented by closures. Every closure, which
+/// implements `Fn` also implements `FnMut` and every closure, which implements
+/// `FnMut` also implements `FnOnce`. Coroutines don't implement any of these
+/// traits and have their own kind.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClosureKind {
+    /// The closure can be called by shared reference.
+    Fn,
+    /// The closure can be called by mutable reference. This is the case if
+    /// the closure mutates a captured value.
+    FnMut,
+    /// The closure can only be called once. This is the case if the closure
+    /// moves a captured value out of its environment.
+    FnOnce,
+    /// The closure is a coroutine, like a generator. Coroutines are not called
+    /// but resumed by a pinned mutable reference.
+    Coroutine,
+}
+
+/// A place captured by a closure. A place is a local variable with optional
+/// [projections](CaptureProjection). Closures capture disjoint fields, the
+/// closure `|| a.b.len()` captures the place `a.b` instead of `a`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CapturedPlace<'ast> {
+    var: VarId,
+    projections: FfiSlice<'ast, CaptureProjection>,
+    mode: CaptureMode,
+    ty: SemTyKind<'ast>,
+    name: FfiStr<'ast>,
+    span: SpanId,
+}
+
+impl<'ast> CapturedPlace<'ast> {
+    /// The captured variable, or the variable containing the captured place.
+    pub fn var(&self) -> VarId {
+        self.var
+    }
+
+    /// The projections, which are applied to the [variable](Self::var) to
+    /// access the captured place, in the order they're applied.
+    pub fn projections(&self) -> &[CaptureProjection] {
+        self.projections.get()
+    }
+
+    pub fn mode(&self) -> CaptureMode {
+        self.mode
+    }
+
+    /// The semantic type of the captured place. If the place is captured by
+    /// reference, this is the type of the referenced value.
+    pub fn ty(&self) -> SemTyKind<'ast> {
+        self.ty
+    }
+
+    /// A textual representation of the captured place, like `a.b` or `*c`,
+    /// which can be used in diagnostics.
+    pub fn name(&self) -> &str {
+        self.name.get()
+    }
+
+    /// The span of the expression in the closure body, which caused this
+    /// capture.
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> CapturedPlace<'ast> {
+    pub fn new(
+        var: VarId,
+        projections: &'ast [CaptureProjection],
+        mode: CaptureMode,
+        ty: SemTyKind<'ast>,
+        name: &'ast str,
+        span: SpanId,
+    ) -> Self {
+        Self {
+            var,
+            projections: projections.into(),
+            mode,
+            ty,
+            name: name.into(),
+            span,
+        }
+    }
+}
+
+/// A projection of a [`CapturedPlace`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CaptureProjection {
+    /// A dereference of a reference, pointer or `Box`.
+    Deref,
+    /// An access of the field with the given index. For structs and enum
+    /// variants, this is the index of the field in the declaration.
+    Field(u32),
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CaptureMode {
+    /// The value is moved or copied into the closure.
+    Value,
+    /// The value is captured by shared reference.
+    Ref,
+    /// The value is captured by mutable reference. This includes unique
+    /// immutable borrows, which are used to mutate values behind a captured
+    /// `&mut` reference.
+    MutRef,
+}
@@ -1,5 +1,5 @@
 use crate::{
-    ast::{pat::PatKind, ExprId, Ident, Span, SpanId},
+    ast::{pat::PatKind, ty::SemTyKind, ExprId, Ident, Span, SpanId},
     context::with_cx,
     ffi::{FfiOption, FfiSlice},
 };
@@ -520,6 +520,10 @@ pub struct ForExpr<'ast> {
     pat: PatKind<'ast>,
     iterable: ExprKind<'ast>,
     block: ExprKind<'ast>,
+    /// The id of the desugared `IntoIterator::into_iter(<iterable>)` call.
+    into_iter: ExprId,
+    /// The id of the desugared `Iterator::next(&mut iter)` call.
+    next: ExprId,
 }
 
 impl<'ast> ForExpr<'ast> {
@@ -538,6 +542,23 @@ impl<'ast> ForExpr<'ast> {
     pub fn block(&self) -> ExprKind<'ast> {
         self.block
     }
+
+    /// The semantic type of the iterator, which is created by calling
+    /// `IntoIterator::into_iter` on the [iterable](Self::iterable). For a
+    /// loop like `for x in vec.iter()` this is `std::slice::Iter<'_, T>`, for
+    /// `for x in vec` it's `std::vec::IntoIter<T>`.
+    pub fn iter_ty(&self) -> SemTyKind<'ast> {
+        with_cx(self, |cx| cx.expr_ty(self.into_iter))
+    }
+
+    /// The semantic type of the items returned by the iterator. This is the
+    /// type matched by the [pattern](Self::pat). [`None`] is returned, if the
+    /// item type couldn't be determined, like for code which failed to type
+    /// check.
+    pub fn item_ty(&self) -> Option<SemTyKind<'ast>> {
+        let next_ty = with_cx(self, |cx| cx.expr_ty(self.next));
+        super::first_ty_arg(next_ty)
+    }
 }
 
 super::impl_expr_data!(ForExpr<'ast>, For);
@@ -550,6 +571,8 @@ impl<'ast> ForExpr<'ast> {
         pat: PatKind<'ast>,
         iterable: ExprKind<'ast>,
         block: ExprKind<'ast>,
+        into_iter: ExprId,
+        next: ExprId,
     ) -> Self {
         Self {
             data,
@@ -557,6 +580,8 @@ impl<'ast> ForExpr<'ast> {
             pat,
             iterable,
             block,
+            into_iter,
+            next,
         }
     }
 }
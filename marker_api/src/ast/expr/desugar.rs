@@ -0,0 +1,74 @@
+use crate::ast::{generic::SemGenericArgKind, ty::SemTyKind};
+
+/// The kind of syntactic sugar, which an expression originates from. The
+/// compiler internally desugars some expressions into simpler ones. Marker
+/// resugars these expressions, a `for` loop is represented as a
+/// [`ForExpr`](super::ForExpr) and not as the `match` and `loop` expressions it
+/// expands to. The desugaring of an expression can be requested via
+/// [`AstContext::expr_desugaring`](crate::context::AstContext::expr_desugaring).
+///
+/// The calls inserted by the desugaring, like `IntoIterator::into_iter`,
+/// `Try::branch` and `Future::poll`, are not part of the AST. The semantic
+/// types involved are instead provided by the resugared expressions, see
+/// [`ForExpr::iter_ty`](super::ForExpr::iter_ty),
+/// [`QuestionMarkExpr::residual_ty`](super::QuestionMarkExpr::residual_ty) and
+/// [`AwaitExpr::future_ty`](super::AwaitExpr::future_ty).
+///
+/// ```
+/// # fn foo() -> Option<u32> {
+/// # let vec = vec![1, 2, 3];
+/// for _ in vec {}
+/// // The `ForExpr` is reported as `ForLoop`
+///
+/// # let option = Some(0);
+/// option?;
+/// // The `QuestionMarkExpr` is reported as `QuestionMark`
+///
+/// match option {
+///     Some(_) => {},
+///     None => {},
+/// }
+/// // This `MatchExpr` was written by the user and has no desugaring.
+/// # None
+/// # }
+/// ```
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DesugarKind {
+    /// A `for` loop, which is desugared into a call of
+    /// `IntoIterator::into_iter` and a `loop` calling `Iterator::next`.
+    ForLoop,
+    /// A `while` or `while let` loop, which is desugared into a `loop` with an
+    /// `if` expression.
+    WhileLoop,
+    /// The `?` operator, which is desugared into a call of `Try::branch` and a
+    /// `match` on the result, which returns the residual.
+    QuestionMark,
+    /// A `try` block, which wraps the value of the block with
+    /// `Try::from_output`.
+    TryBlock,
+    /// An `.await` expression, which is desugared into a call of
+    /// `IntoFuture::into_future` and a `loop` calling `Future::poll`.
+    Await,
+    /// The body of an `async` function, block or closure.
+    Async,
+    /// A range expression, like `0..16`, which is desugared into a struct
+    /// expression of the corresponding range type.
+    Range,
+}
+
+/// Returns the first generic type argument of the given type. This is used to
+/// extract types like the item type from the `Option<Item>` returned by
+/// `Iterator::next`, which the compiler uses in the desugared expression.
+/// [`None`] is returned, if the type isn't an ADT with a type argument, like
+/// the error type of code, which failed to type check.
+pub(crate) fn first_ty_arg(ty: SemTyKind<'_>) -> Option<SemTyKind<'_>> {
+    match ty {
+        SemTyKind::Adt(adt) => match adt.generics().args().first() {
+            Some(SemGenericArgKind::Ty(arg)) => Some(*arg),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -1,5 +1,10 @@
 use crate::{
-    ast::{pat::PatKind, ty::SynTyKind, Mutability},
+    ast::{
+        pat::PatKind,
+        ty::{SemTyKind, SynTyKind},
+        ExprId, Mutability,
+    },
+    context::with_cx,
     ffi::FfiOption,
 };
 
@@ -147,20 +152,33 @@ impl<'ast> RefExpr<'ast> {
 pub struct QuestionMarkExpr<'ast> {
     data: CommonExprData<'ast>,
     expr: ExprKind<'ast>,
+    /// The id of the desugared `Try::branch(<expr>)` call.
+    branch: ExprId,
 }
 
 impl<'ast> QuestionMarkExpr<'ast> {
     pub fn expr(&self) -> ExprKind<'ast> {
         self.expr
     }
+
+    /// The semantic type of the residual, which is returned early, if the
+    /// [expression](Self::expr) doesn't contain a value. For an expression of
+    /// type `Result<T, E>` this is `Result<Infallible, E>`, for `Option<T>`
+    /// it's `Option<Infallible>`. The type of the extracted value is the type
+    /// of the `?` expression itself. [`None`] is returned, if the residual
+    /// type couldn't be determined, like for code which failed to type check.
+    pub fn residual_ty(&self) -> Option<SemTyKind<'ast>> {
+        let branch_ty = with_cx(self, |cx| cx.expr_ty(self.branch));
+        super::first_ty_arg(branch_ty)
+    }
 }
 
 super::impl_expr_data!(QuestionMarkExpr<'ast>, QuestionMark);
 
 #[cfg(feature = "driver-api")]
 impl<'ast> QuestionMarkExpr<'ast> {
-    pub fn new(data: CommonExprData<'ast>, expr: ExprKind<'ast>) -> Self {
-        Self { data, expr }
+    pub fn new(data: CommonExprData<'ast>, expr: ExprKind<'ast>, branch: ExprId) -> Self {
+        Self { data, expr, branch }
     }
 }
 
@@ -327,19 +345,33 @@ impl<'ast> AssignExpr<'ast> {
 pub struct AwaitExpr<'ast> {
     data: CommonExprData<'ast>,
     expr: ExprKind<'ast>,
+    /// The id of the desugared `IntoFuture::into_future(<expr>)` call.
+    into_future: ExprId,
 }
 
 impl<'ast> AwaitExpr<'ast> {
     pub fn expr(&self) -> ExprKind<'ast> {
         self.expr
     }
+
+    /// The semantic type of the future, which is created by calling
+    /// `IntoFuture::into_future` on the [expression](Self::expr) and then
+    /// polled until it's ready. The type of the output is the type of the
+    /// `.await` expression itself.
+    pub fn future_ty(&self) -> SemTyKind<'ast> {
+        with_cx(self, |cx| cx.expr_ty(self.into_future))
+    }
 }
 
 super::impl_expr_data!(AwaitExpr<'ast>, Await);
 
 #[cfg(feature = "driver-api")]
 impl<'ast> AwaitExpr<'ast> {
-    pub fn new(data: CommonExprData<'ast>, expr: ExprKind<'ast>) -> Self {
-        Self { data, expr }
+    pub fn new(data: CommonExprData<'ast>, expr: ExprKind<'ast>, into_future: ExprId) -> Self {
+        Self {
+            data,
+            expr,
+            into_future,
+        }
     }
 }
@@ -2,8 +2,13 @@ mod args;
 pub use args::*;
 mod param;
 pub use param::*;
+mod predicate;
+pub use predicate::*;
 
-use crate::{ast::ty::SemTyKind, ffi::FfiSlice};
+use crate::{
+    ast::{ty::SemTyKind, DriverGenericArgsId},
+    ffi::FfiSlice,
+};
 
 /// The semantic representation of generic arguments for an item or path.
 ///
@@ -27,7 +32,11 @@ use crate::{ast::ty::SemTyKind, ffi::FfiSlice};
 #[repr(C)]
 #[derive(Debug)]
 pub struct SemGenericArgs<'ast> {
+    driver_id: DriverGenericArgsId,
     args: FfiSlice<'ast, SemGenericArgKind<'ast>>,
+    /// The number of args, which are equal to the declared defaults of their
+    /// parameters. These are the last args before any bindings.
+    defaulted_len: usize,
 }
 
 impl<'ast> SemGenericArgs<'ast> {
@@ -38,12 +47,54 @@ impl<'ast> SemGenericArgs<'ast> {
     pub fn is_empty(&self) -> bool {
         self.args.is_empty()
     }
+
+    /// Returns the args, without the trailing args, which are equal to the
+    /// declared defaults of their parameters. For `Vec<u8, Global>`, this only
+    /// returns `u8`. Bindings, like `Item = u8`, are always included.
+    pub fn args_without_defaults(&self) -> impl Iterator<Item = &SemGenericArgKind<'ast>> {
+        let args = self.args();
+        let end = args
+            .iter()
+            .position(|arg| matches!(arg, SemGenericArgKind::Binding(_) | SemGenericArgKind::ConstBinding(_)))
+            .unwrap_or(args.len());
+        let defaults = end.saturating_sub(self.defaulted_len)..end;
+        args.iter()
+            .enumerate()
+            .filter(move |(index, _)| !defaults.contains(index))
+            .map(|(_, arg)| arg)
+    }
+}
+
+// The driver id only identifies the args inside the driver. Generic args are
+// compared by their structure, the id is therefore ignored.
+impl<'ast> PartialEq for SemGenericArgs<'ast> {
+    fn eq(&self, other: &Self) -> bool {
+        self.args() == other.args()
+    }
+}
+
+impl<'ast> Eq for SemGenericArgs<'ast> {}
+
+impl<'ast> std::hash::Hash for SemGenericArgs<'ast> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.args().hash(state);
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemGenericArgs<'ast> {
-    pub fn new(args: &'ast [SemGenericArgKind<'ast>]) -> Self {
-        Self { args: args.into() }
+    pub fn new(driver_id: DriverGenericArgsId, args: &'ast [SemGenericArgKind<'ast>], defaulted_len: usize) -> Self {
+        Self {
+            driver_id,
+            args: args.into(),
+            defaulted_len,
+        }
+    }
+
+    /// Returns the [`DriverGenericArgsId`], which the driver assigned to these
+    /// args.
+    pub fn driver_id(&self) -> DriverGenericArgsId {
+        self.driver_id
     }
 }
 
@@ -52,7 +103,7 @@ impl<'ast> SemGenericArgs<'ast> {
 /// See: <https://doc.rust-lang.org/stable/reference/paths.html>
 #[repr(C)]
 #[non_exhaustive]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "driver-api", derive(Clone))]
 pub enum SemGenericArgKind<'ast> {
     /// A type as a generic argument, like this:
@@ -80,4 +131,11 @@ pub enum SemGenericArgKind<'ast> {
     /// //            ^
     /// ```
     Const(&'ast SemConstArg<'ast>),
+    /// A constant binding as a generic argument, like this:
+    ///
+    /// ```ignore
+    /// let _baz: &dyn Shape<SIZE=3> = todo!();
+    /// //                   ^^^^^^
+    /// ```
+    ConstBinding(&'ast SemConstBindingArg<'ast>),
 }
@@ -8,7 +8,7 @@ use crate::ast::{ty::SemTyKind, ConstValue, ItemId};
 /// //                      ^^^^^^^^
 /// ```
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemBindingArg<'ast> {
     binding_target: ItemId,
     ty: SemTyKind<'ast>,
@@ -40,19 +40,62 @@ impl<'ast> SemBindingArg<'ast> {
     }
 }
 
+/// A semantic constant binding in the form `<identifier=constant>`. For example,
+/// `SIZE=3` would be the constant binding here:
+///
+/// ```ignore
+/// let _baz: &dyn Shape<SIZE=3> = todo!();
+/// //                   ^^^^^^
+/// ```
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Hash)]
+pub struct SemConstBindingArg<'ast> {
+    binding_target: ItemId,
+    value: ConstValue<'ast>,
+}
+
+impl<'ast> SemConstBindingArg<'ast> {
+    /// This returns the `ItemId` of the associated constant, which is bound.
+    pub fn binding_target(&self) -> ItemId {
+        self.binding_target
+    }
+
+    /// The value that the binding is set to. This will be [`ConstValue::Unknown`],
+    /// if the value depends on a generic parameter.
+    pub fn value(&self) -> &ConstValue<'ast> {
+        &self.value
+    }
+}
+
+// Constant generic arguments can't be floats. The reflexivity of floats is
+// therefore not an issue.
+impl<'ast> Eq for SemConstBindingArg<'ast> {}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemConstBindingArg<'ast> {
+    pub fn new(binding_target: ItemId, value: ConstValue<'ast>) -> Self {
+        Self { binding_target, value }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Hash)]
 pub struct SemConstArg<'ast> {
     value: ConstValue<'ast>,
 }
 
 impl<'ast> SemConstArg<'ast> {
-    /// The value that is used as an argument.
+    /// The value that is used as an argument. This will be [`ConstValue::Unknown`],
+    /// if the value depends on a generic parameter.
     pub fn value(&self) -> &ConstValue<'ast> {
         &self.value
     }
 }
 
+// Constant generic arguments can't be floats. The reflexivity of floats is
+// therefore not an issue.
+impl<'ast> Eq for SemConstArg<'ast> {}
+
 #[cfg(feature = "driver-api")]
 impl<'ast> SemConstArg<'ast> {
     pub fn new(value: ConstValue<'ast>) -> Self {
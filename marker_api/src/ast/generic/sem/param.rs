@@ -1,10 +1,14 @@
-use crate::ast::TyDefId;
+use crate::{
+    ast::{GenericId, ItemId, SymbolId, TyDefId},
+    context::with_cx,
+    ffi::FfiSlice,
+};
 
-use super::SemGenericArgs;
+use super::{SemGenericArgs, SemPredicate};
 
 /// A semantic trait bound used by [`SemTraitTy`](`crate::ast::ty::SemTraitObjTy`)
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemTraitBound<'ast> {
     /// This is used for relaxed type bounds like `?Size`. This is probably not
     /// the best representation. Rustc uses a `TraitBoundModifier` enum which
@@ -40,3 +44,89 @@ impl<'ast> SemTraitBound<'ast> {
         }
     }
 }
+
+/// The semantic representation of a generic parameter. It can be requested via
+/// [`AstContext::generic_param`](crate::context::AstContext::generic_param)
+/// with the [`GenericId`] of a [`SemGenericTy`](crate::ast::ty::SemGenericTy)
+/// or [`Lifetime`](crate::ast::generic::Lifetime).
+///
+/// ```
+/// # use std::fmt::Debug;
+/// fn example<'a, T: Clone>(_: &'a T)
+/// where
+///     T: Debug,
+/// {
+/// }
+/// // `'a` is a lifetime parameter without bounds.
+/// // `T` is a type parameter with the bounds `T: Clone`, `T: Debug` and the
+/// // implicit `T: Sized`.
+/// ```
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemGenericParam<'ast> {
+    id: GenericId,
+    owner: ItemId,
+    name: SymbolId,
+    kind: SemGenericParamKind,
+    bounds: FfiSlice<'ast, SemPredicate<'ast>>,
+}
+
+impl<'ast> SemGenericParam<'ast> {
+    pub fn id(&self) -> GenericId {
+        self.id
+    }
+
+    /// The [`ItemId`] of the item, which declares this parameter.
+    pub fn owner(&self) -> ItemId {
+        self.owner
+    }
+
+    /// The name of the parameter, like `T` or `'a`.
+    pub fn name(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.name))
+    }
+
+    pub fn kind(&self) -> SemGenericParamKind {
+        self.kind
+    }
+
+    /// The predicates of the [owner](Self::owner), which bound this parameter.
+    /// These are trait and projection predicates with this parameter as the
+    /// `Self` type, and outlives predicates, where this parameter has to outlive
+    /// a lifetime. This includes inherited and implied predicates, see
+    /// [`AstContext::predicates_of`](crate::context::AstContext::predicates_of).
+    pub fn bounds(&self) -> &[SemPredicate<'ast>] {
+        self.bounds.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemGenericParam<'ast> {
+    pub fn new(
+        id: GenericId,
+        owner: ItemId,
+        name: SymbolId,
+        kind: SemGenericParamKind,
+        bounds: &'ast [SemPredicate<'ast>],
+    ) -> Self {
+        Self {
+            id,
+            owner,
+            name,
+            kind,
+            bounds: bounds.into(),
+        }
+    }
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SemGenericParamKind {
+    /// A lifetime parameter, like `'a`
+    Lifetime,
+    /// A type parameter, like `T`
+    Ty,
+    /// A const parameter, like `const N: usize`
+    Const,
+}
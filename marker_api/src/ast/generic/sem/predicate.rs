@@ -0,0 +1,236 @@
+use crate::{
+    ast::{
+        generic::{Lifetime, SemGenericArgs, SemTraitBound},
+        ty::SemTyKind,
+        ItemId, Span, SpanId, TyDefId,
+    },
+    context::with_cx,
+};
+
+/// A semantic predicate, which has to hold for an item to be usable. The
+/// predicates of an item can be requested via
+/// [`AstContext::predicates_of`](crate::context::AstContext::predicates_of).
+///
+/// ```
+/// # use std::fmt::Debug;
+/// trait Container {
+///     type Item;
+/// }
+///
+/// fn example<'a, 'b: 'a, T: Copy + 'a, C>(_: &'a T, _: &'b C)
+/// where
+///     C: Container<Item = T>,
+/// {
+/// }
+/// // The predicates of `example` are:
+/// // * `'b: 'a` (LifetimeOutlives, Declared)
+/// // * `T: Copy` (Trait, Declared)
+/// // * `T: 'a` (TyOutlives, Declared)
+/// // * `C: Container` (Trait, Declared)
+/// // * `<C as Container>::Item == T` (Projection, Declared)
+/// // * `T: Sized` and `C: Sized` (Trait, Implied)
+/// // * `T: Clone`, since `Clone` is a supertrait of `Copy` (Trait, Implied)
+/// ```
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemPredicate<'ast> {
+    kind: SemPredicateKind<'ast>,
+    source: PredicateSource,
+    span: SpanId,
+}
+
+impl<'ast> SemPredicate<'ast> {
+    pub fn kind(&self) -> SemPredicateKind<'ast> {
+        self.kind
+    }
+
+    pub fn source(&self) -> PredicateSource {
+        self.source
+    }
+
+    /// The span of the declaration, which caused this predicate. Implied
+    /// predicates use the span of the predicate, they were derived from.
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemPredicate<'ast> {
+    pub fn new(kind: SemPredicateKind<'ast>, source: PredicateSource, span: SpanId) -> Self {
+        Self { kind, source, span }
+    }
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone)]
+pub enum SemPredicateKind<'ast> {
+    /// A trait bound, like `T: Clone`.
+    Trait(&'ast SemTraitPredicate<'ast>),
+    /// A type outlives bound, like `T: 'a`.
+    TyOutlives(&'ast SemTyOutlivesPredicate<'ast>),
+    /// A lifetime outlives bound, like `'a: 'b`.
+    LifetimeOutlives(&'ast SemLifetimeOutlivesPredicate<'ast>),
+    /// An equality of an associated type, like the `Item = u8` in
+    /// `I: Iterator<Item = u8>`.
+    Projection(&'ast SemProjectionPredicate<'ast>),
+}
+
+/// Where a [`SemPredicate`] comes from.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PredicateSource {
+    /// The predicate is declared by the item itself, in the bounds of its
+    /// generic parameters or in its where clause. The bounds of associated
+    /// and opaque types, like `type Item: Clone;`, are declared as well.
+    Declared,
+    /// The predicate is declared by the parent of the item, like the `impl`
+    /// block or trait of an associated item.
+    Inherited,
+    /// The predicate is implied by other predicates or by the compiler. This
+    /// includes the implicit `Sized` bound of type parameters, the bounds of
+    /// supertraits and outlives bounds inferred from fields.
+    Implied,
+}
+
+/// A trait bound, like `T: Clone`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemTraitPredicate<'ast> {
+    self_ty: SemTyKind<'ast>,
+    bound: SemTraitBound<'ast>,
+}
+
+impl<'ast> SemTraitPredicate<'ast> {
+    /// The bound type, like the `T` in `T: Clone`.
+    pub fn self_ty(&self) -> SemTyKind<'ast> {
+        self.self_ty
+    }
+
+    /// The trait, which has to be implemented by the [bound type](Self::self_ty).
+    /// The `Self` type is not part of the generic arguments of the bound.
+    pub fn bound(&self) -> &SemTraitBound<'ast> {
+        &self.bound
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemTraitPredicate<'ast> {
+    pub fn new(self_ty: SemTyKind<'ast>, bound: SemTraitBound<'ast>) -> Self {
+        Self { self_ty, bound }
+    }
+}
+
+/// A type outlives bound, like `T: 'a`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemTyOutlivesPredicate<'ast> {
+    ty: SemTyKind<'ast>,
+    lifetime: Lifetime<'ast>,
+}
+
+impl<'ast> SemTyOutlivesPredicate<'ast> {
+    pub fn ty(&self) -> SemTyKind<'ast> {
+        self.ty
+    }
+
+    /// The lifetime, which is outlived by the [type](Self::ty).
+    pub fn lifetime(&self) -> &Lifetime<'ast> {
+        &self.lifetime
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemTyOutlivesPredicate<'ast> {
+    pub fn new(ty: SemTyKind<'ast>, lifetime: Lifetime<'ast>) -> Self {
+        Self { ty, lifetime }
+    }
+}
+
+/// A lifetime outlives bound, like `'a: 'b`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemLifetimeOutlivesPredicate<'ast> {
+    longer: Lifetime<'ast>,
+    shorter: Lifetime<'ast>,
+}
+
+impl<'ast> SemLifetimeOutlivesPredicate<'ast> {
+    /// The lifetime, which outlives the other one, like the `'a` in `'a: 'b`.
+    pub fn longer(&self) -> &Lifetime<'ast> {
+        &self.longer
+    }
+
+    /// The lifetime, which is outlived, like the `'b` in `'a: 'b`.
+    pub fn shorter(&self) -> &Lifetime<'ast> {
+        &self.shorter
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemLifetimeOutlivesPredicate<'ast> {
+    pub fn new(longer: Lifetime<'ast>, shorter: Lifetime<'ast>) -> Self {
+        Self { longer, shorter }
+    }
+}
+
+/// An equality of an associated type, like `<I as Iterator>::Item == u8`, which
+/// is usually written as `I: Iterator<Item = u8>`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemProjectionPredicate<'ast> {
+    self_ty: SemTyKind<'ast>,
+    trait_id: TyDefId,
+    trait_generic_args: SemGenericArgs<'ast>,
+    assoc_item: ItemId,
+    ty: SemTyKind<'ast>,
+}
+
+impl<'ast> SemProjectionPredicate<'ast> {
+    /// The type, which implements the trait, like the `I` in
+    /// `<I as Iterator>::Item == u8`.
+    pub fn self_ty(&self) -> SemTyKind<'ast> {
+        self.self_ty
+    }
+
+    /// The [`TyDefId`] of the trait, which declares the associated type.
+    pub fn trait_id(&self) -> TyDefId {
+        self.trait_id
+    }
+
+    /// The [`SemGenericArgs`] of the trait, without the `Self` type.
+    pub fn trait_generic_args(&self) -> &SemGenericArgs<'ast> {
+        &self.trait_generic_args
+    }
+
+    /// The [`ItemId`] of the associated type, like `Iterator::Item`.
+    pub fn assoc_item(&self) -> ItemId {
+        self.assoc_item
+    }
+
+    /// The type, which the associated type is equal to.
+    pub fn ty(&self) -> SemTyKind<'ast> {
+        self.ty
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemProjectionPredicate<'ast> {
+    pub fn new(
+        self_ty: SemTyKind<'ast>,
+        trait_id: TyDefId,
+        trait_generic_args: SemGenericArgs<'ast>,
+        assoc_item: ItemId,
+        ty: SemTyKind<'ast>,
+    ) -> Self {
+        Self {
+            self_ty,
+            trait_id,
+            trait_generic_args,
+            assoc_item,
+            ty,
+        }
+    }
+}
@@ -93,6 +93,27 @@ pub enum SynGenericArgKind<'ast> {
     /// //            ^
     /// ```
     Const(&'ast SynConstArg<'ast>),
+    /// A constant binding as a generic argument, like this:
+    ///
+    /// ```ignore
+    /// let _baz: &dyn Shape<SIZE=3> = todo!();
+    /// //                   ^^^^^^
+    /// ```
+    ConstBinding(&'ast SynConstBindingArg<'ast>),
+    /// A constraint on an associated type as a generic argument, like this:
+    ///
+    /// ```ignore
+    /// fn debug_all(_: impl Iterator<Item: Debug>) {}
+    /// //                            ^^^^^^^^^^^
+    /// ```
+    Constraint(&'ast SynConstraintArg<'ast>),
+    /// An inferred generic argument, like this:
+    ///
+    /// ```ignore
+    /// let _bat: [u8; 3] = create_array::<_>();
+    /// //                                 ^
+    /// ```
+    Infer(&'ast SynInferArg<'ast>),
 }
 
 /// This represents the generic parameters of a generic item. The bounds applied
@@ -1,6 +1,9 @@
+use std::marker::PhantomData;
+
 use crate::{
     ast::{expr::ConstExpr, ty::SynTyKind, Span, SpanId, SymbolId, TraitRef},
     context::with_cx,
+    ffi::FfiSlice,
 };
 
 use super::super::Lifetime;
@@ -128,6 +131,134 @@ impl<'ast> SynBindingArg<'ast> {
     }
 }
 
+/// A constant binding in the form `<identifier=constant>`. For example, `SIZE=3`
+/// would be the constant binding here:
+///
+/// ```ignore
+/// let _baz: &dyn Shape<SIZE=3> = todo!();
+/// //                   ^^^^^^
+/// ```
+///
+/// Constant bindings are currently unstable, see rust-lang/rust#92827.
+#[repr(C)]
+#[derive(Debug)]
+pub struct SynConstBindingArg<'ast> {
+    span: SpanId,
+    ident: SymbolId,
+    expr: ConstExpr<'ast>,
+}
+
+impl<'ast> SynConstBindingArg<'ast> {
+    /// The name of the identifier used in the binding. This would return `SIZE`
+    /// in the example above.
+    pub fn ident(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.ident))
+    }
+
+    /// The [`ConstExpr`] that the identifier is set to. This would return the
+    /// expression `3` in the example above.
+    pub fn expr(&self) -> &ConstExpr<'ast> {
+        &self.expr
+    }
+
+    /// The [`Span`] of the binding.
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SynConstBindingArg<'ast> {
+    pub fn new(span: SpanId, ident: SymbolId, expr: ConstExpr<'ast>) -> Self {
+        Self { span, ident, expr }
+    }
+}
+
+/// A constraint on an associated type in the form `<identifier: bounds>`. For
+/// example, `Item: Debug` would be the constraint here:
+///
+/// ```ignore
+/// fn debug_all(_: impl Iterator<Item: Debug>) {}
+/// //                            ^^^^^^^^^^^
+/// ```
+///
+/// Associated type bounds are currently unstable, see rust-lang/rust#52662.
+#[repr(C)]
+#[derive(Debug)]
+pub struct SynConstraintArg<'ast> {
+    span: SpanId,
+    ident: SymbolId,
+    bounds: FfiSlice<'ast, SynTyParamBound<'ast>>,
+}
+
+impl<'ast> SynConstraintArg<'ast> {
+    /// The name of the associated type, which is constrained. This would return
+    /// `Item` in the example above.
+    pub fn ident(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.ident))
+    }
+
+    /// The bounds that the associated type has to fulfill. This would return
+    /// the `Debug` bound in the example above.
+    pub fn bounds(&self) -> &'ast [SynTyParamBound<'ast>] {
+        self.bounds.get()
+    }
+
+    /// The [`Span`] of the constraint.
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SynConstraintArg<'ast> {
+    pub fn new(span: SpanId, ident: SymbolId, bounds: &'ast [SynTyParamBound<'ast>]) -> Self {
+        Self {
+            span,
+            ident,
+            bounds: bounds.into(),
+        }
+    }
+}
+
+/// An inferred generic argument, like this:
+///
+/// ```ignore
+/// let _bat: [u8; 3] = create_array::<_>();
+/// //                                 ^
+/// ```
+///
+/// The underscore is represented as a generic argument, if it's unclear whether it
+/// refers to a type or a constant. Otherwise, it's represented as a
+/// [`SynTyArg`] with a [`SynInferredTy`](crate::ast::ty::SynInferredTy).
+#[repr(C)]
+pub struct SynInferArg<'ast> {
+    span: SpanId,
+    _lifetime: PhantomData<&'ast ()>,
+}
+
+impl<'ast> SynInferArg<'ast> {
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+}
+
+impl<'ast> std::fmt::Debug for SynInferArg<'ast> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SynInferArg").field("span", &self.span).finish()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SynInferArg<'ast> {
+    pub fn new(span: SpanId) -> Self {
+        Self {
+            span,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
 /// A constant expression as an argument for a constant generic.
 ///
 /// ```
@@ -1,10 +1,12 @@
 use std::{fmt::Debug, marker::PhantomData};
 
+use crate::context::with_cx;
+use crate::ffi::FfiOption;
 use crate::private::Sealed;
 use crate::CtorBlocker;
 
 use super::expr::ExprKind;
-use super::{Ident, ItemId, Span, SpanId};
+use super::{Docs, Ident, ItemId, Span, SpanId};
 
 // Item implementations
 mod extern_crate_item;
@@ -51,12 +53,20 @@ pub trait ItemData<'ast>: Debug + Sealed {
     /// This function can return [`None`] if the item was generated and has no real name
     fn ident(&self) -> Option<&Ident<'ast>>;
 
+    /// Returns the [`ItemId`] of the item, that this item belongs to. This is the
+    /// [`TraitItem`] or [`ImplItem`] for associated items and the [`ExternBlockItem`]
+    /// for items declared inside an `extern` block. All other items return [`None`].
+    fn owner_id(&self) -> Option<ItemId>;
+
     /// Returns this item wrapped in it's [`ExprKind`] variant.
     ///
     /// In function parameters, it's recommended to use `Into<ItemKind<'ast>>`
     /// as a bound to support all items and `ItemKind<'ast>` as parameters.
     fn as_item(&'ast self) -> ItemKind<'ast>;
 
+    /// The documentation of this item.
+    fn docs(&self) -> &Docs<'ast>;
+
     /// The attributes attached to this item.
     ///
     /// Currently, it's only a placeholder until a proper representation is implemented.
@@ -82,6 +92,7 @@ pub enum ItemKind<'ast> {
     Trait(&'ast TraitItem<'ast>),
     Impl(&'ast ImplItem<'ast>),
     ExternBlock(&'ast ExternBlockItem<'ast>),
+    ForeignTy(&'ast ForeignTyItem<'ast>),
     Unstable(&'ast UnstableItem<'ast>),
 }
 
@@ -90,6 +101,8 @@ impl<'ast> ItemKind<'ast> {
     impl_item_type_fn!(ItemKind: span() -> &Span<'ast>);
     impl_item_type_fn!(ItemKind: visibility() -> &Visibility<'ast>);
     impl_item_type_fn!(ItemKind: ident() -> Option<&Ident<'ast>>);
+    impl_item_type_fn!(ItemKind: owner_id() -> Option<ItemId>);
+    impl_item_type_fn!(ItemKind: docs() -> &Docs<'ast>);
     impl_item_type_fn!(ItemKind: attrs() -> ());
 }
 
@@ -106,9 +119,17 @@ impl<'ast> AssocItemKind<'ast> {
     impl_item_type_fn!(AssocItemKind: span() -> &Span<'ast>);
     impl_item_type_fn!(AssocItemKind: visibility() -> &Visibility<'ast>);
     impl_item_type_fn!(AssocItemKind: ident() -> Option<&Ident<'ast>>);
+    impl_item_type_fn!(AssocItemKind: docs() -> &Docs<'ast>);
     impl_item_type_fn!(AssocItemKind: attrs() -> ());
     impl_item_type_fn!(AssocItemKind: as_item() -> ItemKind<'ast>);
-    // FIXME: Potentially add a field to the items to optionally store the owner id
+
+    /// Returns the [`ItemId`] of the [`TraitItem`] or [`ImplItem`], that this
+    /// item belongs to.
+    pub fn owner_id(&self) -> ItemId {
+        self.as_item()
+            .owner_id()
+            .expect("associated items always have an owner")
+    }
 }
 
 impl<'ast> From<AssocItemKind<'ast>> for ItemKind<'ast> {
@@ -126,6 +147,7 @@ impl<'ast> From<AssocItemKind<'ast>> for ItemKind<'ast> {
 pub enum ExternItemKind<'ast> {
     Static(&'ast StaticItem<'ast>, CtorBlocker),
     Fn(&'ast FnItem<'ast>, CtorBlocker),
+    ForeignTy(&'ast ForeignTyItem<'ast>, CtorBlocker),
 }
 
 impl<'ast> ExternItemKind<'ast> {
@@ -133,8 +155,16 @@ impl<'ast> ExternItemKind<'ast> {
     impl_item_type_fn!(ExternItemKind: span() -> &Span<'ast>);
     impl_item_type_fn!(ExternItemKind: visibility() -> &Visibility<'ast>);
     impl_item_type_fn!(ExternItemKind: ident() -> Option<&Ident<'ast>>);
+    impl_item_type_fn!(ExternItemKind: docs() -> &Docs<'ast>);
     impl_item_type_fn!(ExternItemKind: attrs() -> ());
     impl_item_type_fn!(ExternItemKind: as_item() -> ItemKind<'ast>);
+
+    /// Returns the [`ItemId`] of the [`ExternBlockItem`], that this item belongs to.
+    pub fn owner_id(&self) -> ItemId {
+        self.as_item()
+            .owner_id()
+            .expect("items in extern blocks always have an owner")
+    }
 }
 
 impl<'ast> From<ExternItemKind<'ast>> for ItemKind<'ast> {
@@ -142,6 +172,7 @@ impl<'ast> From<ExternItemKind<'ast>> for ItemKind<'ast> {
         match value {
             ExternItemKind::Static(item, ..) => ItemKind::Static(item),
             ExternItemKind::Fn(item, ..) => ItemKind::Fn(item),
+            ExternItemKind::ForeignTy(item, ..) => ItemKind::ForeignTy(item),
         }
     }
 }
@@ -152,7 +183,7 @@ macro_rules! impl_item_type_fn {
     (ItemKind: $method:ident () -> $return_ty:ty) => {
         impl_item_type_fn!((ItemKind) $method() -> $return_ty,
             Mod, ExternCrate, Use, Static, Const, Fn, TyAlias, Struct, Enum,
-            Union, Trait, Impl, ExternBlock, Unstable
+            Union, Trait, Impl, ExternBlock, ForeignTy, Unstable
         );
     };
     (AssocItemKind: $method:ident () -> $return_ty:ty) => {
@@ -162,7 +193,7 @@ macro_rules! impl_item_type_fn {
     };
     (ExternItemKind: $method:ident () -> $return_ty:ty) => {
         impl_item_type_fn!((ExternItemKind) $method() -> $return_ty,
-            Static, Fn
+            Static, Fn, ForeignTy
         );
     };
     (($self:ident) $method:ident () -> $return_ty:ty $(, $item:ident)+) => {
@@ -184,6 +215,7 @@ struct CommonItemData<'ast> {
     span: SpanId,
     vis: Visibility<'ast>,
     ident: Ident<'ast>,
+    owner: FfiOption<ItemId>,
 }
 
 macro_rules! impl_item_data {
@@ -205,6 +237,14 @@ macro_rules! impl_item_data {
                 Some(&self.data.ident)
             }
 
+            fn owner_id(&self) -> Option<crate::ast::item::ItemId> {
+                self.data.owner.copy()
+            }
+
+            fn docs(&self) -> &crate::ast::Docs<'ast> {
+                $crate::context::with_cx(self, |cx| cx.docs(self.data.id.into()))
+            }
+
             fn as_item(&'ast self) -> crate::ast::item::ItemKind<'ast> {
                 $crate::ast::item::ItemKind::$enum_name(self)
             }
@@ -226,44 +266,174 @@ use impl_item_data;
 
 #[cfg(feature = "driver-api")]
 impl<'ast> CommonItemData<'ast> {
-    pub fn new(id: ItemId, span: SpanId, ident: Ident<'ast>) -> Self {
+    pub fn new(id: ItemId, span: SpanId, vis: Visibility<'ast>, ident: Ident<'ast>, owner: Option<ItemId>) -> Self {
         Self {
             id,
             span,
-            vis: Visibility::new(id),
+            vis,
             ident,
+            owner: owner.into(),
         }
     }
 }
 
-/// This struct represents the visibility of an item.
+/// The visibility of an item, field or enum variant.
 ///
-/// Currently, it's only a placeholder until a proper representation is implemented.
-/// rust-marker/marker#26 tracks the task of implementing this. You're welcome to
-/// leave any comments in that issue.
+/// The [`kind()`](Visibility::kind) describes the visibility modifier, as it was
+/// written in the source code. The other methods describe the semantic visibility,
+/// which also considers inherited visibilities and re-exports.
+///
+/// ```
+/// // Public, but only reachable inside the crate, as the module is private
+/// mod private {
+///     pub fn not_exported() {}
+/// }
+///
+/// // Public and exported via a re-export
+/// mod reexported {
+///     pub fn exported() {}
+/// }
+/// pub use reexported::exported;
+/// ```
 #[repr(C)]
 pub struct Visibility<'ast> {
     _lifetime: PhantomData<&'ast ()>,
-    _item_id: ItemId,
+    kind: VisibilityKind,
+    span: FfiOption<SpanId>,
+    scope: FfiOption<ItemId>,
+    is_exported: bool,
+    is_reachable: bool,
 }
 
 impl<'ast> Debug for Visibility<'ast> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Visibility {{ /* WIP: See rust-marker/marker#26 */}}")
+        f.debug_struct("Visibility")
+            .field("kind", &self.kind)
+            .field("span", &self.span)
+            .field("scope", &self.scope)
+            .field("is_exported", &self.is_exported)
+            .field("is_reachable", &self.is_reachable)
             .finish()
     }
 }
 
+impl<'ast> Visibility<'ast> {
+    /// The [`VisibilityKind`] of the visibility modifier. See the documentation
+    /// of [`VisibilityKind`] for how restricted visibilities are represented.
+    pub fn kind(&self) -> VisibilityKind {
+        self.kind
+    }
+
+    /// Returns `true`, if this item is public. This also includes items, which
+    /// inherit a public visibility, like the items of a public trait or the
+    /// variants of a public enum.
+    ///
+    /// Note that public items can still be unreachable from outside the crate,
+    /// if they're declared in a private module. Use [`is_exported()`] to check,
+    /// if an item is part of the public API.
+    ///
+    /// [`is_exported()`]: Visibility::is_exported
+    pub fn is_pub(&self) -> bool {
+        !self.scope.is_some()
+    }
+
+    /// Returns `true`, if this item doesn't have a visibility modifier.
+    pub fn is_default(&self) -> bool {
+        self.kind == VisibilityKind::Inherited
+    }
+
+    /// The [`Span`] of the visibility modifier, like `pub(crate)`. This will be
+    /// [`None`], if the item doesn't have a visibility modifier.
+    pub fn span(&self) -> Option<&Span<'ast>> {
+        self.span.copy().map(|span| with_cx(self, |cx| cx.span(span)))
+    }
+
+    /// The [`ItemId`] of the module, in which this item is visible. Child modules
+    /// of this module can see the item as well. This will be [`None`] for
+    /// public items. Private items will return the id of their parent module.
+    pub fn scope(&self) -> Option<ItemId> {
+        self.scope.copy()
+    }
+
+    /// Returns `true`, if this item can be named from outside of the crate. This
+    /// can be the case, if the item is public and declared in public modules or
+    /// if the item has been re-exported by a public `use` item.
+    pub fn is_exported(&self) -> bool {
+        self.is_exported
+    }
+
+    /// Returns `true`, if this item can be used from outside of the crate, even
+    /// if it can't be named. This is for example the case for a public type,
+    /// declared in a private module, which is used as the return type of an
+    /// exported function.
+    ///
+    /// Every [exported](Visibility::is_exported) item is also reachable.
+    pub fn is_reachable(&self) -> bool {
+        self.is_reachable
+    }
+}
+
 #[cfg(feature = "driver-api")]
 impl<'ast> Visibility<'ast> {
-    pub fn new(item_id: ItemId) -> Self {
+    pub fn new(
+        kind: VisibilityKind,
+        span: Option<SpanId>,
+        scope: Option<ItemId>,
+        is_exported: bool,
+        is_reachable: bool,
+    ) -> Self {
         Self {
             _lifetime: PhantomData,
-            _item_id: item_id,
+            kind,
+            span: span.into(),
+            scope: scope.into(),
+            is_exported,
+            is_reachable,
         }
     }
 }
 
+/// The visibility modifier of an item.
+///
+/// Restricted visibilities, like `pub(super)`, are represented by the module
+/// they restrict the visibility to, called the [scope](Visibility::scope).
+/// Modifiers with the same scope have the same kind, regardless of how they
+/// were written. The kind is the first one of the following list, which
+/// matches the scope:
+///
+/// 1. [`VisibilityKind::Crate`], if the scope is the crate root.
+/// 2. [`VisibilityKind::SelfModule`], if the scope is the module, which declares the item.
+/// 3. [`VisibilityKind::Super`], if the scope is the parent of the module, which declares the item.
+/// 4. [`VisibilityKind::InPath`] for any other scope.
+///
+/// For example, `pub(self)` and `pub(super)` are represented as
+/// [`VisibilityKind::Crate`], if they restrict the visibility to the crate root.
+/// `pub(in self)` is represented as [`VisibilityKind::SelfModule`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum VisibilityKind {
+    /// The item has no visibility modifier. This is the case for private items,
+    /// but also for items which inherit the visibility of their owner, like the
+    /// items of a trait or the variants of an enum.
+    Inherited,
+    /// A public item, declared with `pub`.
+    Public,
+    /// An item, which is visible in the current crate, like `pub(crate)`.
+    Crate,
+    /// An item, which is visible in the parent module of the module, which
+    /// declares the item, like `pub(super)`.
+    Super,
+    /// An item, which is visible in the module, which declares the item, like
+    /// `pub(self)`. Private items without a visibility modifier are
+    /// [`VisibilityKind::Inherited`] instead.
+    SelfModule,
+    /// An item, which is visible in any other module, like
+    /// `pub(in crate::module)`. The module can be accessed via
+    /// [`Visibility::scope`].
+    InPath,
+}
+
 /// A body represents the expression of items.
 ///
 /// Bodies act like a barrier between the item and expression level. When items
@@ -306,19 +476,19 @@ mod test {
     fn test_item_struct_size() {
         // These sizes are allowed to change, this is just a check to have a
         // general overview and to prevent accidental changes
-        assert_eq!(56, size_of::<ModItem<'_>>(), "ModItem");
-        assert_eq!(48, size_of::<ExternCrateItem<'_>>(), "ExternCrateItem");
-        assert_eq!(64, size_of::<UseItem<'_>>(), "UseItem");
-        assert_eq!(80, size_of::<StaticItem<'_>>(), "StaticItem");
-        assert_eq!(72, size_of::<ConstItem<'_>>(), "ConstItem");
-        assert_eq!(144, size_of::<FnItem<'_>>(), "FnItem");
-        assert_eq!(112, size_of::<TyAliasItem<'_>>(), "TyAliasItem");
-        assert_eq!(96, size_of::<StructItem<'_>>(), "StructItem");
-        assert_eq!(88, size_of::<EnumItem<'_>>(), "EnumItem");
-        assert_eq!(88, size_of::<UnionItem<'_>>(), "UnionItem");
-        assert_eq!(112, size_of::<TraitItem<'_>>(), "TraitItem");
-        assert_eq!(144, size_of::<ImplItem<'_>>(), "ImplItem");
-        assert_eq!(64, size_of::<ExternBlockItem<'_>>(), "ExternBlockItem");
-        assert_eq!(48, size_of::<UnstableItem<'_>>(), "UnstableItem");
+        assert_eq!(112, size_of::<ModItem<'_>>(), "ModItem");
+        assert_eq!(104, size_of::<ExternCrateItem<'_>>(), "ExternCrateItem");
+        assert_eq!(136, size_of::<UseItem<'_>>(), "UseItem");
+        assert_eq!(136, size_of::<StaticItem<'_>>(), "StaticItem");
+        assert_eq!(128, size_of::<ConstItem<'_>>(), "ConstItem");
+        assert_eq!(200, size_of::<FnItem<'_>>(), "FnItem");
+        assert_eq!(168, size_of::<TyAliasItem<'_>>(), "TyAliasItem");
+        assert_eq!(152, size_of::<StructItem<'_>>(), "StructItem");
+        assert_eq!(144, size_of::<EnumItem<'_>>(), "EnumItem");
+        assert_eq!(144, size_of::<UnionItem<'_>>(), "UnionItem");
+        assert_eq!(168, size_of::<TraitItem<'_>>(), "TraitItem");
+        assert_eq!(200, size_of::<ImplItem<'_>>(), "ImplItem");
+        assert_eq!(120, size_of::<ExternBlockItem<'_>>(), "ExternBlockItem");
+        assert_eq!(104, size_of::<UnstableItem<'_>>(), "UnstableItem");
     }
 }
@@ -1,7 +1,7 @@
 use crate::ast::expr::ConstExpr;
 use crate::ast::generic::SynGenericParams;
 use crate::ast::ty::SynTyKind;
-use crate::ast::{FieldId, Span, SpanId, SymbolId, VariantId};
+use crate::ast::{Docs, FieldId, Span, SpanId, SymbolId, VariantId};
 use crate::context::with_cx;
 use crate::ffi::{FfiOption, FfiSlice};
 
@@ -114,6 +114,11 @@ impl<'ast> EnumVariant<'ast> {
         with_cx(self, |cx| cx.symbol_str(self.ident))
     }
 
+    /// The documentation of this variant.
+    pub fn docs(&self) -> &Docs<'ast> {
+        with_cx(self, |cx| cx.docs(self.id.into()))
+    }
+
     // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
 
     /// Returns `true` if this is a unit variant like:
@@ -316,6 +321,11 @@ impl<'ast> Field<'ast> {
         with_cx(self, |cx| cx.span(self.span))
     }
 
+    /// The documentation of this field.
+    pub fn docs(&self) -> &Docs<'ast> {
+        with_cx(self, |cx| cx.docs(self.id.into()))
+    }
+
     // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
 }
 
@@ -13,6 +13,8 @@ use super::{CommonItemData, ExternItemKind};
 /// }
 /// ```
 ///
+/// The items inside the block are represented by [`ExternItemKind`].
+///
 /// * See <https://doc.rust-lang.org/stable/reference/items/external-blocks.html>
 #[repr(C)]
 #[derive(Debug)]
@@ -44,3 +46,30 @@ impl<'ast> ExternBlockItem<'ast> {
         }
     }
 }
+
+/// A foreign type, declared inside an `extern` block, like this:
+///
+/// ```ignore
+/// extern "C" {
+///     type Opaque;
+/// }
+/// ```
+///
+/// The size and layout of these types is unknown to Rust. Foreign types are
+/// currently unstable, see rust-lang/rust#43467.
+///
+/// * See <https://rust-lang.github.io/rfcs/1861-extern-types.html>
+#[repr(C)]
+#[derive(Debug)]
+pub struct ForeignTyItem<'ast> {
+    data: CommonItemData<'ast>,
+}
+
+super::impl_item_data!(ForeignTyItem, ForeignTy);
+
+#[cfg(feature = "driver-api")]
+impl<'ast> ForeignTyItem<'ast> {
+    pub fn new(data: CommonItemData<'ast>) -> Self {
+        Self { data }
+    }
+}
@@ -1,4 +1,7 @@
-use crate::ast::AstPath;
+use crate::{
+    ast::{AstPath, AstPathTarget},
+    ffi::FfiSlice,
+};
 
 use super::CommonItemData;
 
@@ -26,6 +29,7 @@ pub struct UseItem<'ast> {
     data: CommonItemData<'ast>,
     use_path: AstPath<'ast>,
     use_kind: UseKind,
+    targets: FfiSlice<'ast, AstPathTarget>,
 }
 
 super::impl_item_data!(UseItem, Use);
@@ -52,15 +56,34 @@ impl<'ast> UseItem<'ast> {
     pub fn is_glob(&self) -> bool {
         matches!(self.use_kind, UseKind::Glob)
     }
+
+    /// Returns the targets, which are imported by this `use` item. Paths can
+    /// resolve to multiple targets, as Rust has separate namespaces for types
+    /// and values. For example, `use foo::Unit` can import the type and the
+    /// constructor of a unit struct. Re-exports are resolved to the original
+    /// items.
+    ///
+    /// For glob imports, this returns the module or type, which the items are
+    /// imported from. Targets, which can't be represented by an
+    /// [`AstPathTarget`], like macros and primitive types, are not included.
+    pub fn resolve(&self) -> &[AstPathTarget] {
+        self.targets.get()
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> UseItem<'ast> {
-    pub fn new(data: CommonItemData<'ast>, use_path: AstPath<'ast>, use_kind: UseKind) -> Self {
+    pub fn new(
+        data: CommonItemData<'ast>,
+        use_path: AstPath<'ast>,
+        use_kind: UseKind,
+        targets: &'ast [AstPathTarget],
+    ) -> Self {
         Self {
             data,
             use_path,
             use_kind,
+            targets: targets.into(),
         }
     }
 }
@@ -1,19 +1,26 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use crate::context::with_cx;
 use crate::private::Sealed;
 
-use super::{Span, SpanId};
+use super::{generic::SemGenericArgs, DriverTyId, Span, SpanId};
 
 // Primitive types
+mod adt_def;
+mod display;
 mod fn_ty;
+mod layout;
 mod other_ty;
 mod prim_ty;
 mod ptr_ty;
 mod sequence_ty;
 mod trait_ty;
 mod user_ty;
+pub use adt_def::*;
+pub use display::*;
 pub use fn_ty::*;
+pub use layout::*;
 pub use other_ty::*;
 pub use prim_ty::*;
 pub use ptr_ty::*;
@@ -212,7 +219,7 @@ use impl_ty_data;
 /// The semantic representation of a type.
 #[repr(C)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum SemTyKind<'ast> {
     // ================================
     // Primitive types
@@ -243,6 +250,9 @@ pub enum SemTyKind<'ast> {
     /// The semantic representation of a
     /// [closure type](https://doc.rust-lang.org/reference/types/closure.html).
     ClosureTy(&'ast SemClosureTy<'ast>),
+    /// The semantic representation of a coroutine, like the body of an `async`
+    /// function or block.
+    Coroutine(&'ast SemCoroutineTy<'ast>),
     // ================================
     // Pointer types
     // ================================
@@ -257,6 +267,9 @@ pub enum SemTyKind<'ast> {
     // ================================
     /// A trait object like [`dyn Trait`](https://doc.rust-lang.org/stable/std/keyword.dyn.html)
     TraitObj(&'ast SemTraitObjTy<'ast>),
+    /// An opaque type like [`impl Trait`](https://doc.rust-lang.org/stable/reference/types/impl-trait.html)
+    /// in return position, or the return type of an `async` function.
+    Opaque(&'ast SemOpaqueTy<'ast>),
     // ================================
     // User defined types
     // ================================
@@ -268,6 +281,8 @@ pub enum SemTyKind<'ast> {
     /// semantic types. This kind is mainly used for type aliases, where the concrete
     /// type is not yet known, for example in traits.
     Alias(&'ast SemAliasTy<'ast>),
+    /// A foreign type, declared in an `extern` block
+    Foreign(&'ast SemForeignTy<'ast>),
     // ================================
     // Other types
     // ================================
@@ -275,3 +290,137 @@ pub enum SemTyKind<'ast> {
     /// and therefor not represented as part of the API.
     Unstable(&'ast SemUnstableTy<'ast>),
 }
+
+impl<'ast> SemTyKind<'ast> {
+    /// Removes all references from this type. For example, `&&String` becomes
+    /// `String`. Raw pointers are not removed.
+    #[must_use]
+    pub fn peel_refs(&self) -> SemTyKind<'ast> {
+        let mut ty = *self;
+        while let SemTyKind::Ref(reference) = ty {
+            ty = reference.inner_ty();
+        }
+        ty
+    }
+
+    /// Replaces [`SemTyKind::Alias`] types with the aliased type, if it can be
+    /// determined. This is the case for associated types of concrete types, like
+    /// `<Vec<u8> as IntoIterator>::Item`, which is resolved to `u8`. Aliases
+    /// which can't be resolved, are kept as they are. Nested aliases, like the
+    /// alias in `Vec<<T as Iterator>::Item>`, are also resolved.
+    ///
+    /// [`SemTyKind::Opaque`] types are replaced with their hidden type, like
+    /// the [`SemTyKind::Coroutine`] of an `async` function.
+    #[must_use]
+    pub fn peel_aliases(&self) -> SemTyKind<'ast> {
+        with_cx(self, |cx| cx.peel_aliases(*self))
+    }
+
+    /// Replaces [`SemTyKind::Generic`] types with the given generic arguments.
+    /// The arguments have to belong to the item, which declares the generic
+    /// parameters. Types which don't depend on these generic parameters are
+    /// returned as they are. The arguments of trait bounds also replace the
+    /// `Self` type of the trait, with the type the bound applies to.
+    ///
+    /// This can, for example, be used to get the concrete types of fields:
+    ///
+    /// ```ignore
+    /// // `ty` is the semantic type of `Wrapper<String>`
+    /// if let SemTyKind::Adt(adt) = ty {
+    ///     for field in adt.def().fields() {
+    ///         // For `struct Wrapper<T> { value: T }`, this will be `String`
+    ///         let field_ty = field.ty().subst(adt.generics());
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn subst(&self, args: &SemGenericArgs<'ast>) -> SemTyKind<'ast> {
+        with_cx(self, |cx| cx.subst_generics(*self, args))
+    }
+
+    /// Returns a wrapper, which renders this type as Rust syntax. The
+    /// [`Display`](std::fmt::Display) implementation of [`SemTyKind`] uses
+    /// the same format. See [`SemTyDisplay`] for more information.
+    pub fn display(&self) -> SemTyDisplay<'ast> {
+        SemTyDisplay::new(*self)
+    }
+}
+
+impl<'ast> std::fmt::Display for SemTyKind<'ast> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.display(), f)
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemTyKind<'ast> {
+    /// Returns the [`DriverTyId`], which the driver assigned to this type.
+    pub fn driver_id(&self) -> DriverTyId {
+        match self {
+            SemTyKind::Bool(ty) => ty.data().driver_id,
+            SemTyKind::Num(ty) => ty.data().driver_id,
+            SemTyKind::Text(ty) => ty.data().driver_id,
+            SemTyKind::Never(ty) => ty.data().driver_id,
+            SemTyKind::Tuple(ty) => ty.data().driver_id,
+            SemTyKind::Array(ty) => ty.data().driver_id,
+            SemTyKind::Slice(ty) => ty.data().driver_id,
+            SemTyKind::FnTy(ty) => ty.data().driver_id,
+            SemTyKind::ClosureTy(ty) => ty.data().driver_id,
+            SemTyKind::Coroutine(ty) => ty.data().driver_id,
+            SemTyKind::Ref(ty) => ty.data().driver_id,
+            SemTyKind::RawPtr(ty) => ty.data().driver_id,
+            SemTyKind::FnPtr(ty) => ty.data().driver_id,
+            SemTyKind::TraitObj(ty) => ty.data().driver_id,
+            SemTyKind::Opaque(ty) => ty.data().driver_id,
+            SemTyKind::Adt(ty) => ty.data().driver_id,
+            SemTyKind::Generic(ty) => ty.data().driver_id,
+            SemTyKind::Alias(ty) => ty.data().driver_id,
+            SemTyKind::Foreign(ty) => ty.data().driver_id,
+            SemTyKind::Unstable(ty) => ty.data().driver_id,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+pub(crate) struct CommonSemTyData<'ast> {
+    _lifetime: PhantomData<&'ast ()>,
+    driver_id: DriverTyId,
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> CommonSemTyData<'ast> {
+    pub fn new_semantic(driver_id: DriverTyId) -> Self {
+        Self {
+            _lifetime: PhantomData,
+            driver_id,
+        }
+    }
+}
+
+// The driver id only identifies the type inside the driver. Semantic types are
+// compared by their structure, the id is therefore ignored.
+impl<'ast> PartialEq for CommonSemTyData<'ast> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<'ast> Eq for CommonSemTyData<'ast> {}
+
+impl<'ast> std::hash::Hash for CommonSemTyData<'ast> {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
+macro_rules! impl_sem_ty_data {
+    ($self_ty:ty) => {
+        impl<'ast> $self_ty {
+            #[cfg(feature = "driver-api")]
+            pub(crate) fn data(&self) -> &$crate::ast::ty::CommonSemTyData<'ast> {
+                &self.data
+            }
+        }
+    };
+}
+use impl_sem_ty_data;
@@ -0,0 +1,214 @@
+use crate::{
+    ast::{ItemId, SymbolId, TyDefId, VariantId},
+    context::with_cx,
+    ffi::{FfiOption, FfiSlice},
+};
+
+use super::SemTyKind;
+
+/// The definition of an abstract data type, like a [`Struct`], [`Enum`] or
+/// [`Union`]. Definitions are available for local and external types. They can
+/// be requested via [`AstContext::adt_def`](crate::context::AstContext::adt_def)
+/// or [`SemAdtTy::def`](super::SemAdtTy::def).
+///
+/// The types of fields are stated as they are declared. Generic parameters of
+/// the data type are therefore represented as [`SemTyKind::Generic`].
+///
+/// [`Struct`]: https://doc.rust-lang.org/reference/types/struct.html
+/// [`Enum`]: https://doc.rust-lang.org/reference/types/enum.html
+/// [`Union`]: https://doc.rust-lang.org/reference/types/union.html
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemAdtDef<'ast> {
+    id: TyDefId,
+    item_id: ItemId,
+    kind: SemAdtKind,
+    variants: FfiSlice<'ast, SemVariantDef<'ast>>,
+    is_non_exhaustive: bool,
+}
+
+impl<'ast> SemAdtDef<'ast> {
+    pub fn id(&self) -> TyDefId {
+        self.id
+    }
+
+    /// The [`ItemId`] of the item, which defines this type. This id can be used
+    /// to get the [`ItemPath`](crate::ast::ItemPath) of external types. For local
+    /// types, it can also be used to request the item itself.
+    pub fn item_id(&self) -> ItemId {
+        self.item_id
+    }
+
+    pub fn kind(&self) -> SemAdtKind {
+        self.kind
+    }
+
+    pub fn is_struct(&self) -> bool {
+        matches!(self.kind, SemAdtKind::Struct)
+    }
+
+    pub fn is_enum(&self) -> bool {
+        matches!(self.kind, SemAdtKind::Enum)
+    }
+
+    pub fn is_union(&self) -> bool {
+        matches!(self.kind, SemAdtKind::Union)
+    }
+
+    /// The variants of an enum. Structs and unions have a single variant, which
+    /// holds their fields.
+    pub fn variants(&self) -> &[SemVariantDef<'ast>] {
+        self.variants.get()
+    }
+
+    /// The fields of a struct or union. This returns an empty slice for enums.
+    pub fn fields(&self) -> &[SemFieldDef<'ast>] {
+        match self.kind {
+            SemAdtKind::Enum => &[],
+            _ => self.variants().first().map_or(&[], SemVariantDef::fields),
+        }
+    }
+
+    /// Returns `true`, if the type is marked with `#[non_exhaustive]`.
+    pub fn is_non_exhaustive(&self) -> bool {
+        self.is_non_exhaustive
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemAdtDef<'ast> {
+    pub fn new(
+        id: TyDefId,
+        item_id: ItemId,
+        kind: SemAdtKind,
+        variants: &'ast [SemVariantDef<'ast>],
+        is_non_exhaustive: bool,
+    ) -> Self {
+        Self {
+            id,
+            item_id,
+            kind,
+            variants: variants.into(),
+            is_non_exhaustive,
+        }
+    }
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SemAdtKind {
+    Struct,
+    Enum,
+    Union,
+}
+
+/// A variant of a [`SemAdtDef`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemVariantDef<'ast> {
+    id: VariantId,
+    ident: SymbolId,
+    kind: SemVariantKind,
+    fields: FfiSlice<'ast, SemFieldDef<'ast>>,
+    is_non_exhaustive: bool,
+}
+
+impl<'ast> SemVariantDef<'ast> {
+    pub fn id(&self) -> VariantId {
+        self.id
+    }
+
+    /// The name of the variant. For structs and unions, this is the name of the type.
+    pub fn ident(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.ident))
+    }
+
+    pub fn kind(&self) -> SemVariantKind {
+        self.kind
+    }
+
+    pub fn fields(&self) -> &[SemFieldDef<'ast>] {
+        self.fields.get()
+    }
+
+    /// Returns `true`, if the variant is marked with `#[non_exhaustive]`.
+    pub fn is_non_exhaustive(&self) -> bool {
+        self.is_non_exhaustive
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemVariantDef<'ast> {
+    pub fn new(
+        id: VariantId,
+        ident: SymbolId,
+        kind: SemVariantKind,
+        fields: &'ast [SemFieldDef<'ast>],
+        is_non_exhaustive: bool,
+    ) -> Self {
+        Self {
+            id,
+            ident,
+            kind,
+            fields: fields.into(),
+            is_non_exhaustive,
+        }
+    }
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SemVariantKind {
+    /// A variant without fields, like `None` or `struct Unit;`
+    Unit,
+    /// A variant with unnamed fields, like `Some(T)` or `struct Tuple(u32);`
+    Tuple,
+    /// A variant with named fields, like `struct Point { x: u32, y: u32 }`
+    Field,
+}
+
+/// A field of a [`SemVariantDef`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemFieldDef<'ast> {
+    ident: SymbolId,
+    ty: SemTyKind<'ast>,
+    vis_scope: FfiOption<ItemId>,
+}
+
+impl<'ast> SemFieldDef<'ast> {
+    /// The name of the field. Fields of tuple variants are named by their index.
+    pub fn ident(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.ident))
+    }
+
+    /// The declared type of this field.
+    pub fn ty(&self) -> SemTyKind<'ast> {
+        self.ty
+    }
+
+    /// Returns `true`, if the field is visible everywhere.
+    pub fn is_pub(&self) -> bool {
+        !self.vis_scope.is_some()
+    }
+
+    /// The module, which the visibility of the field is restricted to. This
+    /// returns [`None`] for `pub` fields. See
+    /// [`Visibility::scope()`](crate::ast::item::Visibility::scope).
+    pub fn vis_scope(&self) -> Option<ItemId> {
+        self.vis_scope.copy()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemFieldDef<'ast> {
+    pub fn new(ident: SymbolId, ty: SemTyKind<'ast>, vis_scope: Option<ItemId>) -> Self {
+        Self {
+            ident,
+            ty,
+            vis_scope: vis_scope.into(),
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt::{self, Display, Formatter, Write};
+
+use crate::{
+    ast::{
+        generic::{SemGenericArgKind, SemGenericArgs, SemTraitBound},
+        Abi, ConstValue, ItemPath, Safety,
+    },
+    context::with_cx,
+};
+
+use super::{CoroutineKind, SemAliasTy, SemTyKind};
+
+/// A wrapper to render a [`SemTyKind`] as Rust syntax, like `Vec<u8>` or
+/// `&dyn Fn(u32) -> bool`. It can be created with [`SemTyKind::display`].
+///
+/// By default, types are rendered with their full path, like
+/// `alloc::vec::Vec<u8>`. [`SemTyDisplay::trim_paths`] can be used to only
+/// render the name of the type, which is usually better for diagnostics.
+///
+/// Some types can't be expressed in Rust syntax. These are rendered with a
+/// placeholder in curly brackets, like `{closure}`. ABIs other than `"C"` are
+/// rendered as `extern "_"`.
+#[derive(Debug, Clone, Copy)]
+pub struct SemTyDisplay<'ast> {
+    ty: SemTyKind<'ast>,
+    trim_paths: bool,
+}
+
+impl<'ast> SemTyDisplay<'ast> {
+    pub(super) fn new(ty: SemTyKind<'ast>) -> Self {
+        Self { ty, trim_paths: false }
+    }
+
+    /// Only render the last segment of paths, like `Vec<u8>` instead of
+    /// `alloc::vec::Vec<u8>`.
+    #[must_use]
+    pub fn trim_paths(mut self) -> Self {
+        self.trim_paths = true;
+        self
+    }
+
+    fn with_ty(self, ty: SemTyKind<'ast>) -> Self {
+        Self { ty, ..self }
+    }
+
+    fn fmt_path(self, f: &mut Formatter<'_>, path: &ItemPath<'_>) -> fmt::Result {
+        if self.trim_paths {
+            f.write_str(path.segments().last().unwrap_or_default())
+        } else {
+            f.write_str(path.as_str())
+        }
+    }
+
+    fn fmt_list(self, f: &mut Formatter<'_>, types: &[SemTyKind<'ast>]) -> fmt::Result {
+        for (index, ty) in types.iter().enumerate() {
+            if index != 0 {
+                f.write_str(", ")?;
+            }
+            self.with_ty(*ty).fmt(f)?;
+        }
+        Ok(())
+    }
+
+    /// Renders the given args, like `<u8, Item = u32>`. Trailing args, which are
+    /// equal to their defaults, like the allocator of `Vec<u8>`, are skipped.
+    fn fmt_generic_args(self, f: &mut Formatter<'_>, args: &SemGenericArgs<'ast>) -> fmt::Result {
+        let mut args = args.args_without_defaults().peekable();
+        if args.peek().is_none() {
+            return Ok(());
+        }
+
+        f.write_char('<')?;
+        for (index, arg) in args.enumerate() {
+            if index != 0 {
+                f.write_str(", ")?;
+            }
+            match arg {
+                SemGenericArgKind::Ty(ty) => self.with_ty(*ty).fmt(f)?,
+                SemGenericArgKind::Binding(binding) => {
+                    let path = with_cx(binding, |cx| cx.item_path(binding.binding_target()));
+                    write!(f, "{} = ", path.segments().last().unwrap_or_default())?;
+                    self.with_ty(binding.ty()).fmt(f)?;
+                },
+                SemGenericArgKind::Const(arg) => fmt_const(f, arg.value())?,
+                SemGenericArgKind::ConstBinding(binding) => {
+                    let path = with_cx(binding, |cx| cx.item_path(binding.binding_target()));
+                    write!(f, "{} = ", path.segments().last().unwrap_or_default())?;
+                    fmt_const(f, binding.value())?;
+                },
+            }
+        }
+        f.write_char('>')
+    }
+
+    /// Renders aliases with their path. Associated types are rendered as
+    /// `<Self as Trait>::Name`.
+    fn fmt_alias(self, f: &mut Formatter<'_>, alias: &SemAliasTy<'ast>) -> fmt::Result {
+        let path = with_cx(alias, |cx| cx.item_path(alias.alias_item()));
+        if let Some(self_ty) = alias.self_ty() {
+            let segments: Vec<_> = path.segments().collect();
+            let (name, trait_segments) = segments.split_last().unwrap_or((&"", &[]));
+            f.write_char('<')?;
+            self.with_ty(self_ty).fmt(f)?;
+            f.write_str(" as ")?;
+            if self.trim_paths {
+                f.write_str(trait_segments.last().unwrap_or(&""))?;
+            } else {
+                f.write_str(&trait_segments.join("::"))?;
+            }
+            self.fmt_generic_args(f, alias.trait_generics())?;
+            write!(f, ">::{name}")?;
+        } else {
+            self.fmt_path(f, path)?;
+        }
+        self.fmt_generic_args(f, alias.generics())
+    }
+
+    fn fmt_bounds(self, f: &mut Formatter<'_>, bounds: &[SemTraitBound<'ast>]) -> fmt::Result {
+        for (index, bound) in bounds.iter().enumerate() {
+            if index != 0 {
+                f.write_str(" + ")?;
+            }
+            if bound.is_relaxed() {
+                f.write_char('?')?;
+            }
+            self.fmt_path(f, with_cx(bound, |cx| cx.ty_def_path(bound.trait_id())))?;
+            self.fmt_generic_args(f, bound.trait_generic_args())?;
+        }
+        Ok(())
+    }
+}
+
+impl<'ast> Display for SemTyDisplay<'ast> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.ty {
+            SemTyKind::Bool(_) => f.write_str("bool"),
+            SemTyKind::Num(num) => num.numeric_kind().fmt(f),
+            SemTyKind::Text(text) => text.textual_kind().fmt(f),
+            SemTyKind::Never(_) => f.write_char('!'),
+            SemTyKind::Tuple(tuple) => {
+                f.write_char('(')?;
+                self.fmt_list(f, tuple.types())?;
+                if tuple.types().len() == 1 {
+                    f.write_char(',')?;
+                }
+                f.write_char(')')
+            },
+            SemTyKind::Array(array) => {
+                f.write_char('[')?;
+                self.with_ty(array.inner_ty()).fmt(f)?;
+                f.write_str("; ")?;
+                fmt_const(f, array.len())?;
+                f.write_char(']')
+            },
+            SemTyKind::Slice(slice) => {
+                f.write_char('[')?;
+                self.with_ty(slice.inner_ty()).fmt(f)?;
+                f.write_char(']')
+            },
+            SemTyKind::FnTy(fn_ty) => {
+                f.write_str("fn {")?;
+                self.fmt_path(f, with_cx(fn_ty, |cx| cx.item_path(fn_ty.fn_id())))?;
+                if !fn_ty.generics().is_empty() {
+                    f.write_str("::")?;
+                    self.fmt_generic_args(f, fn_ty.generics())?;
+                }
+                f.write_char('}')
+            },
+            SemTyKind::ClosureTy(_) => f.write_str("{closure}"),
+            SemTyKind::Coroutine(coroutine) => match coroutine.kind() {
+                CoroutineKind::AsyncFn => f.write_str("{async fn body}"),
+                CoroutineKind::AsyncBlock => f.write_str("{async block}"),
+                CoroutineKind::AsyncClosure => f.write_str("{async closure body}"),
+                _ => f.write_str("{coroutine}"),
+            },
+            SemTyKind::Ref(reference) => {
+                f.write_str(if reference.mutability().is_mut() { "&mut " } else { "&" })?;
+                self.with_ty(reference.inner_ty()).fmt(f)
+            },
+            SemTyKind::RawPtr(ptr) => {
+                f.write_str(if ptr.mutability().is_mut() { "*mut " } else { "*const " })?;
+                self.with_ty(ptr.inner_ty()).fmt(f)
+            },
+            SemTyKind::FnPtr(fn_ptr) => {
+                if matches!(fn_ptr.safety(), Safety::Unsafe) {
+                    f.write_str("unsafe ")?;
+                }
+                match fn_ptr.abi() {
+                    Abi::Default => {},
+                    Abi::C => f.write_str("extern \"C\" ")?,
+                    // The API doesn't expose the names of other ABIs
+                    Abi::Other => f.write_str("extern \"_\" ")?,
+                }
+                f.write_str("fn(")?;
+                self.fmt_list(f, fn_ptr.params())?;
+                f.write_char(')')?;
+                fmt_return_ty(*self, f, fn_ptr.return_ty())
+            },
+            SemTyKind::TraitObj(trait_obj) => {
+                f.write_str("dyn ")?;
+                self.fmt_bounds(f, trait_obj.bounds())
+            },
+            SemTyKind::Opaque(opaque) => {
+                f.write_str("impl ")?;
+                self.fmt_bounds(f, opaque.bounds())
+            },
+            SemTyKind::Adt(adt) => {
+                self.fmt_path(f, with_cx(adt, |cx| cx.ty_def_path(adt.def_id())))?;
+                self.fmt_generic_args(f, adt.generics())
+            },
+            SemTyKind::Generic(generic) => f.write_str(with_cx(generic, |cx| cx.generic_name(generic.generic_id()))),
+            SemTyKind::Alias(alias) => self.fmt_alias(f, alias),
+            SemTyKind::Foreign(foreign) => {
+                self.fmt_path(f, with_cx(foreign, |cx| cx.item_path(foreign.foreign_item())))
+            },
+            SemTyKind::Unstable(_) => f.write_str("{unstable}"),
+        }
+    }
+}
+
+fn fmt_return_ty<'ast>(display: SemTyDisplay<'ast>, f: &mut Formatter<'_>, ty: SemTyKind<'ast>) -> fmt::Result {
+    match ty {
+        SemTyKind::Tuple(tuple) if tuple.types().is_empty() => Ok(()),
+        _ => {
+            f.write_str(" -> ")?;
+            display.with_ty(ty).fmt(f)
+        },
+    }
+}
+
+fn fmt_const(f: &mut Formatter<'_>, value: &ConstValue<'_>) -> fmt::Result {
+    match value {
+        ConstValue::Int(int) => match int.as_i128() {
+            Some(value) => write!(f, "{value}"),
+            None => write!(f, "{}", int.as_u128().unwrap_or_default()),
+        },
+        ConstValue::Bool(value) => write!(f, "{value}"),
+        ConstValue::Char(value) => write!(f, "{value:?}"),
+        _ => f.write_char('_'),
+    }
+}
@@ -1,22 +1,38 @@
-use crate::ast::{generic::SemGenericArgs, impl_callable_data_trait, CommonCallableData, ItemId, TyDefId};
+use crate::{
+    ast::{
+        generic::SemGenericArgs, impl_callable_data_trait, Abi, BodyId, CommonCallableData, Constness, ItemId, Safety,
+        SymbolId, Syncness, TyDefId,
+    },
+    context::with_cx,
+    ffi::{FfiOption, FfiSlice},
+};
 
-use super::CommonSynTyData;
+use super::{CommonSemTyData, CommonSynTyData, SemTyKind};
 
 /// A [function item type](https://doc.rust-lang.org/reference/types/function-item.html)
 /// identifying a specific function and potentualy additional generics.
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemFnTy<'ast> {
+    data: CommonSemTyData<'ast>,
     fn_id: ItemId,
     generics: SemGenericArgs<'ast>,
 }
 
+super::impl_sem_ty_data!(SemFnTy<'ast>);
+
 impl<'ast> SemFnTy<'ast> {
     /// This returns the [`ItemId`] of the identified function.
     pub fn fn_id(&self) -> ItemId {
         self.fn_id
     }
 
+    /// This returns the declared signature of the identified function. For tuple
+    /// struct and tuple variant constructors, the parameters are unnamed.
+    pub fn sig(&self) -> &SemFnSig<'ast> {
+        with_cx(self, |cx| cx.fn_sig(self.fn_id)).expect("the id of a function type should always belong to a function")
+    }
+
     /// This returns the [`SemGenericArgs`] used by identified function
     pub fn generics(&self) -> &SemGenericArgs<'ast> {
         &self.generics
@@ -25,8 +41,120 @@ impl<'ast> SemFnTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemFnTy<'ast> {
-    pub fn new(fn_id: ItemId, generics: SemGenericArgs<'ast>) -> Self {
-        Self { fn_id, generics }
+    pub fn new(data: CommonSemTyData<'ast>, fn_id: ItemId, generics: SemGenericArgs<'ast>) -> Self {
+        Self { data, fn_id, generics }
+    }
+}
+
+/// The declared signature of a function, available for local and external
+/// functions via [`AstContext::fn_sig`](crate::context::AstContext::fn_sig).
+///
+/// The types are stated as they are declared. Generic parameters are therefore
+/// represented as [`SemTyKind::Generic`]. The return type of `async` functions
+/// is the opaque future returned by the function.
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemFnSig<'ast> {
+    fn_id: ItemId,
+    safety: Safety,
+    abi: Abi,
+    constness: Constness,
+    syncness: Syncness,
+    params: FfiSlice<'ast, SemFnParam<'ast>>,
+    return_ty: SemTyKind<'ast>,
+    is_variadic: bool,
+}
+
+impl<'ast> SemFnSig<'ast> {
+    pub fn fn_id(&self) -> ItemId {
+        self.fn_id
+    }
+
+    pub fn safety(&self) -> Safety {
+        self.safety
+    }
+
+    pub fn abi(&self) -> Abi {
+        self.abi
+    }
+
+    pub fn constness(&self) -> Constness {
+        self.constness
+    }
+
+    pub fn syncness(&self) -> Syncness {
+        self.syncness
+    }
+
+    /// The parameters of the function. For methods, this includes the `self`
+    /// parameter.
+    pub fn params(&self) -> &[SemFnParam<'ast>] {
+        self.params.get()
+    }
+
+    pub fn return_ty(&self) -> SemTyKind<'ast> {
+        self.return_ty
+    }
+
+    /// Returns `true`, if this is a C-variadic function, like `printf`.
+    pub fn is_variadic(&self) -> bool {
+        self.is_variadic
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemFnSig<'ast> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        fn_id: ItemId,
+        safety: Safety,
+        abi: Abi,
+        constness: Constness,
+        syncness: Syncness,
+        params: &'ast [SemFnParam<'ast>],
+        return_ty: SemTyKind<'ast>,
+        is_variadic: bool,
+    ) -> Self {
+        Self {
+            fn_id,
+            safety,
+            abi,
+            constness,
+            syncness,
+            params: params.into(),
+            return_ty,
+            is_variadic,
+        }
+    }
+}
+
+/// A parameter of a [`SemFnSig`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct SemFnParam<'ast> {
+    ident: FfiOption<SymbolId>,
+    ty: SemTyKind<'ast>,
+}
+
+impl<'ast> SemFnParam<'ast> {
+    /// The name of the parameter, if it's declared with a simple identifier
+    /// pattern, like `self`, `name` or `_`.
+    pub fn ident(&self) -> Option<&str> {
+        self.ident.copy().map(|sym| with_cx(self, |cx| cx.symbol_str(sym)))
+    }
+
+    pub fn ty(&self) -> SemTyKind<'ast> {
+        self.ty
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemFnParam<'ast> {
+    pub fn new(ident: Option<SymbolId>, ty: SemTyKind<'ast>) -> Self {
+        Self {
+            ident: ident.into(),
+            ty,
+        }
     }
 }
 
@@ -56,12 +184,15 @@ impl_callable_data_trait!(SynClosureTy<'ast>);
 /// Closure expressions create anonymous types, which implement traits to call the
 /// closure. This type on it's own therefore only identifies the type of the closure.
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemClosureTy<'ast> {
+    data: CommonSemTyData<'ast>,
     closure_ty_id: TyDefId,
     generics: SemGenericArgs<'ast>,
 }
 
+super::impl_sem_ty_data!(SemClosureTy<'ast>);
+
 impl<'ast> SemClosureTy<'ast> {
     /// This returns the [`ItemId`] of the identified function.
     pub fn closure_ty_id(&self) -> TyDefId {
@@ -79,10 +210,144 @@ impl<'ast> SemClosureTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemClosureTy<'ast> {
-    pub fn new(closure_ty_id: TyDefId, generics: SemGenericArgs<'ast>) -> Self {
+    pub fn new(data: CommonSemTyData<'ast>, closure_ty_id: TyDefId, generics: SemGenericArgs<'ast>) -> Self {
         Self {
+            data,
             closure_ty_id,
             generics,
         }
     }
 }
+
+/// The semantic representation of a coroutine. Coroutines are created by the
+/// compiler for the bodies of `async` functions, blocks and closures. The
+/// coroutine type implements `Future` and stores the state of the body between
+/// `.await` points.
+///
+/// Calls of `async` functions return an opaque type, like
+/// `impl Future<Output = u8>`, instead of the coroutine type. The coroutine
+/// can be accessed by revealing the opaque type with
+/// [`SemTyKind::peel_aliases`].
+///
+/// ```
+/// # async fn foo() -> u8 { 1 }
+/// # async fn bar() {
+/// let future = async {
+///     let data = vec![1, 2, 3];
+///     foo().await;
+///     //   ^^^^^^ `data` is held across this await point and therefore
+///     //          stored in the coroutine.
+///     data.len()
+/// };
+/// // The type of `future` is a coroutine of the kind `AsyncBlock`, with the
+/// // return type `usize` and the interior type `Vec<i32>`.
+/// # future.await;
+/// # }
+/// ```
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct SemCoroutineTy<'ast> {
+    data: CommonSemTyData<'ast>,
+    coroutine_ty_id: TyDefId,
+    kind: CoroutineKind,
+    body: FfiOption<BodyId>,
+    generics: SemGenericArgs<'ast>,
+    yield_ty: SemTyKind<'ast>,
+    return_ty: SemTyKind<'ast>,
+}
+
+super::impl_sem_ty_data!(SemCoroutineTy<'ast>);
+
+impl<'ast> SemCoroutineTy<'ast> {
+    /// This returns the [`TyDefId`] of the coroutine.
+    pub fn coroutine_ty_id(&self) -> TyDefId {
+        self.coroutine_ty_id
+    }
+
+    pub fn kind(&self) -> CoroutineKind {
+        self.kind
+    }
+
+    /// Returns `true`, if this coroutine was created for an `async` function,
+    /// block or closure and therefore implements `Future`.
+    pub fn is_async(&self) -> bool {
+        matches!(
+            self.kind,
+            CoroutineKind::AsyncFn | CoroutineKind::AsyncBlock | CoroutineKind::AsyncClosure
+        )
+    }
+
+    /// The [`BodyId`] of the body, which is executed by this coroutine. For
+    /// `async` functions, this is the body of the function. The body is only
+    /// available for coroutines of the current crate.
+    pub fn body_id(&self) -> Option<BodyId> {
+        self.body.copy()
+    }
+
+    /// This returns the [`SemGenericArgs`] of the item, which contains the
+    /// coroutine.
+    pub fn generics(&self) -> &SemGenericArgs<'ast> {
+        &self.generics
+    }
+
+    /// The type of the values yielded by the coroutine. This is the unit type
+    /// for `async` coroutines.
+    pub fn yield_ty(&self) -> SemTyKind<'ast> {
+        self.yield_ty
+    }
+
+    /// The type returned by the coroutine, once it has completed. For `async`
+    /// coroutines, this is the `Output` type of the implemented `Future`.
+    pub fn return_ty(&self) -> SemTyKind<'ast> {
+        self.return_ty
+    }
+
+    /// The types of the values, which are stored in the coroutine across
+    /// `.await` or `yield` points. This is a conservative approximation, it
+    /// can contain more types than strictly required.
+    ///
+    /// The coroutine only implements auto traits, like `Send`, if all of its
+    /// interior types and captured values implement them.
+    pub fn interior_tys(&self) -> &[SemTyKind<'ast>] {
+        with_cx(self, |cx| cx.coroutine_interior_tys(self.data.driver_id))
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemCoroutineTy<'ast> {
+    pub fn new(
+        data: CommonSemTyData<'ast>,
+        coroutine_ty_id: TyDefId,
+        kind: CoroutineKind,
+        body: Option<BodyId>,
+        generics: SemGenericArgs<'ast>,
+        yield_ty: SemTyKind<'ast>,
+        return_ty: SemTyKind<'ast>,
+    ) -> Self {
+        Self {
+            data,
+            coroutine_ty_id,
+            kind,
+            body: body.into(),
+            generics,
+            yield_ty,
+            return_ty,
+        }
+    }
+}
+
+/// The origin of a [`SemCoroutineTy`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CoroutineKind {
+    /// The body of an `async fn`.
+    AsyncFn,
+    /// An `async` block, like `async { 1 }`.
+    AsyncBlock,
+    /// The body of an `async` closure, like `async || 1`.
+    AsyncClosure,
+    /// A generator created by a closure using `yield` expressions. Generators
+    /// are unstable and require the `generators` feature.
+    Generator,
+}
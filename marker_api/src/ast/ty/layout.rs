@@ -0,0 +1,72 @@
+use crate::ffi::FfiSlice;
+
+/// The memory layout of a semantic type, as computed by the compiler. Layouts
+/// can be requested via [`AstContext::layout_of`](crate::context::AstContext::layout_of).
+///
+/// Layouts are only available for fully monomorphic types. Types which depend
+/// on generic parameters, like `Vec<T>`, have no layout.
+///
+/// Note that the layout of types without an explicit representation, like
+/// `#[repr(C)]`, is not stable. It can differ between compiler versions and
+/// targets, and should therefore only be used for heuristics.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Layout<'ast> {
+    size: u64,
+    align: u64,
+    is_uninhabited: bool,
+    has_niche: bool,
+    variant_sizes: FfiSlice<'ast, u64>,
+}
+
+impl<'ast> Layout<'ast> {
+    /// The size of the type in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The ABI alignment of the type in bytes.
+    pub fn align(&self) -> u64 {
+        self.align
+    }
+
+    /// Returns `true`, if the type has a size of zero bytes, like `()` or
+    /// `PhantomData<T>`.
+    pub fn is_zero_sized(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns `true`, if the type has no valid values, like [`!`](prim@never)
+    /// or an enum without variants.
+    pub fn is_uninhabited(&self) -> bool {
+        self.is_uninhabited
+    }
+
+    /// Returns `true`, if the type has invalid bit patterns, which can be used
+    /// by the compiler to store the discriminant of a surrounding enum. This is
+    /// the reason, why `Option<&T>` has the same size as `&T`.
+    pub fn has_niche(&self) -> bool {
+        self.has_niche
+    }
+
+    /// The sizes of the enum variants in bytes, ordered like the variants of the
+    /// [`SemAdtDef`](super::SemAdtDef). The size of a variant includes the space
+    /// reserved for the discriminant. This returns an empty slice for all types,
+    /// which are not enums.
+    pub fn variant_sizes(&self) -> &[u64] {
+        self.variant_sizes.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> Layout<'ast> {
+    pub fn new(size: u64, align: u64, is_uninhabited: bool, has_niche: bool, variant_sizes: &'ast [u64]) -> Self {
+        Self {
+            size,
+            align,
+            is_uninhabited,
+            has_niche,
+            variant_sizes: variant_sizes.into(),
+        }
+    }
+}
@@ -1,19 +1,35 @@
-use std::marker::PhantomData;
-
-use super::CommonSynTyData;
+use super::{CommonSemTyData, CommonSynTyData};
 
 /// The placeholder type, signalling that the semantic type is still unstable
 /// and therefor not represented as part of the API.
 #[repr(C)]
 #[derive(Debug)]
 pub struct SemUnstableTy<'ast> {
-    _lt: PhantomData<&'ast ()>,
+    data: CommonSemTyData<'ast>,
+}
+
+super::impl_sem_ty_data!(SemUnstableTy<'ast>);
+
+// The structure of unstable types is not part of the API. The driver assigns
+// the same id to equal types, which is therefore compared instead.
+impl<'ast> PartialEq for SemUnstableTy<'ast> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data.driver_id == other.data.driver_id
+    }
+}
+
+impl<'ast> Eq for SemUnstableTy<'ast> {}
+
+impl<'ast> std::hash::Hash for SemUnstableTy<'ast> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.data.driver_id.hash(state);
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemUnstableTy<'ast> {
-    pub fn new() -> Self {
-        Self { _lt: PhantomData }
+    pub fn new(data: CommonSemTyData<'ast>) -> Self {
+        Self { data }
     }
 }
 
@@ -1,6 +1,4 @@
-use std::marker::PhantomData;
-
-use super::CommonSynTyData;
+use super::{CommonSemTyData, CommonSynTyData};
 
 /// The syntactic representation of the [`bool`] type.
 #[repr(C)]
@@ -26,15 +24,17 @@ impl<'ast> std::fmt::Display for SynBoolTy<'ast> {
 
 /// The semantic representation of the [`bool`] type.
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemBoolTy<'ast> {
-    _lt: PhantomData<&'ast ()>,
+    data: CommonSemTyData<'ast>,
 }
 
+super::impl_sem_ty_data!(SemBoolTy<'ast>);
+
 #[cfg(feature = "driver-api")]
 impl<'ast> SemBoolTy<'ast> {
-    pub fn new() -> Self {
-        Self { _lt: PhantomData }
+    pub fn new(data: CommonSemTyData<'ast>) -> Self {
+        Self { data }
     }
 }
 
@@ -90,19 +90,18 @@ impl<'ast> std::fmt::Display for SynNumTy<'ast> {
 
 /// The semantic representation of a numeric type like [`u32`], [`i32`], [`f64`].
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemNumTy<'ast> {
-    _ast: PhantomData<&'ast ()>,
+    data: CommonSemTyData<'ast>,
     numeric_kind: NumKind,
 }
 
+super::impl_sem_ty_data!(SemNumTy<'ast>);
+
 #[cfg(feature = "driver-api")]
 impl<'ast> SemNumTy<'ast> {
-    pub fn new(numeric_kind: NumKind) -> Self {
-        Self {
-            _ast: PhantomData,
-            numeric_kind,
-        }
+    pub fn new(data: CommonSemTyData<'ast>, numeric_kind: NumKind) -> Self {
+        Self { data, numeric_kind }
     }
 }
 
@@ -242,18 +241,18 @@ impl<'ast> SynTextTy<'ast> {
 
 /// The semantic representation of a textual type like [`char`] or [`str`].
 #[repr(C)]
+#[derive(PartialEq, Eq, Hash)]
 pub struct SemTextTy<'ast> {
-    _ast: PhantomData<&'ast ()>,
+    data: CommonSemTyData<'ast>,
     textual_kind: TextKind,
 }
 
+super::impl_sem_ty_data!(SemTextTy<'ast>);
+
 #[cfg(feature = "driver-api")]
 impl<'ast> SemTextTy<'ast> {
-    pub fn new(textual_kind: TextKind) -> Self {
-        Self {
-            _ast: PhantomData,
-            textual_kind,
-        }
+    pub fn new(data: CommonSemTyData<'ast>, textual_kind: TextKind) -> Self {
+        Self { data, textual_kind }
     }
 }
 
@@ -317,14 +316,17 @@ impl<'ast> SynNeverTy<'ast> {
 
 /// The semantic representation of the never type [`!`](prim@never).
 #[repr(C)]
+#[derive(PartialEq, Eq, Hash)]
 pub struct SemNeverTy<'ast> {
-    _lt: PhantomData<&'ast ()>,
+    data: CommonSemTyData<'ast>,
 }
 
+super::impl_sem_ty_data!(SemNeverTy<'ast>);
+
 #[cfg(feature = "driver-api")]
 impl<'ast> SemNeverTy<'ast> {
-    pub fn new() -> Self {
-        Self { _lt: PhantomData }
+    pub fn new(data: CommonSemTyData<'ast>) -> Self {
+        Self { data }
     }
 }
 
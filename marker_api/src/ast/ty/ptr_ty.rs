@@ -3,7 +3,7 @@ use crate::{
     ffi::{FfiOption, FfiSlice},
 };
 
-use super::{CommonSynTyData, SemTyKind, SynTyKind};
+use super::{CommonSemTyData, CommonSynTyData, SemTyKind, SynTyKind};
 
 /// The syntactic representation of a reference like [`&T`](prim@reference)
 /// or [`&mut T`](prim@reference)
@@ -57,12 +57,15 @@ impl<'ast> SynRefTy<'ast> {
 /// Marker currently doesn't support the analysis of lifetimes. Removing them
 /// from the type also simplifies type comparisons.
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemRefTy<'ast> {
+    data: CommonSemTyData<'ast>,
     mutability: Mutability,
     inner_ty: SemTyKind<'ast>,
 }
 
+super::impl_sem_ty_data!(SemRefTy<'ast>);
+
 impl<'ast> SemRefTy<'ast> {
     /// This returns the [`Mutability`] of the referenced type.
     pub fn mutability(&self) -> Mutability {
@@ -77,8 +80,12 @@ impl<'ast> SemRefTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemRefTy<'ast> {
-    pub fn new(mutability: Mutability, inner_ty: SemTyKind<'ast>) -> Self {
-        Self { mutability, inner_ty }
+    pub fn new(data: CommonSemTyData<'ast>, mutability: Mutability, inner_ty: SemTyKind<'ast>) -> Self {
+        Self {
+            data,
+            mutability,
+            inner_ty,
+        }
     }
 }
 
@@ -118,12 +125,15 @@ impl<'ast> SynRawPtrTy<'ast> {
 /// The semantic representation of a raw pointer like [`*const T`](prim@pointer)
 /// or [`*mut T`](prim@pointer)
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemRawPtrTy<'ast> {
+    data: CommonSemTyData<'ast>,
     mutability: Mutability,
     inner_ty: SemTyKind<'ast>,
 }
 
+super::impl_sem_ty_data!(SemRawPtrTy<'ast>);
+
 impl<'ast> SemRawPtrTy<'ast> {
     pub fn mutability(&self) -> Mutability {
         self.mutability
@@ -136,8 +146,12 @@ impl<'ast> SemRawPtrTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemRawPtrTy<'ast> {
-    pub fn new(mutability: Mutability, inner_ty: SemTyKind<'ast>) -> Self {
-        Self { mutability, inner_ty }
+    pub fn new(data: CommonSemTyData<'ast>, mutability: Mutability, inner_ty: SemTyKind<'ast>) -> Self {
+        Self {
+            data,
+            mutability,
+            inner_ty,
+        }
     }
 }
 
@@ -162,14 +176,17 @@ impl_callable_data_trait!(SynFnPtrTy<'ast>);
 
 /// The semantic representation of a function pointer, like [`fn (T) -> U`](prim@fn)
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemFnPtrTy<'ast> {
+    data: CommonSemTyData<'ast>,
     safety: Safety,
     abi: Abi,
     params: FfiSlice<'ast, SemTyKind<'ast>>,
     return_ty: SemTyKind<'ast>,
 }
 
+super::impl_sem_ty_data!(SemFnPtrTy<'ast>);
+
 impl<'ast> SemFnPtrTy<'ast> {
     pub fn safety(&self) -> Safety {
         self.safety
@@ -190,8 +207,15 @@ impl<'ast> SemFnPtrTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemFnPtrTy<'ast> {
-    pub fn new(safety: Safety, abi: Abi, params: &'ast [SemTyKind<'ast>], return_ty: SemTyKind<'ast>) -> Self {
+    pub fn new(
+        data: CommonSemTyData<'ast>,
+        safety: Safety,
+        abi: Abi,
+        params: &'ast [SemTyKind<'ast>],
+        return_ty: SemTyKind<'ast>,
+    ) -> Self {
         Self {
+            data,
             safety,
             abi,
             params: params.into(),
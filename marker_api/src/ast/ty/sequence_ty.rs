@@ -3,7 +3,7 @@ use crate::{
     ffi::{FfiOption, FfiSlice},
 };
 
-use super::{CommonSynTyData, SemTyKind, SynTyKind};
+use super::{CommonSemTyData, CommonSynTyData, SemTyKind, SynTyKind};
 
 /// The syntactic representation of a tuple type like [`()`](prim@tuple) or [`(T, U)`](prim@tuple)
 #[repr(C)]
@@ -45,11 +45,14 @@ impl<'ast> std::fmt::Display for SynTupleTy<'ast> {
 
 /// The semantic representation of a tuple type like [`()`](prim@tuple) or [`(T, U)`](prim@tuple)
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemTupleTy<'ast> {
+    data: CommonSemTyData<'ast>,
     types: FfiSlice<'ast, SemTyKind<'ast>>,
 }
 
+super::impl_sem_ty_data!(SemTupleTy<'ast>);
+
 impl<'ast> SemTupleTy<'ast> {
     pub fn types(&self) -> &[SemTyKind<'ast>] {
         self.types.as_slice()
@@ -58,8 +61,11 @@ impl<'ast> SemTupleTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemTupleTy<'ast> {
-    pub fn new(types: &'ast [SemTyKind<'ast>]) -> Self {
-        Self { types: types.into() }
+    pub fn new(data: CommonSemTyData<'ast>, types: &'ast [SemTyKind<'ast>]) -> Self {
+        Self {
+            data,
+            types: types.into(),
+        }
     }
 }
 
@@ -106,10 +112,14 @@ impl<'ast> std::fmt::Display for SynSliceTy<'ast> {
 
 /// The semantic representation of a variable length slice like [`[T]`](prim@slice)
 #[repr(C)]
+#[derive(PartialEq, Eq, Hash)]
 pub struct SemSliceTy<'ast> {
+    data: CommonSemTyData<'ast>,
     inner_ty: SemTyKind<'ast>,
 }
 
+super::impl_sem_ty_data!(SemSliceTy<'ast>);
+
 impl<'ast> SemSliceTy<'ast> {
     pub fn inner_ty(&self) -> SemTyKind<'ast> {
         self.inner_ty
@@ -118,8 +128,8 @@ impl<'ast> SemSliceTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemSliceTy<'ast> {
-    pub fn new(inner_ty: SemTyKind<'ast>) -> Self {
-        Self { inner_ty }
+    pub fn new(data: CommonSemTyData<'ast>, inner_ty: SemTyKind<'ast>) -> Self {
+        Self { data, inner_ty }
     }
 }
 
@@ -172,26 +182,35 @@ impl<'ast> std::fmt::Display for SynArrayTy<'ast> {
 
 /// The semantic representation of an array with a known size like: [`[T; N]`](prim@array)
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Hash)]
 pub struct SemArrayTy<'ast> {
+    data: CommonSemTyData<'ast>,
     inner_ty: SemTyKind<'ast>,
     len: ConstValue<'ast>,
 }
 
+super::impl_sem_ty_data!(SemArrayTy<'ast>);
+
 impl<'ast> SemArrayTy<'ast> {
     pub fn inner_ty(&self) -> SemTyKind<'ast> {
         self.inner_ty
     }
 
+    /// The length of the array. This will be [`ConstValue::Unknown`], if the
+    /// length depends on a generic parameter.
     pub fn len(&self) -> &ConstValue<'ast> {
         &self.len
     }
 }
 
+// Array lengths are always integers. The reflexivity of floats is therefore
+// not an issue.
+impl<'ast> Eq for SemArrayTy<'ast> {}
+
 #[cfg(feature = "driver-api")]
 impl<'ast> SemArrayTy<'ast> {
-    pub fn new(inner_ty: SemTyKind<'ast>, len: ConstValue<'ast>) -> Self {
-        Self { inner_ty, len }
+    pub fn new(data: CommonSemTyData<'ast>, inner_ty: SemTyKind<'ast>, len: ConstValue<'ast>) -> Self {
+        Self { data, inner_ty, len }
     }
 }
 
@@ -1,9 +1,13 @@
 use crate::{
-    ast::generic::{SemTraitBound, SynTyParamBound},
+    ast::{
+        generic::{SemGenericArgs, SemTraitBound, SynTyParamBound},
+        ItemId,
+    },
+    context::with_cx,
     ffi::FfiSlice,
 };
 
-use super::CommonSynTyData;
+use super::{CommonSemTyData, CommonSynTyData};
 
 #[repr(C)]
 #[derive(Debug)]
@@ -62,11 +66,14 @@ impl<'ast> SynTraitObjTy<'ast> {
 ///
 /// [trait object]: https://doc.rust-lang.org/reference/types/trait-object.html
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemTraitObjTy<'ast> {
+    data: CommonSemTyData<'ast>,
     bound: FfiSlice<'ast, SemTraitBound<'ast>>,
 }
 
+super::impl_sem_ty_data!(SemTraitObjTy<'ast>);
+
 impl<'ast> SemTraitObjTy<'ast> {
     pub fn bounds(&self) -> &[SemTraitBound<'ast>] {
         self.bound.get()
@@ -75,7 +82,70 @@ impl<'ast> SemTraitObjTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemTraitObjTy<'ast> {
-    pub fn new(bound: &'ast [SemTraitBound<'ast>]) -> Self {
-        Self { bound: bound.into() }
+    pub fn new(data: CommonSemTyData<'ast>, bound: &'ast [SemTraitBound<'ast>]) -> Self {
+        Self {
+            data,
+            bound: bound.into(),
+        }
+    }
+}
+
+/// The semantic representation of an opaque type. Opaque types are created
+/// for `impl Trait` types in return position and for `async` functions. Only
+/// the bounds of the opaque type are visible to callers.
+///
+/// ```
+/// // The return type is an opaque type with the bound `Iterator<Item = u8>`
+/// fn numbers() -> impl Iterator<Item = u8> {
+///     0..16
+/// }
+///
+/// // The return type is an opaque type with the bound `Future<Output = u8>`
+/// async fn number() -> u8 {
+///     16
+/// }
+/// ```
+///
+/// The hidden type, like the [coroutine](super::SemCoroutineTy) of an `async`
+/// function, can be revealed with [`SemTyKind::peel_aliases`](super::SemTyKind::peel_aliases).
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct SemOpaqueTy<'ast> {
+    data: CommonSemTyData<'ast>,
+    opaque_item: ItemId,
+    generics: SemGenericArgs<'ast>,
+}
+
+super::impl_sem_ty_data!(SemOpaqueTy<'ast>);
+
+impl<'ast> SemOpaqueTy<'ast> {
+    /// This [`ItemId`] identifies the item that defined the opaque type.
+    pub fn opaque_item(&self) -> ItemId {
+        self.opaque_item
+    }
+
+    /// This returns the [`SemGenericArgs`] used by the opaque type. These are
+    /// the generic arguments of the item, which defined the opaque type.
+    pub fn generics(&self) -> &SemGenericArgs<'ast> {
+        &self.generics
+    }
+
+    /// The trait bounds of the opaque type, with the generic arguments of
+    /// this instance. Bindings, like the `Item = u8` in
+    /// `impl Iterator<Item = u8>`, are part of the generic arguments of the
+    /// bound trait. The implicit `Sized` bound is not included.
+    pub fn bounds(&self) -> &[SemTraitBound<'ast>] {
+        with_cx(self, |cx| cx.opaque_bounds(self.data.driver_id))
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemOpaqueTy<'ast> {
+    pub fn new(data: CommonSemTyData<'ast>, opaque_item: ItemId, generics: SemGenericArgs<'ast>) -> Self {
+        Self {
+            data,
+            opaque_item,
+            generics,
+        }
     }
 }
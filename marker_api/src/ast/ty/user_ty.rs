@@ -1,8 +1,10 @@
-use std::marker::PhantomData;
+use crate::{
+    ast::{generic::SemGenericArgs, AstQPath, GenericId, ItemId, TyDefId},
+    context::with_cx,
+    ffi::FfiOption,
+};
 
-use crate::ast::{generic::SemGenericArgs, AstQPath, GenericId, ItemId, TyDefId};
-
-use super::CommonSynTyData;
+use super::{CommonSemTyData, CommonSynTyData, SemAdtDef, SemTyKind};
 
 /// A type identified via a [`AstQPath`]. The kind and definition can be
 /// accessed via the ID returned by [`AstQPath::resolve()`].
@@ -42,12 +44,15 @@ impl<'ast> SynPathTy<'ast> {
 /// [`Enum`]: https://doc.rust-lang.org/reference/types/enum.html
 /// [`Union`]: https://doc.rust-lang.org/reference/types/union.html
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemAdtTy<'ast> {
+    data: CommonSemTyData<'ast>,
     def_id: TyDefId,
     generics: SemGenericArgs<'ast>,
 }
 
+super::impl_sem_ty_data!(SemAdtTy<'ast>);
+
 impl<'ast> SemAdtTy<'ast> {
     /// This returns the [`TyDefId`] of the abstract data type.
     pub fn def_id(&self) -> TyDefId {
@@ -58,12 +63,17 @@ impl<'ast> SemAdtTy<'ast> {
     pub fn generics(&self) -> &SemGenericArgs<'ast> {
         &self.generics
     }
+
+    /// This returns the definition of the abstract data type.
+    pub fn def(&self) -> &SemAdtDef<'ast> {
+        with_cx(self, |cx| cx.adt_def(self.def_id)).expect("the id of an ADT should always belong to an ADT")
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemAdtTy<'ast> {
-    pub fn new(def_id: TyDefId, generics: SemGenericArgs<'ast>) -> Self {
-        Self { def_id, generics }
+    pub fn new(data: CommonSemTyData<'ast>, def_id: TyDefId, generics: SemGenericArgs<'ast>) -> Self {
+        Self { data, def_id, generics }
     }
 }
 
@@ -76,12 +86,14 @@ impl<'ast> SemAdtTy<'ast> {
 /// }
 /// ```
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemGenericTy<'ast> {
-    _lifetime: PhantomData<&'ast ()>,
+    data: CommonSemTyData<'ast>,
     generic_id: GenericId,
 }
 
+super::impl_sem_ty_data!(SemGenericTy<'ast>);
+
 impl<'ast> SemGenericTy<'ast> {
     /// This returns the [`GenericId`] assigned to the generic parameter.
     /// This id can be used to retrieve more information from the item that
@@ -93,11 +105,39 @@ impl<'ast> SemGenericTy<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemGenericTy<'ast> {
-    pub fn new(generic_id: GenericId) -> Self {
-        Self {
-            _lifetime: PhantomData,
-            generic_id,
-        }
+    pub fn new(data: CommonSemTyData<'ast>, generic_id: GenericId) -> Self {
+        Self { data, generic_id }
+    }
+}
+
+/// The semantic representation of a foreign type, declared in an `extern` block:
+///
+/// ```ignore
+/// extern "C" {
+///     type Opaque;
+/// }
+/// ```
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct SemForeignTy<'ast> {
+    data: CommonSemTyData<'ast>,
+    foreign_item: ItemId,
+}
+
+super::impl_sem_ty_data!(SemForeignTy<'ast>);
+
+impl<'ast> SemForeignTy<'ast> {
+    /// This [`ItemId`] identifies the [`ForeignTyItem`](crate::ast::item::ForeignTyItem)
+    /// that declared the type.
+    pub fn foreign_item(&self) -> ItemId {
+        self.foreign_item
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemForeignTy<'ast> {
+    pub fn new(data: CommonSemTyData<'ast>, foreign_item: ItemId) -> Self {
+        Self { data, foreign_item }
     }
 }
 
@@ -105,27 +145,59 @@ impl<'ast> SemGenericTy<'ast> {
 ///
 /// Aliases in semantic type representations are usually resolved directly. This
 /// kind, is primarily used for instances, where the concrete aliased type is not yet
-/// known.
+/// known, like the associated type in `<I as Iterator>::Item`.
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SemAliasTy<'ast> {
-    _lifetime: PhantomData<&'ast ()>,
+    data: CommonSemTyData<'ast>,
     alias_item: ItemId,
+    self_ty: FfiOption<SemTyKind<'ast>>,
+    trait_generics: SemGenericArgs<'ast>,
+    generics: SemGenericArgs<'ast>,
 }
 
+super::impl_sem_ty_data!(SemAliasTy<'ast>);
+
 impl<'ast> SemAliasTy<'ast> {
     /// This [`ItemId`] identifies the item that defined the alias
     pub fn alias_item(&self) -> ItemId {
         self.alias_item
     }
+
+    /// The `Self` type of associated types, like the `I` in `<I as Iterator>::Item`.
+    /// This is `None` for other aliases.
+    pub fn self_ty(&self) -> Option<SemTyKind<'ast>> {
+        self.self_ty.copy()
+    }
+
+    /// The generic args of the trait, which declares the associated type, without
+    /// the `Self` type. These are empty for other aliases.
+    pub fn trait_generics(&self) -> &SemGenericArgs<'ast> {
+        &self.trait_generics
+    }
+
+    /// The generic args of the alias itself, like the `'a` in
+    /// `<T as Lending>::Item<'a>`.
+    pub fn generics(&self) -> &SemGenericArgs<'ast> {
+        &self.generics
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemAliasTy<'ast> {
-    pub fn new(alias_item: ItemId) -> Self {
+    pub fn new(
+        data: CommonSemTyData<'ast>,
+        alias_item: ItemId,
+        self_ty: Option<SemTyKind<'ast>>,
+        trait_generics: SemGenericArgs<'ast>,
+        generics: SemGenericArgs<'ast>,
+    ) -> Self {
         Self {
-            _lifetime: PhantomData,
+            data,
             alias_item,
+            self_ty: self_ty.into(),
+            trait_generics,
+            generics,
         }
     }
 }
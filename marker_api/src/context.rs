@@ -6,10 +6,12 @@ use std::{cell::RefCell, mem::transmute};
 
 use crate::{
     ast::{
+        expr::{Adjustment, ClosureCaptures, ClosureExpr, DesugarKind, ExprData, ExprKind},
+        generic::{SemGenericArgs, SemGenericParam, SemPredicate, SemTraitBound},
         item::{Body, ItemKind},
-        ty::SemTyKind,
-        BodyId, ExpnId, ExpnInfo, ExprId, FileInfo, FilePos, ItemId, Span, SpanId, SpanPos, SpanSource, SymbolId,
-        TyDefId,
+        ty::{Layout, SemAdtDef, SemFnSig, SemTyKind},
+        BodyId, Comment, ConstValue, Docs, DriverTyId, ExpnId, ExpnInfo, ExprId, FileInfo, FilePos, GenericId, ItemId,
+        ItemPath, Span, SpanId, SpanPos, SpanSource, SymbolId, TyDefId,
     },
     diagnostic::{Diagnostic, DiagnosticBuilder, EmissionNode},
     ffi,
@@ -131,7 +133,9 @@ impl<'ast> AstContext<'ast> {
             return;
         }
         let node = node.into();
-        if self.lint_level_at(lint, node) != Level::Allow {
+        if self.lint_level_at(lint, node) == Level::Allow {
+            self.driver.call_lint_suppressed(lint, node);
+        } else {
             let mut builder = DiagnosticBuilder::new(lint, node, msg.to_string(), span.clone());
             decorate(&mut builder);
             builder.emit(self);
@@ -146,7 +150,8 @@ impl<'ast> AstContext<'ast> {
     /// return `None` in special cases depending on the used driver.
     ///
     /// #### Driver information
-    /// * Rustc's driver will always return a valid item.
+    /// * Rustc's driver will always return a valid item for ids of the current crate. This includes
+    ///   associated items and items of `extern` blocks.
     pub fn item(&self, id: ItemId) -> Option<ItemKind<'ast>> {
         self.driver.call_item(id)
     }
@@ -155,6 +160,51 @@ impl<'ast> AstContext<'ast> {
         self.driver.call_body(id)
     }
 
+    /// Returns the [`ItemId`] of the trait item, which is implemented by the
+    /// given associated item of a trait implementation.
+    ///
+    /// [`None`] is returned, if the item is not part of a trait implementation.
+    /// Inherent associated items and the items of the trait itself, will also
+    /// return [`None`].
+    ///
+    /// This can be used to check, if an associated function overrides a
+    /// provided method of the trait:
+    /// ```ignore
+    /// if let Some(trait_fn_id) = cx.trait_item_for_impl_item(fn_item.id()) {
+    ///     if let Some(ItemKind::Fn(trait_fn)) = cx.item(trait_fn_id) {
+    ///         if trait_fn.body_id().is_some() {
+    ///             // `fn_item` overrides a provided method
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn trait_item_for_impl_item(&self, id: ItemId) -> Option<ItemId> {
+        self.driver.call_trait_item_for_impl_item(id)
+    }
+
+    /// This function tries to evaluate the given expression at compile time.
+    ///
+    /// Expressions of a [`ConstExpr`](crate::ast::expr::ConstExpr) will be
+    /// evaluated like the compiler would. Other expressions are evaluated on a
+    /// best-effort basis, if they only consist of literals, constant items and
+    /// simple operations on them.
+    ///
+    /// `None` will be returned, if the value can't be evaluated or isn't
+    /// representable as a [`ConstValue`]. This is also the case, if the evaluation
+    /// of the expression would overflow, or if the value contains an array with
+    /// more than 65536 elements.
+    ///
+    /// ```ignore
+    /// if let Some(len) = array_ty.len() {
+    ///     if let Some(len) = cx.eval_const(len.expr()).and_then(|value| value.as_u128()) {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub fn eval_const(&self, expr: ExprKind<'ast>) -> Option<ConstValue<'ast>> {
+        self.driver.call_eval_const(expr.id())
+    }
+
     /// This function tries to resolve the given path to the corresponding [`TyDefId`].
     ///
     /// The slice might be empty if the path could not be resolved. This could be
@@ -179,6 +229,226 @@ impl<'ast> AstContext<'ast> {
     pub fn resolve_ty_ids(&self, path: &str) -> &[TyDefId] {
         (self.driver.resolve_ty_ids)(self.driver.driver_context, path.into()).get()
     }
+
+    /// This function tries to resolve the given path to the corresponding [`ItemId`]s.
+    ///
+    /// It works like [`AstContext::resolve_ty_ids`], but can also resolve functions,
+    /// constants, statics, traits, modules and associated items of types and
+    /// traits. The returned slice might be empty, if the path could not be resolved.
+    /// It can also contain multiple [`ItemId`]s, if there are multiple crates with
+    /// different versions in the dependency tree.
+    ///
+    /// The returned ids are unordered and, depending on the driver, can also
+    /// change during different calls. The slice should not be stored across
+    /// `check_*` calls.
+    ///
+    /// ```ignore
+    /// // Check if a function call calls a specific function
+    /// if let ExprKind::Call(call) = expr {
+    ///     if let ExprKind::Path(path) = call.func() {
+    ///         if let Some(AstPathTarget::Item(id)) = path.resolve() {
+    ///             if cx.resolve_item_ids("std::mem::forget").contains(&id) {
+    ///                 // ...
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve_item_ids(&self, path: &str) -> &[ItemId] {
+        self.driver.call_resolve_item_ids(path)
+    }
+
+    /// Returns the canonical [`ItemPath`] of the given item. This also works for
+    /// items of external crates.
+    ///
+    /// ```ignore
+    /// if cx.item_path(fn_item.id()).as_str() == "my_crate::net::Client::connect" {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn item_path(&self, id: ItemId) -> &ItemPath<'ast> {
+        self.driver.call_item_path(id)
+    }
+
+    /// Returns the canonical [`ItemPath`] of the given type definition. This is
+    /// useful to print the path of [`SemTyKind`]s in diagnostics.
+    pub fn ty_def_path(&self, id: TyDefId) -> &ItemPath<'ast> {
+        self.driver.call_ty_def_path(id)
+    }
+
+    /// Returns the definition of the abstract data type with the given id. This
+    /// works for local and external types. [`None`] is returned, if the id
+    /// doesn't belong to a struct, enum or union.
+    ///
+    /// ```ignore
+    /// if let SemTyKind::Adt(adt) = expr.ty() {
+    ///     let def = cx.adt_def(adt.def_id()).unwrap();
+    ///     for field in def.fields().iter().filter(|field| field.is_pub()) {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub fn adt_def(&self, id: TyDefId) -> Option<&SemAdtDef<'ast>> {
+        self.driver.call_adt_def(id)
+    }
+
+    /// Returns the declared signature of the function with the given id. This
+    /// works for local and external functions, including associated functions.
+    /// [`None`] is returned, if the id doesn't belong to a function.
+    pub fn fn_sig(&self, id: ItemId) -> Option<&SemFnSig<'ast>> {
+        self.driver.call_fn_sig(id)
+    }
+
+    /// Returns the semantic predicates of the item with the given id. These
+    /// are the trait bounds, outlives bounds and projection equalities, which
+    /// have to hold for the item to be usable. This includes predicates
+    /// inherited from the parent item, like the `impl` block or trait of an
+    /// associated item, and implied predicates, like the implicit `Sized`
+    /// bounds and the bounds of supertraits. The [`PredicateSource`] of each
+    /// predicate tells where it comes from. An empty slice is returned, if the
+    /// item can't have predicates, like modules or `use` items.
+    ///
+    /// ```ignore
+    /// for predicate in cx.predicates_of(item.id()) {
+    ///     if let SemPredicateKind::Trait(trait_pred) = predicate.kind() {
+    ///         if predicate.source() == PredicateSource::Declared
+    ///             && cx.resolve_ty_ids("core::marker::Sized").contains(&trait_pred.bound().trait_id())
+    ///         {
+    ///             // Lint unnecessary `Sized` bounds
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`PredicateSource`]: crate::ast::generic::PredicateSource
+    pub fn predicates_of(&self, id: ItemId) -> &[SemPredicate<'ast>] {
+        self.driver.call_predicates_of(id)
+    }
+
+    /// Returns the generic parameter with the given id, including its name,
+    /// kind and [bounds](SemGenericParam::bounds).
+    pub fn generic_param(&self, id: GenericId) -> &SemGenericParam<'ast> {
+        self.driver.call_generic_param(id)
+    }
+
+    /// Returns the memory [`Layout`] of the given semantic type. [`None`] is
+    /// returned, if the layout can't be computed. This is the case for types,
+    /// which depend on generic parameters, and for types provided by Marker,
+    /// which don't originate from the compiler.
+    ///
+    /// ```ignore
+    /// if let Some(layout) = cx.layout_of(expr.ty()) {
+    ///     if layout.size() > 512 {
+    ///         // Lint large values
+    ///     }
+    /// }
+    /// ```
+    pub fn layout_of(&self, ty: SemTyKind<'ast>) -> Option<&Layout<'ast>> {
+        self.driver.call_layout_of(ty)
+    }
+
+    /// Returns the [`Adjustment`]s, which the compiler applied to the given
+    /// expression, in the order they're applied. The type returned by
+    /// [`ExprData::ty`] is the type before
+    /// these adjustments. An empty slice is returned, if the expression is used
+    /// as is.
+    ///
+    /// ```ignore
+    /// // `vec.len()` auto-borrows the receiver
+    /// let adjustments = cx.expr_adjustments(method.receiver().id());
+    /// if matches!(adjustments, [adj] if matches!(adj.kind(), AdjustmentKind::Borrow(_))) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn expr_adjustments(&self, expr: ExprId) -> &[Adjustment<'ast>] {
+        self.driver.call_expr_adjustments(expr)
+    }
+
+    /// Returns the semantic type of the given expression, after all
+    /// [adjustments](AstContext::expr_adjustments) have been applied.
+    pub fn expr_ty_adjusted(&self, expr: ExprId) -> SemTyKind<'ast> {
+        self.expr_adjustments(expr)
+            .last()
+            .map_or_else(|| self.expr_ty(expr), Adjustment::target)
+    }
+
+    /// Returns the places captured by the given closure, and whether it
+    /// implements `Fn`, `FnMut` or `FnOnce`.
+    ///
+    /// ```ignore
+    /// let captures = cx.closure_captures(closure);
+    /// for capture in captures.captures() {
+    ///     if capture.mode() == CaptureMode::Value {
+    ///         if let Some(layout) = cx.layout_of(capture.ty()) {
+    ///             // Lint closures capturing large values by value
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn closure_captures(&self, closure: &ClosureExpr<'ast>) -> &ClosureCaptures<'ast> {
+        self.driver.call_closure_captures(closure.id())
+    }
+
+    /// Returns the kind of syntactic sugar, which the given expression
+    /// originates from. [`None`] is returned, if the expression was written
+    /// as is, like a `match` expression in user code. Expressions, which are
+    /// resugared by Marker, like [`ForExpr`](crate::ast::expr::ForExpr),
+    /// always return their [`DesugarKind`].
+    ///
+    /// ```ignore
+    /// if let ExprKind::Match(expr) = expr {
+    ///     if cx.expr_desugaring(expr.id()).is_none() {
+    ///         // The `match` was written by the user
+    ///     }
+    /// }
+    /// ```
+    pub fn expr_desugaring(&self, expr: ExprId) -> Option<DesugarKind> {
+        self.driver.call_expr_desugaring(expr)
+    }
+
+    /// Returns all [`Comment`]s, which are completely inside the given [`Span`],
+    /// in the order they appear in the source code.
+    ///
+    /// ```ignore
+    /// for comment in cx.comments_in(body.expr().span()) {
+    ///     if comment.content().trim_start().starts_with("TODO") {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub fn comments_in(&self, span: &Span<'ast>) -> &[Comment<'ast>] {
+        self.driver.call_comments_in(span)
+    }
+
+    /// Returns all [`Comment`]s of the file, which contains the given [`Span`],
+    /// in the order they appear in the source code.
+    pub fn comments_in_file(&self, span: &Span<'ast>) -> &[Comment<'ast>] {
+        self.driver.call_comments_in_file(span)
+    }
+
+    /// Returns the [`Comment`]s directly before the given node, in the order
+    /// they appear in the source code. Comments are directly before a node, if
+    /// they are only separated by whitespace without empty lines. Attributes of
+    /// the node, including doc comments, are skipped. Trailing comments of
+    /// previous code, like `foo(); // Comment`, and inner doc comments don't
+    /// belong to the next node.
+    ///
+    /// ```ignore
+    /// // SAFETY: `ptr` is valid, since ...
+    /// unsafe { *ptr }
+    /// ```
+    ///
+    /// This can be used to check that every `unsafe` block has a `// SAFETY:` comment:
+    ///
+    /// ```ignore
+    /// let has_safety_comment = cx
+    ///     .comments_before(block.id())
+    ///     .iter()
+    ///     .any(|comment| comment.content().trim_start().starts_with("SAFETY:"));
+    /// ```
+    pub fn comments_before(&self, node: impl Into<EmissionNode>) -> &[Comment<'ast>] {
+        self.driver.call_comments_before(node.into())
+    }
 }
 
 impl<'ast> AstContext<'ast> {
@@ -186,6 +456,26 @@ impl<'ast> AstContext<'ast> {
         self.driver.call_expr_ty(expr)
     }
 
+    pub(crate) fn peel_aliases(&self, ty: SemTyKind<'ast>) -> SemTyKind<'ast> {
+        (self.driver.peel_aliases)(self.driver.driver_context, ty)
+    }
+
+    pub(crate) fn subst_generics(&self, ty: SemTyKind<'ast>, args: &SemGenericArgs<'ast>) -> SemTyKind<'ast> {
+        (self.driver.subst_generics)(self.driver.driver_context, ty, args)
+    }
+
+    pub(crate) fn generic_name(&self, id: GenericId) -> &'ast str {
+        self.symbol_str((self.driver.generic_name)(self.driver.driver_context, id))
+    }
+
+    pub(crate) fn opaque_bounds(&self, ty: DriverTyId) -> &'ast [SemTraitBound<'ast>] {
+        (self.driver.opaque_bounds)(self.driver.driver_context, ty).get()
+    }
+
+    pub(crate) fn coroutine_interior_tys(&self, ty: DriverTyId) -> &'ast [SemTyKind<'ast>] {
+        (self.driver.coroutine_interior_tys)(self.driver.driver_context, ty).get()
+    }
+
     // FIXME: This function should probably be removed in favor of a better
     // system to deal with spans. See rust-marker/marker#175
     pub(crate) fn span_snipped(&self, span: &Span<'ast>) -> Option<&'ast str> {
@@ -194,6 +484,10 @@ impl<'ast> AstContext<'ast> {
             .map(ffi::FfiStr::get)
     }
 
+    pub(crate) fn docs(&self, node: EmissionNode) -> &'ast Docs<'ast> {
+        (self.driver.docs)(self.driver.driver_context, node)
+    }
+
     pub(crate) fn span(&self, span_id: SpanId) -> &'ast Span<'ast> {
         self.driver.call_span(span_id)
     }
@@ -212,7 +506,6 @@ impl<'ast> AstContext<'ast> {
         self.driver.call_symbol_str(sym)
     }
 
-    #[allow(unused)] // Will be used later(or removed)
     pub(crate) fn resolve_method_target(&self, expr: ExprId) -> ItemId {
         self.driver.resolve_method_target(expr)
     }
@@ -249,15 +542,40 @@ struct DriverCallbacks<'ast> {
     // Lint emission and information
     pub lint_level_at: extern "C" fn(&'ast (), &'static Lint, EmissionNode) -> Level,
     pub emit_diag: for<'a> extern "C" fn(&'ast (), &'a Diagnostic<'a, 'ast>),
+    /// Informs the driver, that a lint emission has been suppressed, since the
+    /// lint is allowed at the given node.
+    pub lint_suppressed: extern "C" fn(&'ast (), &'static Lint, EmissionNode),
 
     // Public utility
     pub item: extern "C" fn(&'ast (), id: ItemId) -> ffi::FfiOption<ItemKind<'ast>>,
     pub body: extern "C" fn(&'ast (), id: BodyId) -> &'ast Body<'ast>,
+    pub trait_item_for_impl_item: extern "C" fn(&'ast (), id: ItemId) -> ffi::FfiOption<ItemId>,
 
     pub resolve_ty_ids: extern "C" fn(&'ast (), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, TyDefId>,
+    pub resolve_item_ids: extern "C" fn(&'ast (), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, ItemId>,
+    pub item_path: extern "C" fn(&'ast (), ItemId) -> &'ast ItemPath<'ast>,
+    pub ty_def_path: extern "C" fn(&'ast (), TyDefId) -> &'ast ItemPath<'ast>,
+    pub eval_const: extern "C" fn(&'ast (), ExprId) -> ffi::FfiOption<&'ast ConstValue<'ast>>,
+    pub adt_def: extern "C" fn(&'ast (), TyDefId) -> ffi::FfiOption<&'ast SemAdtDef<'ast>>,
+    pub fn_sig: extern "C" fn(&'ast (), ItemId) -> ffi::FfiOption<&'ast SemFnSig<'ast>>,
+    pub predicates_of: extern "C" fn(&'ast (), ItemId) -> ffi::FfiSlice<'ast, SemPredicate<'ast>>,
+    pub generic_param: extern "C" fn(&'ast (), GenericId) -> &'ast SemGenericParam<'ast>,
+    pub layout_of: extern "C" fn(&'ast (), SemTyKind<'ast>) -> ffi::FfiOption<&'ast Layout<'ast>>,
+    pub expr_adjustments: extern "C" fn(&'ast (), ExprId) -> ffi::FfiSlice<'ast, Adjustment<'ast>>,
+    pub closure_captures: extern "C" fn(&'ast (), ExprId) -> &'ast ClosureCaptures<'ast>,
+    pub expr_desugaring: extern "C" fn(&'ast (), ExprId) -> ffi::FfiOption<DesugarKind>,
+    pub comments_in: extern "C" fn(&'ast (), &Span<'ast>) -> ffi::FfiSlice<'ast, Comment<'ast>>,
+    pub comments_in_file: extern "C" fn(&'ast (), &Span<'ast>) -> ffi::FfiSlice<'ast, Comment<'ast>>,
+    pub comments_before: extern "C" fn(&'ast (), EmissionNode) -> ffi::FfiSlice<'ast, Comment<'ast>>,
 
     // Internal utility
     pub expr_ty: extern "C" fn(&'ast (), ExprId) -> SemTyKind<'ast>,
+    pub peel_aliases: extern "C" fn(&'ast (), SemTyKind<'ast>) -> SemTyKind<'ast>,
+    pub subst_generics: extern "C" fn(&'ast (), SemTyKind<'ast>, &SemGenericArgs<'ast>) -> SemTyKind<'ast>,
+    pub generic_name: extern "C" fn(&'ast (), GenericId) -> SymbolId,
+    pub opaque_bounds: extern "C" fn(&'ast (), DriverTyId) -> ffi::FfiSlice<'ast, SemTraitBound<'ast>>,
+    pub coroutine_interior_tys: extern "C" fn(&'ast (), DriverTyId) -> ffi::FfiSlice<'ast, SemTyKind<'ast>>,
+    pub docs: extern "C" fn(&'ast (), EmissionNode) -> &'ast Docs<'ast>,
     pub span: extern "C" fn(&'ast (), SpanId) -> &'ast Span<'ast>,
     pub span_snippet: extern "C" fn(&'ast (), &Span<'ast>) -> ffi::FfiOption<ffi::FfiStr<'ast>>,
     pub span_source: extern "C" fn(&'ast (), &Span<'_>) -> SpanSource<'ast>,
@@ -276,12 +594,64 @@ impl<'ast> DriverCallbacks<'ast> {
         (self.emit_diag)(self.driver_context, diag);
     }
 
+    fn call_lint_suppressed(&self, lint: &'static Lint, node: EmissionNode) {
+        (self.lint_suppressed)(self.driver_context, lint, node);
+    }
+
     fn call_item(&self, id: ItemId) -> Option<ItemKind<'ast>> {
         (self.item)(self.driver_context, id).copy()
     }
     fn call_body(&self, id: BodyId) -> &'ast Body<'ast> {
         (self.body)(self.driver_context, id)
     }
+    fn call_trait_item_for_impl_item(&self, id: ItemId) -> Option<ItemId> {
+        (self.trait_item_for_impl_item)(self.driver_context, id).copy()
+    }
+    fn call_item_path(&self, id: ItemId) -> &'ast ItemPath<'ast> {
+        (self.item_path)(self.driver_context, id)
+    }
+    fn call_ty_def_path(&self, id: TyDefId) -> &'ast ItemPath<'ast> {
+        (self.ty_def_path)(self.driver_context, id)
+    }
+    fn call_adt_def(&self, id: TyDefId) -> Option<&'ast SemAdtDef<'ast>> {
+        (self.adt_def)(self.driver_context, id).copy()
+    }
+    fn call_fn_sig(&self, id: ItemId) -> Option<&'ast SemFnSig<'ast>> {
+        (self.fn_sig)(self.driver_context, id).copy()
+    }
+    fn call_layout_of(&self, ty: SemTyKind<'ast>) -> Option<&'ast Layout<'ast>> {
+        (self.layout_of)(self.driver_context, ty).copy()
+    }
+    fn call_expr_adjustments(&self, expr: ExprId) -> &'ast [Adjustment<'ast>] {
+        (self.expr_adjustments)(self.driver_context, expr).get()
+    }
+    fn call_eval_const(&self, expr: ExprId) -> Option<ConstValue<'ast>> {
+        (self.eval_const)(self.driver_context, expr).copy().copied()
+    }
+    fn call_resolve_item_ids(&self, path: &str) -> &'ast [ItemId] {
+        (self.resolve_item_ids)(self.driver_context, path.into()).get()
+    }
+    fn call_comments_in(&self, span: &Span<'ast>) -> &'ast [Comment<'ast>] {
+        (self.comments_in)(self.driver_context, span).get()
+    }
+    fn call_comments_in_file(&self, span: &Span<'ast>) -> &'ast [Comment<'ast>] {
+        (self.comments_in_file)(self.driver_context, span).get()
+    }
+    fn call_comments_before(&self, node: EmissionNode) -> &'ast [Comment<'ast>] {
+        (self.comments_before)(self.driver_context, node).get()
+    }
+    fn call_closure_captures(&self, expr: ExprId) -> &'ast ClosureCaptures<'ast> {
+        (self.closure_captures)(self.driver_context, expr)
+    }
+    fn call_expr_desugaring(&self, expr: ExprId) -> Option<DesugarKind> {
+        (self.expr_desugaring)(self.driver_context, expr).copy()
+    }
+    fn call_predicates_of(&self, id: ItemId) -> &'ast [SemPredicate<'ast>] {
+        (self.predicates_of)(self.driver_context, id).get()
+    }
+    fn call_generic_param(&self, id: GenericId) -> &'ast SemGenericParam<'ast> {
+        (self.generic_param)(self.driver_context, id)
+    }
 
     fn call_expr_ty(&self, expr: ExprId) -> SemTyKind<'ast> {
         (self.expr_ty)(self.driver_context, expr)
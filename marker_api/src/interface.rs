@@ -1,7 +1,11 @@
 //! A module responsible for generating and exposing an interface from lint crates.
 //! [`export_lint_pass`](crate::export_lint_pass) is the main macro, from this module.
 
-use crate::{context::AstContext, ffi::FfiSlice, lint::Lint};
+use crate::{
+    context::AstContext,
+    ffi::{FfiSlice, FfiStr},
+    lint::Lint,
+};
 
 /// **!Unstable!**
 /// This struct is used to connect lint crates to drivers.
@@ -12,6 +16,7 @@ pub struct LintCrateBindings {
 
     // lint pass functions
     pub info: for<'ast> extern "C" fn() -> LintPassInfo,
+    pub check_crate: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::Crate<'ast>),
     pub check_item: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::item::ItemKind<'ast>),
     pub check_field: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::item::Field<'ast>),
     pub check_variant: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::item::EnumVariant<'ast>),
@@ -78,6 +83,12 @@ macro_rules! export_lint_pass {
                 extern "C" fn info() -> $crate::LintPassInfo {
                     super::__MARKER_STATE.with(|state| state.borrow_mut().info())
                 }
+                extern "C" fn check_crate<'ast>(
+                    cx: &'ast $crate::AstContext<'ast>,
+                    krate: &'ast $crate::ast::Crate<'ast>,
+                ) {
+                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_crate(cx, krate));
+                }
                 extern "C" fn check_item<'ast>(
                     cx: &'ast $crate::AstContext<'ast>,
                     item: $crate::ast::item::ItemKind<'ast>,
@@ -118,6 +129,7 @@ macro_rules! export_lint_pass {
                 $crate::interface::LintCrateBindings {
                     set_ast_context,
                     info,
+                    check_crate,
                     check_item,
                     check_field,
                     check_variant,
@@ -133,6 +145,8 @@ macro_rules! export_lint_pass {
 #[derive(Debug)]
 pub struct LintPassInfoBuilder {
     lints: &'static [&'static Lint],
+    renamed_lints: Vec<RenamedLint>,
+    removed_lints: Vec<RemovedLint>,
 }
 
 impl LintPassInfoBuilder {
@@ -146,13 +160,60 @@ impl LintPassInfoBuilder {
             // is called. Ideally, it would be cool to just store the `Box` directly but
             // that is sadly not possible due to ABI constraints
             lints: Box::leak(lints),
+            renamed_lints: vec![],
+            removed_lints: vec![],
         }
     }
 
+    /// This method registers that the lint with the `old_name` has been renamed
+    /// to `new_lint`. The name has to be the full name, like `marker::old_name`.
+    ///
+    /// Attributes using the old name will still apply to the new lint. The driver
+    /// will additionally emit a warning, suggesting the new name.
+    ///
+    /// ```ignore
+    /// LintPassInfoBuilder::new(Box::new([NEW_NAME]))
+    ///     .rename_lint("marker::old_name", NEW_NAME)
+    ///     .build()
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `new_lint` is not part of the lints passed to [`LintPassInfoBuilder::new`].
+    #[must_use]
+    pub fn rename_lint(mut self, old_name: &'static str, new_lint: &'static Lint) -> Self {
+        assert!(
+            self.lints.iter().any(|lint| std::ptr::eq(*lint, new_lint)),
+            "the new lint `{}` has to be registered in the `LintPassInfoBuilder`",
+            new_lint.name
+        );
+        self.renamed_lints.push(RenamedLint {
+            old_name: old_name.into(),
+            new_lint,
+        });
+        self
+    }
+
+    /// This method registers that the lint with the given `name` has been removed.
+    /// The name has to be the full name, like `marker::old_name`.
+    ///
+    /// Attributes using this name will cause the driver to emit a warning, which
+    /// contains the given `reason`.
+    #[must_use]
+    pub fn remove_lint(mut self, name: &'static str, reason: &'static str) -> Self {
+        self.removed_lints.push(RemovedLint {
+            name: name.into(),
+            reason: reason.into(),
+        });
+        self
+    }
+
     /// This method builds the [`LintPassInfo`], ready for consumption.
     pub fn build(self) -> LintPassInfo {
         LintPassInfo {
             lints: self.lints.into(),
+            renamed_lints: (&*Box::leak(self.renamed_lints.into_boxed_slice())).into(),
+            removed_lints: (&*Box::leak(self.removed_lints.into_boxed_slice())).into(),
         }
     }
 }
@@ -168,6 +229,8 @@ impl LintPassInfoBuilder {
 #[non_exhaustive]
 pub struct LintPassInfo {
     lints: FfiSlice<'static, &'static Lint>,
+    renamed_lints: FfiSlice<'static, RenamedLint>,
+    removed_lints: FfiSlice<'static, RemovedLint>,
 }
 
 #[cfg(feature = "driver-api")]
@@ -175,4 +238,50 @@ impl LintPassInfo {
     pub fn lints(&self) -> &[&'static Lint] {
         self.lints.get()
     }
+
+    pub fn renamed_lints(&self) -> &[RenamedLint] {
+        self.renamed_lints.get()
+    }
+
+    pub fn removed_lints(&self) -> &[RemovedLint] {
+        self.removed_lints.get()
+    }
+}
+
+/// A lint, which has been renamed, see [`LintPassInfoBuilder::rename_lint`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct RenamedLint {
+    old_name: FfiStr<'static>,
+    new_lint: &'static Lint,
+}
+
+#[cfg(feature = "driver-api")]
+impl RenamedLint {
+    pub fn old_name(&self) -> &'static str {
+        self.old_name.get()
+    }
+
+    pub fn new_lint(&self) -> &'static Lint {
+        self.new_lint
+    }
+}
+
+/// A lint, which has been removed, see [`LintPassInfoBuilder::remove_lint`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct RemovedLint {
+    name: FfiStr<'static>,
+    reason: FfiStr<'static>,
+}
+
+#[cfg(feature = "driver-api")]
+impl RemovedLint {
+    pub fn name(&self) -> &'static str {
+        self.name.get()
+    }
+
+    pub fn reason(&self) -> &'static str {
+        self.reason.get()
+    }
 }
@@ -31,6 +31,9 @@ pub use interface::{LintPassInfo, LintPassInfoBuilder};
 pub trait LintPass {
     fn info(&self) -> LintPassInfo;
 
+    /// This is called once for the linted crate, before any other `check_*`
+    /// function. It can be used to inspect the [`Crate`](ast::Crate) metadata.
+    fn check_crate<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _krate: &'ast ast::Crate<'ast>) {}
     fn check_item<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _item: ast::item::ItemKind<'ast>) {}
     fn check_field<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _field: &'ast ast::item::Field<'ast>) {}
     fn check_variant<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _variant: &'ast ast::item::EnumVariant<'ast>) {}
@@ -3,17 +3,30 @@ use std::cell::{OnceCell, RefCell};
 use marker_adapter::context::{DriverContext, DriverContextWrapper};
 use marker_api::{
     ast::{
+        expr::{Adjustment, ClosureCaptures, DesugarKind},
+        generic::{SemGenericArgs, SemGenericParam, SemPredicate, SemTraitBound},
         item::{Body, ItemKind},
-        BodyId, ExprId, ItemId, Span, SpanId, SymbolId, TyDefId,
+        ty::{Layout, SemAdtDef, SemFnSig, SemTyKind},
+        BodyId, Comment, ConstValue, Docs, DriverTyId, ExprId, GenericId, ItemId, ItemPath, Span, SpanId, SymbolId,
+        TyDefId,
     },
     context::AstContext,
     diagnostic::{Diagnostic, EmissionNode},
     lint::{Level, Lint},
 };
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use rustc_hir as hir;
+use rustc_hir::def::{CtorKind, DefKind};
+use rustc_hir::definitions::DefPathData;
 use rustc_lint::LintStore;
-use rustc_middle::ty::TyCtxt;
+use rustc_lint_defs::LintExpectationId;
+use rustc_middle::lint::LintLevelSource;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::{
+    AssocItemContainer, ConstKind, EarlyBinder, GenericArgKind, GenericArgsRef, ParamEnv, RegionKind, Ty, TyCtxt,
+    TyKind, TypeVisitableExt,
+};
+use rustc_span::Pos;
 
 use crate::conversion::{marker::MarkerConverter, rustc::RustcConverter};
 
@@ -43,6 +56,25 @@ pub struct RustcContext<'ast, 'tcx> {
     /// which makes it safe to access afterwards.
     ast_cx: OnceCell<&'ast AstContext<'ast>>,
     resolved_ty_ids: RefCell<FxHashMap<&'ast str, &'ast [TyDefId]>>,
+    resolved_item_ids: RefCell<FxHashMap<&'ast str, &'ast [ItemId]>>,
+    item_paths: RefCell<FxHashMap<hir::def_id::DefId, &'ast ItemPath<'ast>>>,
+    docs: RefCell<FxHashMap<hir::HirId, &'ast Docs<'ast>>>,
+    adt_defs: RefCell<FxHashMap<hir::def_id::DefId, &'ast SemAdtDef<'ast>>>,
+    fn_sigs: RefCell<FxHashMap<hir::def_id::DefId, &'ast SemFnSig<'ast>>>,
+    predicates: RefCell<FxHashMap<hir::def_id::DefId, &'ast [SemPredicate<'ast>]>>,
+    generic_params: RefCell<FxHashMap<hir::def_id::DefId, &'ast SemGenericParam<'ast>>>,
+    layouts: RefCell<FxHashMap<Ty<'tcx>, Option<&'ast Layout<'ast>>>>,
+    opaque_bounds: RefCell<FxHashMap<DriverTyId, &'ast [SemTraitBound<'ast>]>>,
+    coroutine_interior_tys: RefCell<FxHashMap<DriverTyId, &'ast [SemTyKind<'ast>]>>,
+    /// The comments of source files, stored by the start position of the file.
+    comments: RefCell<FxHashMap<rustc_span::BytePos, &'ast [Comment<'ast>]>>,
+    /// The normalized ids of all lint expectations, which have been fulfilled
+    /// by a diagnostic emitted by a lint crate. Rustc tracks these as well, but
+    /// only gives access to them, once all lint passes are done.
+    fulfilled_expectations: RefCell<FxHashSet<LintExpectationId>>,
+    /// The spans of lint names in `allow` and `expect` attributes, which have
+    /// suppressed at least one diagnostic of a lint crate.
+    used_lint_attrs: RefCell<FxHashSet<rustc_span::Span>>,
 }
 
 impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
@@ -56,6 +88,19 @@ impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
             rustc_converter: RustcConverter::new(rustc_cx),
             ast_cx: OnceCell::new(),
             resolved_ty_ids: RefCell::default(),
+            resolved_item_ids: RefCell::default(),
+            item_paths: RefCell::default(),
+            docs: RefCell::default(),
+            adt_defs: RefCell::default(),
+            fn_sigs: RefCell::default(),
+            predicates: RefCell::default(),
+            generic_params: RefCell::default(),
+            layouts: RefCell::default(),
+            opaque_bounds: RefCell::default(),
+            coroutine_interior_tys: RefCell::default(),
+            comments: RefCell::default(),
+            fulfilled_expectations: RefCell::default(),
+            used_lint_attrs: RefCell::default(),
         });
 
         // Create and link `AstContext`
@@ -71,6 +116,305 @@ impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
         // The `OnceCell` is filled in the new function and can never be not set.
         self.ast_cx.get().unwrap()
     }
+
+    /// Returns `true`, if the lint attribute, which contains the lint name at
+    /// the given span, has suppressed a diagnostic of a lint crate.
+    pub fn is_lint_attr_used(&self, lint_name_span: rustc_span::Span) -> bool {
+        self.used_lint_attrs.borrow().contains(&lint_name_span)
+    }
+
+    /// This is called for every diagnostic of a lint crate, regardless of
+    /// whether it has been emitted or suppressed by the lint crate. If the
+    /// lint is allowed or expected at the given node, the responsible lint
+    /// attribute is marked as used. Expectations are additionally remembered
+    /// as fulfilled, to report unfulfilled ones with the lint name later.
+    fn track_lint_attr(&self, lint: &'static rustc_lint::Lint, id: hir::HirId) {
+        let (level, source) = self.rustc_cx.lint_level_at_node(lint, id);
+        match level {
+            rustc_lint::Level::Expect(expectation) => {
+                self.fulfilled_expectations.borrow_mut().insert(expectation.normalize());
+            },
+            rustc_lint::Level::Allow => {},
+            _ => return,
+        }
+        if let LintLevelSource::Node { span, .. } = source {
+            self.used_lint_attrs.borrow_mut().insert(span);
+        }
+    }
+
+    /// Returns all comments of the source file, which contains the given position.
+    fn file_comments(&self, pos: rustc_span::BytePos) -> &'ast [Comment<'ast>] {
+        let file = self.rustc_cx.sess.source_map().lookup_source_file(pos);
+        if let Some(comments) = self.comments.borrow().get(&file.start_pos) {
+            return comments;
+        }
+
+        let comments = self.marker_converter.to_comments(&file);
+        self.comments.borrow_mut().insert(file.start_pos, comments);
+        comments
+    }
+
+    /// Erases all lifetimes of the given type. In contrast to
+    /// [`TyCtxt::erase_regions`] this also erases late bound lifetimes, which
+    /// might be escaping, like in `for<'a> fn(&'a u8)`.
+    fn erase_all_regions(&self, ty: Ty<'tcx>) -> Ty<'tcx> {
+        self.rustc_cx.fold_regions(ty, |_, _| self.rustc_cx.lifetimes.re_erased)
+    }
+
+    /// Checks that all generic parameters of the given type can be replaced by
+    /// the given args. The generic parameters might belong to a different item,
+    /// which would cause a panic during the substitution.
+    fn can_subst_generics(ty: Ty<'tcx>, args: GenericArgsRef<'tcx>) -> bool {
+        ty.walk().all(|arg| match arg.unpack() {
+            GenericArgKind::Type(ty) => match ty.kind() {
+                TyKind::Param(param) => matches!(
+                    args.get(param.index as usize).map(|arg| arg.unpack()),
+                    Some(GenericArgKind::Type(_))
+                ),
+                _ => true,
+            },
+            GenericArgKind::Const(value) => match value.kind() {
+                ConstKind::Param(param) => matches!(
+                    args.get(param.index as usize).map(|arg| arg.unpack()),
+                    Some(GenericArgKind::Const(_))
+                ),
+                _ => true,
+            },
+            GenericArgKind::Lifetime(region) => match region.kind() {
+                RegionKind::ReEarlyBound(region) => matches!(
+                    args.get(region.index as usize).map(|arg| arg.unpack()),
+                    Some(GenericArgKind::Lifetime(_))
+                ),
+                _ => true,
+            },
+        })
+    }
+
+    fn comment_span(&self, comment: &Comment<'_>) -> rustc_span::Span {
+        self.rustc_converter.to_span_from_id(comment.span_id())
+    }
+
+    /// This emits a warning for every `#[expect(marker::lint_name)]` attribute
+    /// which hasn't been fulfilled by a diagnostic of the named lint.
+    ///
+    /// Rustc would report these expectations as well, but with a generic message
+    /// that doesn't name the expected lint. Therefore, this function marks every
+    /// reported expectation as fulfilled, to prevent rustc from reporting it again.
+    pub fn check_lint_expectations(&self) {
+        let tcx = self.rustc_cx;
+        if !tcx.features().enabled(rustc_span::sym::lint_reasons) {
+            return;
+        }
+
+        let marker_tool = rustc_span::Symbol::intern("marker");
+        let fulfilled = self.fulfilled_expectations.borrow();
+        for (id, expectation) in tcx.lint_expectations(()) {
+            if expectation.lint_tool != Some(marker_tool) || fulfilled.contains(&id.normalize()) {
+                continue;
+            }
+            let LintExpectationId::Stable { hir_id, .. } = id else {
+                unreachable!("at this stage all `LintExpectationId`s are stable");
+            };
+
+            // The emission span points to the lint name in the attribute
+            let lint_name = tcx
+                .sess
+                .source_map()
+                .span_to_snippet(expectation.emission_span)
+                .unwrap_or_else(|_| "marker::<unknown>".to_string());
+            tcx.struct_span_lint_hir(
+                rustc_lint_defs::builtin::UNFULFILLED_LINT_EXPECTATIONS,
+                *hir_id,
+                expectation.emission_span,
+                format!("this expectation of `{lint_name}` is unfulfilled"),
+                |builder| {
+                    if let Some(reason) = expectation.reason {
+                        builder.note(reason.to_string());
+                    }
+                    builder.help("the expected lint was not emitted, consider removing the attribute");
+                    builder
+                },
+            );
+
+            // Emitting a diagnostic with the `Expect` level marks the expectation
+            // as fulfilled, without displaying anything to the user.
+            tcx.sess.diagnostic().struct_expect("", *id).emit();
+        }
+    }
+
+    /// Resolves the given path to all matching [`DefId`](hir::def_id::DefId)s.
+    ///
+    /// This code is inspired by `clippy_utils::def_path_res` without the special
+    /// handling for primitive types.
+    fn resolve_def_ids(&self, path: &str) -> Vec<hir::def_id::DefId> {
+        // Path splitting and "validation"
+        let mut splits = path.split("::");
+        let Some(krate_name) = splits.next() else {
+            return vec![];
+        };
+        let segs: Vec<_> = splits.collect();
+        if segs.is_empty() {
+            return vec![];
+        }
+
+        let tcx = self.rustc_cx;
+        let krate_name = rustc_span::Symbol::intern(krate_name);
+        let additional_krate: &[_] = if krate_name == rustc_span::symbol::kw::Crate {
+            &[hir::def_id::LOCAL_CRATE]
+        } else {
+            &[]
+        };
+        let krates = tcx
+            .crates(())
+            .iter()
+            .copied()
+            .chain(std::iter::once(hir::def_id::LOCAL_CRATE))
+            .filter(|id| tcx.crate_name(*id) == krate_name)
+            .chain(additional_krate.iter().copied());
+        let mut searches: Vec<_> = krates
+            .map(rustc_span::def_id::CrateNum::as_def_id)
+            .map(|id| hir::def::Res::Def::<hir::def_id::DefId>(tcx.def_kind(id), id))
+            .collect();
+
+        let mut rest = &segs[..];
+        while let [seg, next_rest @ ..] = rest {
+            rest = next_rest;
+            let seg = rustc_span::Symbol::intern(seg);
+            searches = select_children_with_name(tcx, &searches, seg);
+        }
+
+        searches.into_iter().filter_map(|res| res.opt_def_id()).collect()
+    }
+
+    fn def_path(&'ast self, def_id: hir::def_id::DefId) -> &'ast ItemPath<'ast> {
+        // Caching
+        if let Some(path) = self.item_paths.borrow().get(&def_id) {
+            return path;
+        }
+
+        let segments = self.def_path_segments(def_id);
+        let path = self.storage.alloc_str(&segments.join("::"));
+        let segments = self
+            .storage
+            .alloc_slice(segments.iter().map(|seg| self.storage.alloc_str(seg).into()));
+        let path = self.storage.alloc(ItemPath::new(segments, path));
+        self.item_paths.borrow_mut().insert(def_id, path);
+        path
+    }
+
+    fn def_path_segments(&self, def_id: hir::def_id::DefId) -> Vec<String> {
+        let tcx = self.rustc_cx;
+        let key = tcx.def_key(def_id);
+        let Some(parent) = key.parent else {
+            return vec![tcx.crate_name(def_id.krate).to_string()];
+        };
+        let parent = hir::def_id::DefId {
+            krate: def_id.krate,
+            index: parent,
+        };
+
+        match key.disambiguated_data.data {
+            // The segments of these items are not part of the path
+            DefPathData::ForeignMod | DefPathData::Use | DefPathData::GlobalAsm | DefPathData::Ctor => {
+                self.def_path_segments(parent)
+            },
+            // Impl blocks are replaced with the path of the type
+            DefPathData::Impl => self.impl_path_segments(def_id),
+            _ => {
+                let mut segments = self.def_path_segments(parent);
+                let mut segment = String::new();
+                key.disambiguated_data
+                    .fmt_maybe_verbose(&mut segment, false)
+                    .expect("writing to a string can't fail");
+                segments.push(segment);
+                segments
+            },
+        }
+    }
+
+    /// Impl blocks are named by their self type and implemented trait. Both are
+    /// rendered with their generic args, see [`RustcContext::path_ty_str`].
+    /// Inherent impls for the generic type itself, like `impl<T> Option<T>`,
+    /// use the plain path of the type, as their args only name parameters.
+    fn impl_path_segments(&self, impl_id: hir::def_id::DefId) -> Vec<String> {
+        let tcx = self.rustc_cx;
+        let self_ty = tcx.type_of(impl_id).instantiate_identity();
+
+        match tcx.impl_trait_ref(impl_id) {
+            Some(trait_ref) => {
+                let trait_ref = trait_ref.instantiate_identity();
+                let trait_path = self.def_path_segments(trait_ref.def_id).join("::");
+                // The first arg is the `Self` type
+                let trait_args = self.path_args_str(&trait_ref.args[1..]);
+                vec![format!("<{} as {trait_path}{trait_args}>", self.path_ty_str(self_ty))]
+            },
+            None => match self_ty.kind() {
+                rustc_middle::ty::Adt(adt, args) => {
+                    let mut segments = self.def_path_segments(adt.did());
+                    if !is_identity_args(args)
+                        && let Some(last) = segments.last_mut()
+                    {
+                        last.push_str(&self.path_args_str(args));
+                    }
+                    segments
+                },
+                rustc_middle::ty::Foreign(def_id) => self.def_path_segments(*def_id),
+                _ => vec![format!("<{}>", self.path_ty_str(self_ty))],
+            },
+        }
+    }
+
+    /// Renders the given type for item paths. Paths inside the type are
+    /// rendered as item paths, generic args are included, but lifetimes are
+    /// omitted. Types, which are uncommon in impl blocks, are rendered by rustc.
+    fn path_ty_str(&self, ty: rustc_middle::ty::Ty<'tcx>) -> String {
+        match ty.kind() {
+            rustc_middle::ty::Adt(adt, args) => {
+                format!(
+                    "{}{}",
+                    self.def_path_segments(adt.did()).join("::"),
+                    self.path_args_str(args)
+                )
+            },
+            rustc_middle::ty::Foreign(def_id) => self.def_path_segments(*def_id).join("::"),
+            rustc_middle::ty::Ref(_lifetime, inner, mutbl) => {
+                format!("&{}{}", mutbl.prefix_str(), self.path_ty_str(*inner))
+            },
+            rustc_middle::ty::RawPtr(ty_and_mut) => {
+                let mutbl = if ty_and_mut.mutbl.is_mut() { "mut" } else { "const" };
+                format!("*{mutbl} {}", self.path_ty_str(ty_and_mut.ty))
+            },
+            rustc_middle::ty::Slice(inner) => format!("[{}]", self.path_ty_str(*inner)),
+            rustc_middle::ty::Array(inner, len) => format!("[{}; {len}]", self.path_ty_str(*inner)),
+            rustc_middle::ty::Tuple(tys) => {
+                let tys: Vec<_> = tys.iter().map(|ty| self.path_ty_str(ty)).collect();
+                if tys.len() == 1 {
+                    format!("({},)", tys[0])
+                } else {
+                    format!("({})", tys.join(", "))
+                }
+            },
+            _ => with_no_trimmed_paths!(ty.to_string()),
+        }
+    }
+
+    /// Renders the given generic args for item paths, like `<T, u8>`. Lifetimes
+    /// are omitted. An empty string is returned, if no args remain.
+    fn path_args_str(&self, args: &[rustc_middle::ty::GenericArg<'tcx>]) -> String {
+        let args: Vec<_> = args
+            .iter()
+            .filter_map(|arg| match arg.unpack() {
+                rustc_middle::ty::GenericArgKind::Type(ty) => Some(self.path_ty_str(ty)),
+                rustc_middle::ty::GenericArgKind::Const(value) => Some(value.to_string()),
+                rustc_middle::ty::GenericArgKind::Lifetime(_) => None,
+            })
+            .collect();
+        if args.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", args.join(", "))
+        }
+    }
 }
 
 impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
@@ -89,6 +433,8 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
             return;
         };
         let lint = self.rustc_converter.to_lint(diag.lint);
+        // Rustc will suppress the emission if the lint is allowed or expected.
+        self.track_lint_attr(lint, id);
         self.rustc_cx.struct_span_lint_hir(
             lint,
             id,
@@ -125,10 +471,15 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
         );
     }
 
+    fn lint_suppressed(&'ast self, api_lint: &'static Lint, node: EmissionNode) {
+        if let Some(id) = self.rustc_converter.try_to_hir_id_from_emission_node(node) {
+            self.track_lint_attr(self.rustc_converter.to_lint(api_lint), id);
+        }
+    }
+
     fn item(&'ast self, api_id: ItemId) -> Option<ItemKind<'ast>> {
-        let rustc_id = self.rustc_converter.to_item_id(api_id);
-        let rust_item = self.rustc_cx.hir().item(rustc_id);
-        self.marker_converter.to_item(rust_item)
+        let def_id = self.rustc_converter.to_def_id(api_id).as_local()?;
+        self.marker_converter.to_item_from_owner_id(hir::OwnerId { def_id })
     }
 
     fn body(&'ast self, id: BodyId) -> &'ast Body<'ast> {
@@ -136,58 +487,39 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
         self.marker_converter.to_body(rustc_body)
     }
 
+    fn trait_item_for_impl_item(&'ast self, api_id: ItemId) -> Option<ItemId> {
+        let def_id = self.rustc_converter.to_def_id(api_id);
+        if !matches!(
+            self.rustc_cx.def_kind(def_id),
+            DefKind::AssocConst | DefKind::AssocFn | DefKind::AssocTy
+        ) {
+            return None;
+        }
+
+        let assoc_item = self.rustc_cx.associated_item(def_id);
+        if assoc_item.container != AssocItemContainer::ImplContainer {
+            return None;
+        }
+
+        assoc_item
+            .trait_item_def_id
+            .map(|trait_item_id| self.marker_converter.to_item_id(trait_item_id))
+    }
+
     fn resolve_ty_ids(&'ast self, path: &str) -> &'ast [TyDefId] {
         // Caching
         if let Some(ids) = self.resolved_ty_ids.borrow().get(path) {
             return ids;
         }
 
-        // Path splitting and "validation"
-        let mut splits = path.split("::");
-        let Some(krate_name) = splits.next() else {
-            return &[];
-        };
-        let segs: Vec<_> = splits.collect();
-        if segs.is_empty() {
-            return &[];
-        }
         // This method is only intended to resolve `TyDefId`s, this means we can
         // ignore primitive types and all others which are specificity handled in
         // the `*TyKind` enums. Basically, we only need to find the ids of Enums,
         // Structs, Unions and maybe type aliases.
-        //
-        // This code is inspired by `clippy_utils::def_path_res` without the special
-        // handling for primitive types and other items
         let tcx = self.rustc_cx;
-        let krate_name = rustc_span::Symbol::intern(krate_name);
-        let additional_krate: &[_] = if krate_name == rustc_span::symbol::kw::Crate {
-            &[hir::def_id::LOCAL_CRATE]
-        } else {
-            &[]
-        };
-        let krates = tcx
-            .crates(())
-            .iter()
-            .copied()
-            .chain(std::iter::once(hir::def_id::LOCAL_CRATE))
-            .filter(|id| tcx.crate_name(*id) == krate_name)
-            .chain(additional_krate.iter().copied());
-        let mut searches: Vec<_> = krates
-            .map(rustc_span::def_id::CrateNum::as_def_id)
-            .map(|id| hir::def::Res::Def::<hir::def_id::DefId>(tcx.def_kind(id), id))
-            .collect();
-
-        let mut rest = &segs[..];
-        while let [seg, next_rest @ ..] = rest {
-            rest = next_rest;
-            let seg = rustc_span::Symbol::intern(seg);
-            searches = select_children_with_name(tcx, &searches, seg);
-        }
-
-        // Filtering to only take `DefId`s which are also `TyDefId`s
-        let ids: Vec<_> = searches
+        let ids: Vec<_> = self
+            .resolve_def_ids(path)
             .into_iter()
-            .filter_map(|res| res.opt_def_id())
             .filter(|def_id| {
                 matches!(
                     tcx.def_kind(def_id),
@@ -209,11 +541,338 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
         ids
     }
 
+    fn resolve_item_ids(&'ast self, path: &str) -> &'ast [ItemId] {
+        // Caching
+        if let Some(ids) = self.resolved_item_ids.borrow().get(path) {
+            return ids;
+        }
+
+        let tcx = self.rustc_cx;
+        let ids: Vec<_> = self
+            .resolve_def_ids(path)
+            .into_iter()
+            .filter(|def_id| {
+                matches!(
+                    tcx.def_kind(def_id),
+                    DefKind::Mod
+                        | DefKind::Struct
+                        | DefKind::Union
+                        | DefKind::Enum
+                        | DefKind::Trait
+                        | DefKind::TyAlias { .. }
+                        | DefKind::ForeignTy
+                        | DefKind::Fn
+                        | DefKind::Const
+                        | DefKind::Static(_)
+                        | DefKind::AssocFn
+                        | DefKind::AssocConst
+                        | DefKind::AssocTy
+                )
+            })
+            .map(|def_id| self.marker_converter.to_item_id(def_id))
+            .collect();
+
+        // Allocation and caching
+        let ids = self.storage.alloc_slice(ids);
+        self.resolved_item_ids
+            .borrow_mut()
+            .insert(self.storage.alloc_str(path), ids);
+        ids
+    }
+
+    fn item_path(&'ast self, api_id: ItemId) -> &'ast ItemPath<'ast> {
+        self.def_path(self.rustc_converter.to_def_id(api_id))
+    }
+
+    fn ty_def_path(&'ast self, api_id: TyDefId) -> &'ast ItemPath<'ast> {
+        self.def_path(self.rustc_converter.to_def_id(api_id))
+    }
+
     fn expr_ty(&'ast self, expr: ExprId) -> marker_api::ast::ty::SemTyKind<'ast> {
         let hir_id = self.rustc_converter.to_hir_id(expr);
         self.marker_converter.expr_ty(hir_id)
     }
 
+    fn peel_aliases(&'ast self, ty: SemTyKind<'ast>) -> SemTyKind<'ast> {
+        let Some((rustc_ty, owner)) = self.marker_converter.to_rustc_ty(ty) else {
+            return ty;
+        };
+        if !rustc_ty.has_projections() {
+            return ty;
+        }
+
+        let param_env = owner.map_or_else(ParamEnv::reveal_all, |owner| {
+            self.rustc_cx.param_env_reveal_all_normalized(owner)
+        });
+        match self
+            .rustc_cx
+            .try_normalize_erasing_regions(param_env, self.erase_all_regions(rustc_ty))
+        {
+            Ok(normalized) if normalized != rustc_ty => self.marker_converter.to_sem_ty_with_owner(normalized, owner),
+            _ => ty,
+        }
+    }
+
+    fn subst_generics(&'ast self, ty: SemTyKind<'ast>, args: &SemGenericArgs<'ast>) -> SemTyKind<'ast> {
+        let Some((rustc_ty, ty_owner)) = self.marker_converter.to_rustc_ty(ty) else {
+            return ty;
+        };
+        if !rustc_ty.has_param() {
+            return ty;
+        }
+        let Some((args_item, (rustc_args, args_owner))) = self.marker_converter.to_rustc_generic_args(args) else {
+            return ty;
+        };
+        // The args have to be for the item, which declares the parameters of the type
+        if ty_owner != Some(args_item) || !Self::can_subst_generics(rustc_ty, rustc_args) {
+            return ty;
+        }
+
+        let substituted = EarlyBinder::bind(rustc_ty).instantiate(self.rustc_cx, rustc_args);
+        self.marker_converter.to_sem_ty_with_owner(substituted, args_owner)
+    }
+
+    fn generic_name(&'ast self, id: GenericId) -> SymbolId {
+        let def_id = self.rustc_converter.to_def_id(id);
+        // The `Self` parameter of traits uses the id of the trait
+        let name = match self.rustc_cx.def_kind(def_id) {
+            DefKind::Trait | DefKind::TraitAlias => rustc_span::symbol::kw::SelfUpper,
+            _ => self.rustc_cx.item_name(def_id),
+        };
+        self.marker_converter.to_symbol_id(name)
+    }
+
+    fn opaque_bounds(&'ast self, ty: DriverTyId) -> &'ast [SemTraitBound<'ast>] {
+        // Caching
+        if let Some(bounds) = self.opaque_bounds.borrow().get(&ty) {
+            return bounds;
+        }
+
+        let bounds = self.marker_converter.to_sem_opaque_bounds(ty);
+        self.opaque_bounds.borrow_mut().insert(ty, bounds);
+        bounds
+    }
+
+    fn coroutine_interior_tys(&'ast self, ty: DriverTyId) -> &'ast [SemTyKind<'ast>] {
+        // Caching
+        if let Some(tys) = self.coroutine_interior_tys.borrow().get(&ty) {
+            return tys;
+        }
+
+        let tys = self.marker_converter.to_sem_coroutine_interior_tys(ty);
+        self.coroutine_interior_tys.borrow_mut().insert(ty, tys);
+        tys
+    }
+
+    fn eval_const(&'ast self, expr: ExprId) -> Option<&'ast ConstValue<'ast>> {
+        let hir_id = self.rustc_converter.to_hir_id(expr);
+        self.marker_converter.eval_const(hir_id)
+    }
+
+    fn adt_def(&'ast self, api_id: TyDefId) -> Option<&'ast SemAdtDef<'ast>> {
+        let def_id = self.rustc_converter.to_def_id(api_id);
+        if !matches!(
+            self.rustc_cx.def_kind(def_id),
+            DefKind::Struct | DefKind::Enum | DefKind::Union
+        ) {
+            return None;
+        }
+
+        // Caching
+        if let Some(adt_def) = self.adt_defs.borrow().get(&def_id) {
+            return Some(adt_def);
+        }
+
+        let adt_def = self.marker_converter.to_sem_adt_def(self.rustc_cx.adt_def(def_id));
+        self.adt_defs.borrow_mut().insert(def_id, adt_def);
+        Some(adt_def)
+    }
+
+    fn fn_sig(&'ast self, api_id: ItemId) -> Option<&'ast SemFnSig<'ast>> {
+        let def_id = self.rustc_converter.to_def_id(api_id);
+        if !matches!(
+            self.rustc_cx.def_kind(def_id),
+            DefKind::Fn | DefKind::AssocFn | DefKind::Ctor(_, CtorKind::Fn)
+        ) {
+            return None;
+        }
+
+        // Caching
+        if let Some(sig) = self.fn_sigs.borrow().get(&def_id) {
+            return Some(sig);
+        }
+
+        let sig = self.marker_converter.to_sem_fn_sig(def_id);
+        self.fn_sigs.borrow_mut().insert(def_id, sig);
+        Some(sig)
+    }
+
+    fn predicates_of(&'ast self, api_id: ItemId) -> &'ast [SemPredicate<'ast>] {
+        let def_id = self.rustc_converter.to_def_id(api_id);
+        if !matches!(
+            self.rustc_cx.def_kind(def_id),
+            DefKind::Struct
+                | DefKind::Enum
+                | DefKind::Union
+                | DefKind::Trait
+                | DefKind::TraitAlias
+                | DefKind::TyAlias { .. }
+                | DefKind::Fn
+                | DefKind::AssocFn
+                | DefKind::AssocTy
+                | DefKind::AssocConst
+                | DefKind::Const
+                | DefKind::Static(_)
+                | DefKind::Impl { .. }
+                | DefKind::OpaqueTy
+                | DefKind::ForeignTy
+        ) {
+            return &[];
+        }
+
+        // Caching
+        if let Some(predicates) = self.predicates.borrow().get(&def_id) {
+            return predicates;
+        }
+
+        let predicates = self.marker_converter.to_sem_predicates(def_id);
+        self.predicates.borrow_mut().insert(def_id, predicates);
+        predicates
+    }
+
+    fn generic_param(&'ast self, api_id: GenericId) -> &'ast SemGenericParam<'ast> {
+        let def_id = self.rustc_converter.to_def_id(api_id);
+
+        // Caching
+        if let Some(param) = self.generic_params.borrow().get(&def_id) {
+            return param;
+        }
+
+        let param = self.marker_converter.to_sem_generic_param(def_id);
+        self.generic_params.borrow_mut().insert(def_id, param);
+        param
+    }
+
+    fn layout_of(&'ast self, ty: SemTyKind<'ast>) -> Option<&'ast Layout<'ast>> {
+        let (ty, _owner) = self.marker_converter.to_rustc_ty(ty)?;
+        // Lifetimes don't affect the layout.
+        let ty = self.erase_all_regions(ty);
+        if ty.has_param() {
+            return None;
+        }
+
+        // Caching
+        if let Some(layout) = self.layouts.borrow().get(&ty) {
+            return *layout;
+        }
+
+        let layout = self
+            .rustc_cx
+            .layout_of(ParamEnv::reveal_all().and(ty))
+            .ok()
+            .map(|layout| self.marker_converter.to_layout(layout));
+        self.layouts.borrow_mut().insert(ty, layout);
+        layout
+    }
+
+    fn expr_adjustments(&'ast self, expr: ExprId) -> &'ast [Adjustment<'ast>] {
+        let hir_id = self.rustc_converter.to_hir_id(expr);
+        self.marker_converter.expr_adjustments(hir_id)
+    }
+
+    fn closure_captures(&'ast self, expr: ExprId) -> &'ast ClosureCaptures<'ast> {
+        let hir_id = self.rustc_converter.to_hir_id(expr);
+        self.marker_converter.closure_captures(hir_id)
+    }
+
+    fn expr_desugaring(&'ast self, expr: ExprId) -> Option<DesugarKind> {
+        let hir_id = self.rustc_converter.to_hir_id(expr);
+        self.marker_converter.expr_desugaring(hir_id)
+    }
+
+    fn comments_in(&'ast self, api_span: &Span<'ast>) -> &'ast [Comment<'ast>] {
+        let span = self.rustc_converter.to_span(api_span);
+        let comments = self.file_comments(span.lo());
+        let start = comments.partition_point(|comment| self.comment_span(comment).lo() < span.lo());
+        let end = comments.partition_point(|comment| self.comment_span(comment).hi() <= span.hi());
+        &comments[start..end.max(start)]
+    }
+
+    fn comments_in_file(&'ast self, api_span: &Span<'ast>) -> &'ast [Comment<'ast>] {
+        let span = self.rustc_converter.to_span(api_span);
+        self.file_comments(span.lo())
+    }
+
+    fn comments_before(&'ast self, node: EmissionNode) -> &'ast [Comment<'ast>] {
+        let Some(hir_id) = self.rustc_converter.try_to_hir_id_from_emission_node(node) else {
+            return &[];
+        };
+        let hir = self.rustc_cx.hir();
+        let span = hir.span_with_body(hir_id);
+        if span.from_expansion() {
+            return &[];
+        }
+
+        // Attributes, including doc comments, are part of the node
+        let start = hir
+            .attrs(hir_id)
+            .iter()
+            .map(|attr| attr.span)
+            .filter(|attr_span| !attr_span.from_expansion() && attr_span.lo() < span.lo())
+            .fold(span.lo(), |start, attr_span| start.min(attr_span.lo()));
+
+        let file = self.rustc_cx.sess.source_map().lookup_source_file(start);
+        let Some(src) = file.src.as_deref() else {
+            return &[];
+        };
+        let to_index = |pos: rustc_span::BytePos| (pos - file.start_pos).to_usize();
+        let line_start = |index: usize| src[..index].rfind('\n').map_or(0, |line_end| line_end + 1);
+
+        let comments = self.file_comments(start);
+        let end = comments.partition_point(|comment| self.comment_span(comment).hi() <= start);
+        let node_line_start = line_start(to_index(start));
+        let mut next_start = to_index(start);
+        let mut first = end;
+        for (index, comment) in comments[..end].iter().enumerate().rev() {
+            let comment_span = self.comment_span(comment);
+            let comment_start = to_index(comment_span.lo());
+            // Only whitespace is allowed between the comment and the node. Empty
+            // lines and inner doc comments separate the comment from the node.
+            let gap = &src[to_index(comment_span.hi())..next_start];
+            if !gap.trim().is_empty()
+                || gap.matches('\n').count() > 1
+                || comment.doc_style() == Some(marker_api::ast::DocStyle::Inner)
+            {
+                break;
+            }
+            // Trailing comments of previous code belong to that code, unless
+            // they're on the same line as the node
+            let comment_line_start = line_start(comment_start);
+            if comment_line_start != node_line_start && !src[comment_line_start..comment_start].trim().is_empty() {
+                break;
+            }
+            first = index;
+            next_start = comment_start;
+        }
+
+        &comments[first..end]
+    }
+
+    fn docs(&'ast self, node: EmissionNode) -> &'ast Docs<'ast> {
+        let Some(hir_id) = self.rustc_converter.try_to_hir_id_from_emission_node(node) else {
+            return self.marker_converter.to_docs(&[]);
+        };
+
+        // Caching
+        if let Some(docs) = self.docs.borrow().get(&hir_id) {
+            return docs;
+        }
+
+        let docs = self.marker_converter.to_docs(self.rustc_cx.hir().attrs(hir_id));
+        self.docs.borrow_mut().insert(hir_id, docs);
+        docs
+    }
+
     fn span(&'ast self, span_id: SpanId) -> &'ast Span<'ast> {
         let rustc_span = self.rustc_converter.to_span_from_id(span_id);
         self.storage.alloc(self.marker_converter.to_span(rustc_span))
@@ -258,8 +917,9 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
         api_str
     }
 
-    fn resolve_method_target(&'ast self, _id: ExprId) -> ItemId {
-        todo!()
+    fn resolve_method_target(&'ast self, id: ExprId) -> ItemId {
+        let hir_id = self.rustc_converter.to_hir_id(id);
+        self.marker_converter.method_target(hir_id)
     }
 }
 
@@ -287,18 +947,33 @@ fn select_children_with_name(
             };
 
             if let Some(hir::ItemKind::Mod(module)) = item {
-                module
+                let items = module.item_ids.iter().map(|&item_id| hir.item(item_id));
+                for item in items.filter(|item| item.ident.name == name) {
+                    // Re-exports are resolved to their targets, like it's
+                    // done by `module_children` for external crates
+                    if let hir::ItemKind::Use(path, hir::UseKind::Single) = item.kind {
+                        path.res
+                            .iter()
+                            .filter(|res| **res != hir::def::Res::Err)
+                            .map(|res| res.expect_non_local())
+                            .collect_into(&mut next_search);
+                    } else {
+                        let def_id = item.owner_id.to_def_id();
+                        next_search.push(hir::def::Res::Def(tcx.def_kind(def_id), def_id));
+                    }
+                }
+                // Items of extern blocks are named like items of the module
+                let foreign_items = module
                     .item_ids
                     .iter()
-                    .filter_map(|&item_id| {
-                        if hir.item(item_id).ident.name == name {
-                            let def_id = item_id.owner_id.to_def_id();
-                            Some(hir::def::Res::Def(tcx.def_kind(def_id), def_id))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect_into(&mut next_search);
+                    .filter_map(|&item_id| match hir.item(item_id).kind {
+                        hir::ItemKind::ForeignMod { items, .. } => Some(items),
+                        _ => None,
+                    });
+                for foreign_item in foreign_items.flatten().filter(|item| item.ident.name == name) {
+                    let def_id = foreign_item.id.owner_id.to_def_id();
+                    next_search.push(hir::def::Res::Def(tcx.def_kind(def_id), def_id));
+                }
             }
         } else if let hir::def::DefKind::Mod = tcx.def_kind(id) {
             tcx.module_children(id)
@@ -309,5 +984,46 @@ fn select_children_with_name(
         }
     }
 
+    // Associated items of types and traits
+    let assoc_item_owners = search.iter().filter_map(|res| match res {
+        hir::def::Res::Def(DefKind::Struct | DefKind::Union | DefKind::Enum | DefKind::ForeignTy, id) => {
+            Some(tcx.inherent_impls(*id))
+        },
+        hir::def::Res::Def(DefKind::Trait, id) => Some(std::slice::from_ref(id)),
+        _ => None,
+    });
+    for owner in assoc_item_owners.flatten() {
+        tcx.associated_items(*owner)
+            .filter_by_name_unhygienic(name)
+            .map(|assoc_item| hir::def::Res::Def(tcx.def_kind(assoc_item.def_id), assoc_item.def_id))
+            .collect_into(&mut next_search);
+    }
+
     next_search
 }
+
+/// Returns `true`, if the given args only consist of distinct generic
+/// parameters, like the args of `Wrapper<T>` in `impl<T> Wrapper<T>`. Lifetimes
+/// are ignored, as they're not part of item paths.
+fn is_identity_args(args: &[rustc_middle::ty::GenericArg<'_>]) -> bool {
+    let mut params = Vec::with_capacity(args.len());
+    args.iter().all(|arg| {
+        let index = match arg.unpack() {
+            rustc_middle::ty::GenericArgKind::Type(ty) => match ty.kind() {
+                rustc_middle::ty::Param(param) => param.index,
+                _ => return false,
+            },
+            rustc_middle::ty::GenericArgKind::Const(value) => match value.kind() {
+                rustc_middle::ty::ConstKind::Param(param) => param.index,
+                _ => return false,
+            },
+            rustc_middle::ty::GenericArgKind::Lifetime(_) => return true,
+        };
+        if params.contains(&index) {
+            false
+        } else {
+            params.push(index);
+            true
+        }
+    })
+}
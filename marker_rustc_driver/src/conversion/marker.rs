@@ -4,9 +4,11 @@
 //! together and share access to common objects easily.
 
 mod common;
+mod const_value;
 mod expr;
 mod generics;
 mod item;
+mod krate;
 mod pat;
 mod stmts;
 mod ty;
@@ -16,15 +18,18 @@ use std::cell::RefCell;
 use crate::context::storage::Storage;
 use marker_api::{
     ast::{
-        expr::ExprKind,
+        expr::{Adjustment, ClosureCaptures, DesugarKind, ExprKind},
+        generic::{SemGenericArgs, SemGenericParam, SemPredicate, SemTraitBound},
         item::{Body, ItemKind},
-        ty::SemTyKind,
-        BodyId, Crate, ExpnInfo, ExprId, FilePos, ItemId, Span, SpanSource, SymbolId, TyDefId,
+        ty::{Layout, SemAdtDef, SemFnSig, SemTyKind},
+        BodyId, Comment, ConstValue, Crate, Docs, DriverTyId, ExpnInfo, ExprId, FilePos, ItemId, Span, SpanSource,
+        SymbolId, TyDefId,
     },
     lint::Level,
 };
 use rustc_hash::FxHashMap;
 use rustc_hir as hir;
+use rustc_middle::ty::GenericArgsRef;
 
 /// An interface to convert rustc's IR to marker types.
 ///
@@ -65,15 +70,168 @@ impl<'ast, 'tcx> MarkerConverter<'ast, 'tcx> {
     }
 
     pub fn expr_ty(&self, id: hir::HirId) -> SemTyKind<'ast> {
+        let id = self.async_block_closure(id).unwrap_or(id);
         self.with_body(id, |inner| {
             let ty = inner.rustc_ty_check().node_type(id);
             inner.to_sem_ty(ty)
         })
     }
 
+    /// Marker represents `async` blocks as [`BlockExpr`](marker_api::ast::expr::BlockExpr)s
+    /// with the id of the block, which is the body of a desugared closure.
+    /// The type of the block is its output, while the closure has the type of
+    /// the coroutine, which implements `Future`. This returns the id of that
+    /// closure, if the given id belongs to the block of an `async` block.
+    fn async_block_closure(&self, id: hir::HirId) -> Option<hir::HirId> {
+        let hir_map = self.inner.rustc_cx.hir();
+        if let Some(hir::Node::Expr(parent)) = hir_map.find_parent(id)
+            && let hir::ExprKind::Closure(closure) = parent.kind
+            && closure.body.hir_id == id
+            && let Some(hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Block)) =
+                hir_map.body(closure.body).generator_kind
+        {
+            return Some(parent.hir_id);
+        }
+        None
+    }
+
+    pub fn expr_adjustments(&self, id: hir::HirId) -> &'ast [Adjustment<'ast>] {
+        self.with_body(id, |inner| {
+            let adjustments = inner
+                .rustc_ty_check()
+                .adjustments()
+                .get(id)
+                .map_or(&[][..], Vec::as_slice);
+            inner.alloc_slice(adjustments.iter().map(|adjustment| inner.to_adjustment(adjustment)))
+        })
+    }
+
+    pub fn closure_captures(&self, id: hir::HirId) -> &'ast ClosureCaptures<'ast> {
+        self.with_body(id, |inner| inner.to_closure_captures(id))
+    }
+
+    pub fn expr_desugaring(&self, id: hir::HirId) -> Option<DesugarKind> {
+        // Some expressions, like loop bodies, are created from other nodes. These
+        // are never the result of a desugaring.
+        match self.inner.rustc_cx.hir().find(id) {
+            Some(hir::Node::Expr(expr)) => self.inner.to_desugar_kind(expr),
+            _ => None,
+        }
+    }
+
+    /// This sets the given item as the owner of generic parameters, for the
+    /// conversion of semantic types outside of bodies.
+    fn with_generics_owner<F, R>(&self, owner: hir::def_id::DefId, with: F) -> R
+    where
+        F: FnOnce(&MarkerConverterInner<'ast, 'tcx>) -> R,
+    {
+        let old_owner = self.inner.rustc_generics_owner.replace(Some(owner));
+        let res = with(&self.inner);
+        self.inner.rustc_generics_owner.replace(old_owner);
+
+        res
+    }
+
+    pub fn to_sem_adt_def(&self, adt: rustc_middle::ty::AdtDef<'tcx>) -> &'ast SemAdtDef<'ast> {
+        self.with_generics_owner(adt.did(), |inner| inner.to_sem_adt_def(adt))
+    }
+
+    pub fn to_sem_fn_sig(&self, fn_id: hir::def_id::DefId) -> &'ast SemFnSig<'ast> {
+        self.with_generics_owner(fn_id, |inner| inner.to_sem_fn_sig(fn_id))
+    }
+
+    pub fn to_sem_predicates(&self, owner: hir::def_id::DefId) -> &'ast [SemPredicate<'ast>] {
+        self.with_generics_owner(owner, |inner| inner.to_sem_predicates(owner, |_| true))
+    }
+
+    pub fn to_sem_generic_param(&self, param_id: hir::def_id::DefId) -> &'ast SemGenericParam<'ast> {
+        let owner = self.inner.generic_param_owner(param_id);
+        self.with_generics_owner(owner, |inner| inner.to_sem_generic_param(param_id, owner))
+    }
+
+    pub fn method_target(&self, id: hir::HirId) -> ItemId {
+        self.with_body(id, |inner| {
+            let def_id = inner
+                .rustc_ty_check()
+                .type_dependent_def_id(id)
+                .expect("method calls are always resolved by the type check");
+            inner.to_item_id(def_id)
+        })
+    }
+
+    pub fn eval_const(&self, id: hir::HirId) -> Option<&'ast ConstValue<'ast>> {
+        self.with_body(id, |inner| inner.eval_const_node(id).map(|value| inner.alloc(value)))
+    }
+
+    /// Converts the given rustc type, with the given item as the owner of
+    /// generic parameters. See [`MarkerConverter::with_generics_owner`].
+    pub fn to_sem_ty_with_owner(
+        &self,
+        rustc_ty: rustc_middle::ty::Ty<'tcx>,
+        owner: Option<hir::def_id::DefId>,
+    ) -> SemTyKind<'ast> {
+        self.with_optional_generics_owner(owner, |inner| inner.to_sem_ty(rustc_ty))
+    }
+
+    /// Converts the bounds of the opaque type with the given [`DriverTyId`].
+    /// An empty slice is returned, if the type is not an opaque type.
+    pub fn to_sem_opaque_bounds(&self, id: DriverTyId) -> &'ast [SemTraitBound<'ast>] {
+        match self.inner.to_rustc_ty_from_id(id) {
+            Some((rustc_ty, owner)) => match rustc_ty.kind() {
+                rustc_middle::ty::TyKind::Alias(rustc_middle::ty::AliasKind::Opaque, info) => {
+                    self.with_optional_generics_owner(owner, |inner| inner.to_sem_opaque_bounds(*info))
+                },
+                _ => &[],
+            },
+            None => &[],
+        }
+    }
+
+    /// Converts the interior types of the coroutine with the given
+    /// [`DriverTyId`]. An empty slice is returned, if the type is not a
+    /// coroutine.
+    pub fn to_sem_coroutine_interior_tys(&self, id: DriverTyId) -> &'ast [SemTyKind<'ast>] {
+        match self.inner.to_rustc_ty_from_id(id) {
+            Some((rustc_ty, owner)) => match rustc_ty.kind() {
+                rustc_middle::ty::TyKind::Generator(_, args, _) => {
+                    self.with_optional_generics_owner(owner, |inner| inner.to_sem_coroutine_interior_tys(args))
+                },
+                _ => &[],
+            },
+            None => &[],
+        }
+    }
+
+    fn with_optional_generics_owner<F, R>(&self, owner: Option<hir::def_id::DefId>, with: F) -> R
+    where
+        F: FnOnce(&MarkerConverterInner<'ast, 'tcx>) -> R,
+    {
+        match owner {
+            Some(owner) => self.with_generics_owner(owner, with),
+            None => with(&self.inner),
+        }
+    }
+
+    forward_to_inner!(pub fn to_rustc_ty(
+        &self,
+        sem_ty: SemTyKind<'ast>,
+    ) -> Option<RustcTyInfo<rustc_middle::ty::Ty<'tcx>>>);
+    forward_to_inner!(pub fn to_rustc_generic_args(
+        &self,
+        args: &SemGenericArgs<'ast>,
+    ) -> Option<RustcItemArgs<'tcx>>);
+    forward_to_inner!(pub fn to_symbol_id(&self, sym: rustc_span::Symbol) -> SymbolId);
+    forward_to_inner!(pub fn to_layout(
+        &self,
+        layout: rustc_middle::ty::layout::TyAndLayout<'tcx>,
+    ) -> &'ast Layout<'ast>);
     forward_to_inner!(pub fn to_lint_level(&self, level: rustc_lint::Level) -> Level);
     forward_to_inner!(pub fn to_item(&self, rustc_item: &'tcx hir::Item<'tcx>) -> Option<ItemKind<'ast>>);
+    forward_to_inner!(pub fn to_item_from_owner_id(&self, owner_id: hir::OwnerId) -> Option<ItemKind<'ast>>);
+    forward_to_inner!(pub fn to_docs(&self, attrs: &[rustc_ast::Attribute]) -> &'ast Docs<'ast>);
+    forward_to_inner!(pub fn to_comments(&self, file: &rustc_span::SourceFile) -> &'ast [Comment<'ast>]);
     forward_to_inner!(pub fn to_body(&self, body: &hir::Body<'tcx>) -> &'ast Body<'ast>);
+    forward_to_inner!(pub fn to_item_id(&self, id: hir::def_id::DefId) -> ItemId);
     forward_to_inner!(pub fn to_ty_def_id(&self, id: hir::def_id::DefId) -> TyDefId);
     forward_to_inner!(pub fn to_span(&self, rustc_span: rustc_span::Span) -> Span<'ast>);
     forward_to_inner!(pub fn to_span_source(&self, rust_span: rustc_span::Span) -> SpanSource<'ast>);
@@ -99,6 +257,13 @@ macro_rules! forward_to_inner {
 }
 use forward_to_inner;
 
+/// A rustc type or generic args, with the item, which defines the used generic
+/// parameters.
+type RustcTyInfo<T> = (T, Option<hir::def_id::DefId>);
+/// The rustc generic args of an item, together with the item, which declares
+/// the generic parameters that the args are for.
+type RustcItemArgs<'tcx> = (hir::def_id::DefId, RustcTyInfo<GenericArgsRef<'tcx>>);
+
 struct MarkerConverterInner<'ast, 'tcx> {
     rustc_cx: rustc_middle::ty::TyCtxt<'tcx>,
     storage: &'ast Storage<'ast>,
@@ -108,6 +273,17 @@ struct MarkerConverterInner<'ast, 'tcx> {
     bodies: RefCell<FxHashMap<BodyId, &'ast Body<'ast>>>,
     exprs: RefCell<FxHashMap<ExprId, ExprKind<'ast>>>,
     num_symbols: RefCell<FxHashMap<u32, SymbolId>>,
+    /// The converted semantic types, by their rustc type and generics owner.
+    sem_tys: RefCell<FxHashMap<RustcTyInfo<rustc_middle::ty::Ty<'tcx>>, SemTyKind<'ast>>>,
+    /// The rustc types of all converted semantic types, indexed by their
+    /// [`DriverTyId`]. See [`MarkerConverterInner::to_rustc_ty`].
+    rustc_tys: RefCell<Vec<RustcTyInfo<rustc_middle::ty::Ty<'tcx>>>>,
+    /// The rustc generic args of all converted semantic generic args, indexed by
+    /// their [`DriverGenericArgsId`]. See
+    /// [`MarkerConverterInner::to_rustc_generic_args`].
+    ///
+    /// [`DriverGenericArgsId`]: marker_api::ast::DriverGenericArgsId
+    rustc_generic_args: RefCell<Vec<RustcItemArgs<'tcx>>>,
 
     /// Lang-items are weird, and if I'm being honest, I'm uncertain that I
     /// completely understand them. Anyways, here it goes, this is my current
@@ -144,6 +320,10 @@ struct MarkerConverterInner<'ast, 'tcx> {
     /// Requested on demand from rustc using a [`hir::BodyId`] see
     /// [`MarkerConverterInner::rustc_body`] for more information
     rustc_ty_check: RefCell<Option<&'tcx rustc_middle::ty::TypeckResults<'tcx>>>,
+    /// The item, which defines the generic parameters of semantic types, that
+    /// are converted outside of bodies, like the types of fields. This takes
+    /// precedence over [`MarkerConverterInner::rustc_body`].
+    rustc_generics_owner: RefCell<Option<hir::def_id::DefId>>,
 }
 
 // General util functions
@@ -156,9 +336,13 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             bodies: RefCell::default(),
             exprs: RefCell::default(),
             num_symbols: RefCell::default(),
+            sem_tys: RefCell::default(),
+            rustc_tys: RefCell::default(),
+            rustc_generic_args: RefCell::default(),
             lang_item_map: RefCell::default(),
             rustc_body: RefCell::default(),
             rustc_ty_check: RefCell::default(),
+            rustc_generics_owner: RefCell::default(),
         };
 
         s.fill_create_lang_item_map();
@@ -198,6 +382,17 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             .expect("MarkerConverterInner.rustc_ty_check is unexpectedly empty")
     }
 
+    /// Returns the item, which defines the generic parameters of the semantic
+    /// types, that are currently converted. See
+    /// [`MarkerConverterInner::rustc_generics_owner`].
+    fn generics_owner(&self) -> Option<hir::def_id::DefId> {
+        self.rustc_generics_owner.borrow().or_else(|| {
+            self.rustc_body
+                .borrow()
+                .map(|body_id| self.rustc_cx.hir().body_owner_def_id(body_id).to_def_id())
+        })
+    }
+
     #[must_use]
     fn alloc<T>(&self, t: T) -> &'ast T {
         self.storage.alloc(t)
@@ -232,17 +427,3 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         res
     }
 }
-
-impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
-    #[must_use]
-    fn to_crate(
-        &self,
-        rustc_crate_id: hir::def_id::CrateNum,
-        rustc_root_mod: &'tcx hir::Mod<'tcx>,
-    ) -> &'ast Crate<'ast> {
-        self.alloc(Crate::new(
-            self.to_crate_id(rustc_crate_id),
-            self.to_items(rustc_root_mod.item_ids),
-        ))
-    }
-}
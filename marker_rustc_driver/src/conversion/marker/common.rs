@@ -3,12 +3,13 @@ use std::mem::{size_of, transmute};
 use marker_api::ast::generic::SynGenericArgs;
 use marker_api::ast::ty::SynTyKind;
 use marker_api::ast::{
-    Abi, AstPath, AstPathSegment, AstPathTarget, AstQPath, BodyId, Constness, CrateId, ExpnId, ExprId, FieldId,
-    GenericId, Ident, ItemId, LetStmtId, MacroId, Mutability, Safety, SpanId, SpanSrcId, SymbolId, Syncness, TraitRef,
-    TyDefId, VarId, VariantId,
+    Abi, AstPath, AstPathSegment, AstPathTarget, AstQPath, BodyId, Comment, CommentKind, Constness, CrateId, DocLine,
+    DocStyle, Docs, ExpnId, ExprId, FieldId, GenericId, Ident, ItemId, LetStmtId, MacroId, Mutability, Safety, SpanId,
+    SpanSrcId, SymbolId, Syncness, TraitRef, TyDefId, VarId, VariantId,
 };
 use marker_api::lint::Level;
 use rustc_hir as hir;
+use rustc_span::Pos;
 
 use crate::conversion::common::{BodyIdLayout, DefIdLayout, ExpnIdLayout, HirIdLayout};
 use crate::transmute_id;
@@ -349,6 +350,35 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         None
     }
 
+    /// Converts the resolutions of a `use` path. Unlike [`Self::to_path_target`],
+    /// this skips resolutions, which can't be represented by an [`AstPathTarget`],
+    /// like macros and primitive types. Constructors are skipped as well, since
+    /// they resolve to the same target as the type or variant.
+    pub fn to_use_targets(&self, res: &[hir::def::Res]) -> &'ast [AstPathTarget] {
+        let targets: Vec<_> = res
+            .iter()
+            .filter_map(|res| match res {
+                hir::def::Res::Def(hir::def::DefKind::Mod, id) => Some(AstPathTarget::Item(self.to_item_id(*id))),
+                hir::def::Res::Def(
+                    hir::def::DefKind::TyAlias { .. }
+                    | hir::def::DefKind::Fn
+                    | hir::def::DefKind::Enum
+                    | hir::def::DefKind::Struct
+                    | hir::def::DefKind::Union
+                    | hir::def::DefKind::Trait
+                    | hir::def::DefKind::ForeignTy
+                    | hir::def::DefKind::TraitAlias
+                    | hir::def::DefKind::Const
+                    | hir::def::DefKind::Static(_)
+                    | hir::def::DefKind::Variant,
+                    _,
+                ) => Some(self.to_path_target(res)),
+                _ => None,
+            })
+            .collect();
+        self.alloc_slice(targets)
+    }
+
     fn to_path_target(&self, res: &hir::def::Res) -> AstPathTarget {
         match res {
             hir::def::Res::Def(
@@ -424,3 +454,86 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         TraitRef::new(trait_id, self.to_syn_generic_args_from_path(trait_ref.path))
     }
 }
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    #[must_use]
+    pub fn to_docs(&self, attrs: &[rustc_ast::Attribute]) -> &'ast Docs<'ast> {
+        let mut lines = vec![];
+        for attr in attrs {
+            let Some((data, kind)) = attr.doc_str_and_comment_kind() else {
+                continue;
+            };
+            let data = rustc_ast::util::comments::beautify_doc_string(data, kind);
+            data.as_str()
+                .split('\n')
+                .for_each(|line| lines.push((line.to_string(), attr.span)));
+        }
+
+        // The common indentation is removed, like rustdoc does it
+        let indent = lines
+            .iter()
+            .filter(|(line, _)| !line.trim().is_empty())
+            .map(|(line, _)| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+        let lines: Vec<_> = lines
+            .iter()
+            .map(|(line, span)| {
+                // Only leading whitespace, that is actually present, is removed
+                let cut = line
+                    .char_indices()
+                    .take_while(|(start, c)| c.is_whitespace() && start + c.len_utf8() <= indent)
+                    .map(|(start, c)| start + c.len_utf8())
+                    .last()
+                    .unwrap_or(0);
+                (&line[cut..], *span)
+            })
+            .collect();
+
+        let text = self
+            .storage
+            .alloc_str(&lines.iter().map(|(line, _)| *line).collect::<Vec<_>>().join("\n"));
+        let doc_lines = self.alloc_slice(
+            lines
+                .iter()
+                .map(|(line, span)| DocLine::new(self.storage.alloc_str(line), self.to_span_id(*span))),
+        );
+        self.alloc(Docs::new(text, doc_lines))
+    }
+
+    /// This lexes the given source file, to find all comments in it. Comments
+    /// are not part of the AST, rustc's lexer is therefore used directly.
+    #[must_use]
+    pub fn to_comments(&self, file: &rustc_span::SourceFile) -> &'ast [Comment<'ast>] {
+        let Some(src) = file.src.as_deref() else {
+            return &[];
+        };
+
+        let mut pos = rustc_lexer::strip_shebang(src).unwrap_or(0);
+        let mut comments = vec![];
+        for token in rustc_lexer::tokenize(&src[pos..]) {
+            let len = token.len as usize;
+            let comment = match token.kind {
+                rustc_lexer::TokenKind::LineComment { doc_style } => Some((CommentKind::Line, doc_style)),
+                rustc_lexer::TokenKind::BlockComment { doc_style, .. } => Some((CommentKind::Block, doc_style)),
+                _ => None,
+            };
+            if let Some((kind, doc_style)) = comment {
+                let lo = file.start_pos + rustc_span::BytePos::from_usize(pos);
+                let span = rustc_span::Span::with_root_ctxt(lo, lo + rustc_span::BytePos::from_usize(len));
+                comments.push(Comment::new(
+                    kind,
+                    doc_style.map(|style| match style {
+                        rustc_lexer::DocStyle::Outer => DocStyle::Outer,
+                        rustc_lexer::DocStyle::Inner => DocStyle::Inner,
+                    }),
+                    self.storage.alloc_str(&src[pos..pos + len]),
+                    self.to_span_id(span),
+                ));
+            }
+            pos += len;
+        }
+
+        self.alloc_slice(comments)
+    }
+}
@@ -0,0 +1,585 @@
+use marker_api::ast::{ty::NumKind, ConstByteStr, ConstFloat, ConstInt, ConstStr, ConstValue, ConstValues};
+use rustc_hir as hir;
+use rustc_middle as mid;
+use rustc_middle::mir::interpret::{self, GlobalAlloc, Scalar};
+use rustc_target::abi::Size;
+use std::str::FromStr;
+
+use super::MarkerConverterInner;
+
+/// Marker represents array values element by element. Larger arrays are not
+/// converted, to avoid allocating memory for every element.
+const MAX_ARRAY_LEN: u64 = 1 << 16;
+
+// Conversion of values, computed by rustc's const evaluation
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Converts a type level constant, like the length of an array or a const
+    /// generic argument. [`ConstValue::Unknown`] is returned, if the constant
+    /// can't be evaluated, for example, since it depends on a generic parameter.
+    #[must_use]
+    pub fn to_const_value_from_ty_const(&self, value: mid::ty::Const<'tcx>) -> ConstValue<'ast> {
+        let value = value.eval(self.rustc_cx, mid::ty::ParamEnv::reveal_all());
+        value
+            .try_to_valtree()
+            .and_then(|tree| self.to_const_value_from_valtree(tree, value.ty()))
+            .unwrap_or(ConstValue::Unknown)
+    }
+
+    fn to_const_value_from_valtree(
+        &self,
+        tree: mid::ty::ValTree<'tcx>,
+        ty: mid::ty::Ty<'tcx>,
+    ) -> Option<ConstValue<'ast>> {
+        match (tree, ty.kind()) {
+            (mid::ty::ValTree::Leaf(scalar), _) => self.to_const_value_from_scalar_int(scalar, ty),
+            (mid::ty::ValTree::Branch(_), mid::ty::TyKind::Ref(_, inner, _)) => {
+                let bytes = tree.try_to_raw_bytes(self.rustc_cx, ty)?;
+                self.to_const_value_from_bytes(bytes, *inner)
+            },
+            (mid::ty::ValTree::Branch(fields), mid::ty::TyKind::Tuple(tys)) => {
+                let values = fields
+                    .iter()
+                    .zip(tys.iter())
+                    .map(|(field, ty)| self.to_const_value_from_valtree(*field, ty))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(ConstValue::Tuple(ConstValues::new(self.alloc_slice(values))))
+            },
+            (mid::ty::ValTree::Branch(fields), mid::ty::TyKind::Array(inner, _)) => {
+                let values = fields
+                    .iter()
+                    .map(|field| self.to_const_value_from_valtree(*field, *inner))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(ConstValue::Array(ConstValues::new(self.alloc_slice(values))))
+            },
+            _ => None,
+        }
+    }
+
+    /// Converts the result of rustc's const evaluation. Values which can't
+    /// be represented by marker, like ADTs, return `None`.
+    fn to_const_value_from_mir(
+        &self,
+        value: interpret::ConstValue<'tcx>,
+        ty: mid::ty::Ty<'tcx>,
+    ) -> Option<ConstValue<'ast>> {
+        match ty.kind() {
+            mid::ty::TyKind::Bool
+            | mid::ty::TyKind::Char
+            | mid::ty::TyKind::Int(_)
+            | mid::ty::TyKind::Uint(_)
+            | mid::ty::TyKind::Float(_) => self.to_const_value_from_scalar_int(value.try_to_scalar_int()?, ty),
+            mid::ty::TyKind::Ref(_, inner, _) => match (value, inner.kind()) {
+                (interpret::ConstValue::Slice { .. }, mid::ty::TyKind::Str | mid::ty::TyKind::Slice(_)) => {
+                    self.to_const_value_from_bytes(interpret::get_slice_bytes(&self.rustc_cx, value), *inner)
+                },
+                // Only byte arrays are converted. The length of these is also
+                // their size in bytes.
+                (interpret::ConstValue::Scalar(Scalar::Ptr(ptr, _)), mid::ty::TyKind::Array(elem, len))
+                    if *elem == self.rustc_cx.types.u8 =>
+                {
+                    let len = len.try_eval_target_usize(self.rustc_cx, mid::ty::ParamEnv::reveal_all())?;
+                    let (alloc_id, offset) = ptr.into_parts();
+                    let GlobalAlloc::Memory(alloc) = self.rustc_cx.global_alloc(alloc_id) else {
+                        return None;
+                    };
+                    let start = usize::try_from(offset.bytes()).ok()?;
+                    let end = start.checked_add(usize::try_from(len).ok()?)?;
+                    if end > usize::try_from(alloc.inner().size().bytes()).ok()? {
+                        return None;
+                    }
+                    let bytes = alloc
+                        .inner()
+                        .inspect_with_uninit_and_ptr_outside_interpreter(start..end);
+                    self.to_const_value_from_bytes(bytes, *inner)
+                },
+                _ => None,
+            },
+            mid::ty::TyKind::Tuple(_) | mid::ty::TyKind::Array(..) => {
+                if let mid::ty::TyKind::Array(_, len) = ty.kind()
+                    && len.try_eval_target_usize(self.rustc_cx, mid::ty::ParamEnv::reveal_all())? > MAX_ARRAY_LEN
+                {
+                    return None;
+                }
+                let fields = self
+                    .rustc_cx
+                    .try_destructure_mir_constant_for_diagnostics((value, ty))?
+                    .fields;
+                let values = fields
+                    .iter()
+                    .map(|(value, ty)| self.to_const_value_from_mir(*value, *ty))
+                    .collect::<Option<Vec<_>>>()?;
+                let values = ConstValues::new(self.alloc_slice(values));
+                if let mid::ty::TyKind::Tuple(_) = ty.kind() {
+                    Some(ConstValue::Tuple(values))
+                } else {
+                    Some(ConstValue::Array(values))
+                }
+            },
+            _ => None,
+        }
+    }
+
+    fn to_const_value_from_scalar_int(
+        &self,
+        scalar: mid::ty::ScalarInt,
+        ty: mid::ty::Ty<'tcx>,
+    ) -> Option<ConstValue<'ast>> {
+        match ty.kind() {
+            mid::ty::TyKind::Bool => scalar.try_to_bool().ok().map(ConstValue::Bool),
+            mid::ty::TyKind::Char => char::from_u32(scalar.try_to_u32().ok()?).map(ConstValue::Char),
+            _ => {
+                let kind = self.to_num_kind(ty)?;
+                let size = scalar.size();
+                let bits = scalar.to_bits(size).ok()?;
+                if kind == NumKind::F32 {
+                    let value = f32::from_bits(u32::try_from(bits).ok()?);
+                    Some(self.to_const_float(f64::from(value), kind))
+                } else if kind == NumKind::F64 {
+                    let value = f64::from_bits(u64::try_from(bits).ok()?);
+                    Some(self.to_const_float(value, kind))
+                } else {
+                    Some(self.to_const_int_from_bits(bits, kind, size))
+                }
+            },
+        }
+    }
+
+    /// Converts the bytes of a `str`, `[u8]` or `[u8; N]` value.
+    fn to_const_value_from_bytes(&self, bytes: &[u8], ty: mid::ty::Ty<'tcx>) -> Option<ConstValue<'ast>> {
+        match ty.kind() {
+            mid::ty::TyKind::Str => {
+                let value = std::str::from_utf8(bytes).ok()?;
+                Some(ConstValue::Str(ConstStr::new(self.storage.alloc_str(value))))
+            },
+            mid::ty::TyKind::Slice(inner) | mid::ty::TyKind::Array(inner, _) if *inner == self.rustc_cx.types.u8 => {
+                Some(ConstValue::ByteStr(ConstByteStr::new(
+                    self.alloc_slice(bytes.iter().copied()),
+                )))
+            },
+            _ => None,
+        }
+    }
+
+    /// Evaluates a constant item, associated constant or inline constant with the
+    /// given generic arguments.
+    fn eval_const_item(
+        &self,
+        def_id: hir::def_id::DefId,
+        args: mid::ty::GenericArgsRef<'tcx>,
+        ty: mid::ty::Ty<'tcx>,
+    ) -> Option<ConstValue<'ast>> {
+        let owner = self.body_owner()?;
+        let param_env = self.rustc_cx.param_env(owner).with_reveal_all_normalized(self.rustc_cx);
+        let value = self
+            .rustc_cx
+            .const_eval_resolve(param_env, mid::mir::UnevaluatedConst::new(def_id, args), None)
+            .ok()?;
+        self.to_const_value_from_mir(value, ty)
+    }
+
+    fn body_owner(&self) -> Option<hir::def_id::LocalDefId> {
+        let body_id = (*self.rustc_body.borrow())?;
+        Some(self.rustc_cx.hir().body_owner_def_id(body_id))
+    }
+}
+
+// Best-effort evaluation of HIR expressions
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Evaluates the expression with the given id. This requires the body
+    /// information to be set.
+    pub fn eval_const_node(&self, hir_id: hir::HirId) -> Option<ConstValue<'ast>> {
+        match self.rustc_cx.hir().find(hir_id)? {
+            hir::Node::Expr(expr) => self.eval_const_body_value(expr).or_else(|| self.eval_const_expr(expr)),
+            hir::Node::Block(block) => self.eval_const_block(block),
+            _ => None,
+        }
+    }
+
+    /// Bodies of constants, like array lengths or constant items, are evaluated
+    /// by rustc. This returns `None`, if the expression isn't the value of such a
+    /// body, or if the evaluation depends on a generic parameter.
+    fn eval_const_body_value(&self, expr: &hir::Expr<'tcx>) -> Option<ConstValue<'ast>> {
+        let body_id = (*self.rustc_body.borrow())?;
+        if self.rustc_cx.hir().body(body_id).value.hir_id != expr.hir_id {
+            return None;
+        }
+        let owner = self.body_owner()?.to_def_id();
+        if !matches!(
+            self.rustc_cx.def_kind(owner),
+            hir::def::DefKind::AnonConst
+                | hir::def::DefKind::InlineConst
+                | hir::def::DefKind::Const
+                | hir::def::DefKind::AssocConst
+        ) {
+            return None;
+        }
+        let args = mid::ty::GenericArgs::identity_for_item(self.rustc_cx, owner);
+        self.eval_const_item(owner, args, self.rustc_ty_check().expr_ty(expr))
+    }
+
+    fn eval_const_block(&self, block: &hir::Block<'tcx>) -> Option<ConstValue<'ast>> {
+        if !block.stmts.is_empty() {
+            return None;
+        }
+        self.eval_const_expr(block.expr?)
+    }
+
+    fn eval_const_expr(&self, expr: &hir::Expr<'tcx>) -> Option<ConstValue<'ast>> {
+        let ty_check = self.rustc_ty_check();
+        // Overloaded operators are method calls, which can't be evaluated
+        if ty_check.is_method_call(expr) {
+            return None;
+        }
+        let ty = ty_check.expr_ty(expr);
+        match &expr.kind {
+            hir::ExprKind::Lit(lit) => self.eval_const_lit(&lit.node, ty),
+            hir::ExprKind::Unary(hir::UnOp::Neg, operand) => {
+                // Negative literals, like `-128_i8`, would overflow, if the
+                // literal was evaluated first.
+                if let hir::ExprKind::Lit(lit) = &operand.kind
+                    && let rustc_ast::LitKind::Int(value, _) = lit.node
+                {
+                    let value = i128::try_from(value).ok()?.checked_neg()?;
+                    return self.to_const_int(value, self.to_num_kind(ty)?);
+                }
+                match self.eval_const_expr(operand)? {
+                    ConstValue::Int(int) if int.is_signed() => {
+                        self.to_const_int(int.as_i128()?.checked_neg()?, int.kind())
+                    },
+                    ConstValue::Float(float) => Some(self.to_const_float(-float.value(), float.kind())),
+                    #[expect(non_exhaustive_omitted_patterns)]
+                    _ => None,
+                }
+            },
+            hir::ExprKind::Unary(hir::UnOp::Not, operand) => match self.eval_const_expr(operand)? {
+                ConstValue::Bool(value) => Some(ConstValue::Bool(!value)),
+                ConstValue::Int(int) => {
+                    let size = self.num_kind_size(int.kind());
+                    Some(self.to_const_int_from_bits(!int_bits(int), int.kind(), size))
+                },
+                #[expect(non_exhaustive_omitted_patterns)]
+                _ => None,
+            },
+            hir::ExprKind::Binary(op, left, right) => self.eval_const_binary(op.node, left, right),
+            hir::ExprKind::Tup(exprs) => Some(ConstValue::Tuple(self.eval_const_exprs(exprs)?)),
+            hir::ExprKind::Array(exprs) => Some(ConstValue::Array(self.eval_const_exprs(exprs)?)),
+            hir::ExprKind::Repeat(value, _) => {
+                let mid::ty::TyKind::Array(_, len) = ty.kind() else {
+                    return None;
+                };
+                let len = len.try_eval_target_usize(self.rustc_cx, mid::ty::ParamEnv::reveal_all())?;
+                if len > MAX_ARRAY_LEN {
+                    return None;
+                }
+                let value = self.eval_const_expr(value)?;
+                let values = self.alloc_slice((0..usize::try_from(len).ok()?).map(|_| value));
+                Some(ConstValue::Array(ConstValues::new(values)))
+            },
+            hir::ExprKind::Cast(operand, _) => {
+                let value = self.eval_const_expr(operand)?;
+                self.eval_const_cast(value, ty)
+            },
+            hir::ExprKind::Block(block, None) => self.eval_const_block(block),
+            hir::ExprKind::DropTemps(inner) => self.eval_const_expr(inner),
+            hir::ExprKind::Path(qpath) => match ty_check.qpath_res(qpath, expr.hir_id) {
+                hir::def::Res::Def(hir::def::DefKind::Const | hir::def::DefKind::AssocConst, def_id) => {
+                    self.eval_const_item(def_id, ty_check.node_args(expr.hir_id), ty)
+                },
+                _ => None,
+            },
+            hir::ExprKind::ConstBlock(anon) => {
+                let def_id = anon.def_id.to_def_id();
+                let args = mid::ty::GenericArgs::identity_for_item(self.rustc_cx, def_id);
+                self.eval_const_item(def_id, args, ty)
+            },
+            _ => None,
+        }
+    }
+
+    fn eval_const_exprs(&self, exprs: &[hir::Expr<'tcx>]) -> Option<ConstValues<'ast>> {
+        let values = exprs
+            .iter()
+            .map(|expr| self.eval_const_expr(expr))
+            .collect::<Option<Vec<_>>>()?;
+        Some(ConstValues::new(self.alloc_slice(values)))
+    }
+
+    fn eval_const_lit(&self, lit: &rustc_ast::LitKind, ty: mid::ty::Ty<'tcx>) -> Option<ConstValue<'ast>> {
+        match lit {
+            rustc_ast::LitKind::Str(sym, _) => {
+                Some(ConstValue::Str(ConstStr::new(self.storage.alloc_str(sym.as_str()))))
+            },
+            rustc_ast::LitKind::ByteStr(bytes, _) => Some(ConstValue::ByteStr(ConstByteStr::new(
+                self.alloc_slice(bytes.iter().copied()),
+            ))),
+            rustc_ast::LitKind::Byte(value) => Some(ConstValue::Int(ConstInt::new(u128::from(*value), NumKind::U8))),
+            rustc_ast::LitKind::Char(value) => Some(ConstValue::Char(*value)),
+            rustc_ast::LitKind::Int(value, _) => self.to_const_int_from_u128(*value, self.to_num_kind(ty)?),
+            rustc_ast::LitKind::Float(sym, _) => {
+                let kind = self.to_num_kind(ty)?;
+                let text = sym.as_str().replace('_', "");
+                let value = if kind == NumKind::F32 {
+                    f64::from(f32::from_str(&text).ok()?)
+                } else {
+                    f64::from_str(&text).ok()?
+                };
+                Some(ConstValue::Float(ConstFloat::new(value, kind)))
+            },
+            rustc_ast::LitKind::Bool(value) => Some(ConstValue::Bool(*value)),
+            rustc_ast::LitKind::CStr(..) | rustc_ast::LitKind::Err => None,
+        }
+    }
+
+    fn eval_const_binary(
+        &self,
+        op: hir::BinOpKind,
+        left: &hir::Expr<'tcx>,
+        right: &hir::Expr<'tcx>,
+    ) -> Option<ConstValue<'ast>> {
+        let left = self.eval_const_expr(left)?;
+        // Lazy boolean operators only evaluate the right side, if needed
+        match (op, left) {
+            (hir::BinOpKind::And, ConstValue::Bool(false)) => return Some(ConstValue::Bool(false)),
+            (hir::BinOpKind::Or, ConstValue::Bool(true)) => return Some(ConstValue::Bool(true)),
+            _ => {},
+        }
+        let right = self.eval_const_expr(right)?;
+
+        match (left, right) {
+            (ConstValue::Int(l), ConstValue::Int(r)) => self.eval_const_int_binary(op, l, r),
+            (ConstValue::Float(l), ConstValue::Float(r)) => {
+                let kind = l.kind();
+                let (l, r) = (l.value(), r.value());
+                let value = match op {
+                    hir::BinOpKind::Add => l + r,
+                    hir::BinOpKind::Sub => l - r,
+                    hir::BinOpKind::Mul => l * r,
+                    hir::BinOpKind::Div => l / r,
+                    hir::BinOpKind::Rem => l % r,
+                    _ => return eval_const_cmp(op, &l, &r),
+                };
+                // The result of `f32` operations is rounded, `f64` has enough
+                // precision to compute the exact result first.
+                Some(self.to_const_float(value, kind))
+            },
+            (ConstValue::Bool(l), ConstValue::Bool(r)) => match op {
+                hir::BinOpKind::And | hir::BinOpKind::BitAnd => Some(ConstValue::Bool(l & r)),
+                hir::BinOpKind::Or | hir::BinOpKind::BitOr => Some(ConstValue::Bool(l | r)),
+                hir::BinOpKind::BitXor => Some(ConstValue::Bool(l ^ r)),
+                _ => eval_const_cmp(op, &l, &r),
+            },
+            (ConstValue::Char(l), ConstValue::Char(r)) => eval_const_cmp(op, &l, &r),
+            #[expect(non_exhaustive_omitted_patterns)]
+            _ => None,
+        }
+    }
+
+    fn eval_const_int_binary(&self, op: hir::BinOpKind, left: ConstInt, right: ConstInt) -> Option<ConstValue<'ast>> {
+        let kind = left.kind();
+        let size = self.num_kind_size(kind);
+
+        if matches!(op, hir::BinOpKind::Shl | hir::BinOpKind::Shr) {
+            // The right side can have any integer type, but the shift amount
+            // has to be smaller than the number of bits of the left side.
+            let amount = u32::try_from(right.as_u128()?).ok()?;
+            if u64::from(amount) >= size.bits() {
+                return None;
+            }
+            return match op {
+                hir::BinOpKind::Shl => Some(self.to_const_int_from_bits(int_bits(left) << amount, kind, size)),
+                _ if left.is_signed() => self.to_const_int(left.as_i128()? >> amount, kind),
+                _ => self.to_const_int_from_u128(left.as_u128()? >> amount, kind),
+            };
+        }
+
+        if left.is_signed() {
+            let (l, r) = (left.as_i128()?, right.as_i128()?);
+            let value = match op {
+                hir::BinOpKind::Add => l.checked_add(r)?,
+                hir::BinOpKind::Sub => l.checked_sub(r)?,
+                hir::BinOpKind::Mul => l.checked_mul(r)?,
+                hir::BinOpKind::Div => l.checked_div(r)?,
+                hir::BinOpKind::Rem => {
+                    // `MIN % -1` overflows, like `MIN / -1`
+                    self.to_const_int(l.checked_div(r)?, kind)?;
+                    l.checked_rem(r)?
+                },
+                hir::BinOpKind::BitAnd => l & r,
+                hir::BinOpKind::BitOr => l | r,
+                hir::BinOpKind::BitXor => l ^ r,
+                _ => return eval_const_cmp(op, &l, &r),
+            };
+            self.to_const_int(value, kind)
+        } else {
+            let (l, r) = (left.as_u128()?, right.as_u128()?);
+            let value = match op {
+                hir::BinOpKind::Add => l.checked_add(r)?,
+                hir::BinOpKind::Sub => l.checked_sub(r)?,
+                hir::BinOpKind::Mul => l.checked_mul(r)?,
+                hir::BinOpKind::Div => l.checked_div(r)?,
+                hir::BinOpKind::Rem => l.checked_rem(r)?,
+                hir::BinOpKind::BitAnd => l & r,
+                hir::BinOpKind::BitOr => l | r,
+                hir::BinOpKind::BitXor => l ^ r,
+                _ => return eval_const_cmp(op, &l, &r),
+            };
+            self.to_const_int_from_u128(value, kind)
+        }
+    }
+
+    /// Evaluates `as` casts between primitive types.
+    // `as` casts are evaluated using `as` casts
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    fn eval_const_cast(&self, value: ConstValue<'ast>, ty: mid::ty::Ty<'tcx>) -> Option<ConstValue<'ast>> {
+        if let mid::ty::TyKind::Char = ty.kind() {
+            return match value {
+                ConstValue::Char(_) => Some(value),
+                ConstValue::Int(int) if int.kind() == NumKind::U8 => {
+                    Some(ConstValue::Char(char::from(int.as_u128()? as u8)))
+                },
+                #[expect(non_exhaustive_omitted_patterns)]
+                _ => None,
+            };
+        }
+        if let mid::ty::TyKind::Bool = ty.kind() {
+            return matches!(value, ConstValue::Bool(_)).then_some(value);
+        }
+
+        let kind = self.to_num_kind(ty)?;
+        let size = self.num_kind_size(kind);
+        let bits = match value {
+            ConstValue::Bool(value) => u128::from(value),
+            ConstValue::Char(value) => u128::from(value),
+            ConstValue::Int(int) => int_bits(int),
+            ConstValue::Float(float) => {
+                let value = float.value();
+                return if kind.is_float() {
+                    Some(self.to_const_float(value, kind))
+                } else {
+                    // Float to int casts saturate. NaN is cast to 0.
+                    let is_32_bit = size.bits() == 32;
+                    let bits = match kind {
+                        NumKind::I8 => value as i8 as u128,
+                        NumKind::I16 => value as i16 as u128,
+                        NumKind::Isize if is_32_bit => value as i32 as u128,
+                        NumKind::I32 => value as i32 as u128,
+                        NumKind::I64 | NumKind::Isize => value as i64 as u128,
+                        NumKind::I128 => value as i128 as u128,
+                        NumKind::U8 => u128::from(value as u8),
+                        NumKind::U16 => u128::from(value as u16),
+                        NumKind::Usize if is_32_bit => u128::from(value as u32),
+                        NumKind::U32 => u128::from(value as u32),
+                        NumKind::U64 | NumKind::Usize => u128::from(value as u64),
+                        NumKind::U128 => value as u128,
+                        #[expect(non_exhaustive_omitted_patterns)]
+                        _ => unreachable!("float kinds are handled above"),
+                    };
+                    Some(self.to_const_int_from_bits(bits, kind, size))
+                };
+            },
+            #[expect(non_exhaustive_omitted_patterns)]
+            _ => return None,
+        };
+
+        if kind.is_float() {
+            let ConstValue::Int(int) = value else {
+                return None;
+            };
+            // Integers are cast directly to `f32`, to prevent double rounding
+            let value = match (kind == NumKind::F32, int.is_signed()) {
+                (true, true) => f64::from(int.as_i128()? as f32),
+                (true, false) => f64::from(bits as f32),
+                (false, true) => int.as_i128()? as f64,
+                (false, false) => bits as f64,
+            };
+            Some(ConstValue::Float(ConstFloat::new(value, kind)))
+        } else {
+            Some(self.to_const_int_from_bits(bits, kind, size))
+        }
+    }
+}
+
+// Value utilities
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    fn num_kind_size(&self, kind: NumKind) -> Size {
+        match kind {
+            NumKind::I8 | NumKind::U8 => Size::from_bits(8),
+            NumKind::I16 | NumKind::U16 => Size::from_bits(16),
+            NumKind::I32 | NumKind::U32 | NumKind::F32 => Size::from_bits(32),
+            NumKind::I64 | NumKind::U64 | NumKind::F64 => Size::from_bits(64),
+            NumKind::I128 | NumKind::U128 => Size::from_bits(128),
+            NumKind::Isize | NumKind::Usize => self.rustc_cx.data_layout.pointer_size,
+            _ => unreachable!("all number kinds are covered"),
+        }
+    }
+
+    /// Creates an integer value, if the value fits into the given type.
+    #[allow(clippy::cast_sign_loss)] // Signed values are stored sign extended
+    fn to_const_int(&self, value: i128, kind: NumKind) -> Option<ConstValue<'ast>> {
+        if kind.is_signed() {
+            let size = self.num_kind_size(kind);
+            let bits = value as u128;
+            (size.sign_extend(size.truncate(bits)) == bits).then(|| ConstValue::Int(ConstInt::new(bits, kind)))
+        } else {
+            self.to_const_int_from_u128(u128::try_from(value).ok()?, kind)
+        }
+    }
+
+    /// Creates an integer value, if the value fits into the given type.
+    fn to_const_int_from_u128(&self, value: u128, kind: NumKind) -> Option<ConstValue<'ast>> {
+        if kind.is_signed() {
+            self.to_const_int(i128::try_from(value).ok()?, kind)
+        } else {
+            let size = self.num_kind_size(kind);
+            (size.truncate(value) == value).then(|| ConstValue::Int(ConstInt::new(value, kind)))
+        }
+    }
+
+    /// Creates an integer value from the given bits. Bits that don't fit into
+    /// the type are truncated.
+    fn to_const_int_from_bits(&self, bits: u128, kind: NumKind, size: Size) -> ConstValue<'ast> {
+        let bits = size.truncate(bits);
+        if kind.is_signed() {
+            ConstValue::Int(ConstInt::new(size.sign_extend(bits), kind))
+        } else {
+            ConstValue::Int(ConstInt::new(bits, kind))
+        }
+    }
+
+    /// Creates a float value. `f32` values are rounded to `f32` precision.
+    #[allow(clippy::cast_possible_truncation)] // Rounding is intended
+    fn to_const_float(&self, value: f64, kind: NumKind) -> ConstValue<'ast> {
+        let value = if kind == NumKind::F32 {
+            f64::from(value as f32)
+        } else {
+            value
+        };
+        ConstValue::Float(ConstFloat::new(value, kind))
+    }
+}
+
+/// Returns the bits of the integer. Signed values are sign extended.
+#[allow(clippy::cast_sign_loss)] // Signed values are stored sign extended
+fn int_bits(int: ConstInt) -> u128 {
+    match int.as_i128() {
+        Some(value) if int.is_signed() => value as u128,
+        _ => int.as_u128().unwrap_or_default(),
+    }
+}
+
+fn eval_const_cmp<'ast, T: PartialOrd>(op: hir::BinOpKind, left: &T, right: &T) -> Option<ConstValue<'ast>> {
+    let value = match op {
+        hir::BinOpKind::Eq => left == right,
+        hir::BinOpKind::Ne => left != right,
+        hir::BinOpKind::Lt => left < right,
+        hir::BinOpKind::Le => left <= right,
+        hir::BinOpKind::Gt => left > right,
+        hir::BinOpKind::Ge => left >= right,
+        _ => return None,
+    };
+    Some(ConstValue::Bool(value))
+}
@@ -1,12 +1,13 @@
 use marker_api::{
     ast::{
         expr::{
-            ArrayExpr, AsExpr, AssignExpr, AwaitExpr, BinaryOpExpr, BinaryOpKind, BlockExpr, BoolLitExpr, BreakExpr,
-            CallExpr, CaptureKind, CharLitExpr, ClosureExpr, ClosureParam, CommonExprData, ConstExpr, ContinueExpr,
-            CtorExpr, CtorField, ExprKind, ExprPrecedence, FieldExpr, FloatLitExpr, FloatSuffix, ForExpr, IfExpr,
-            IndexExpr, IntLitExpr, IntSuffix, LetExpr, LoopExpr, MatchArm, MatchExpr, MethodExpr, PathExpr,
-            QuestionMarkExpr, RangeExpr, RefExpr, ReturnExpr, StrLitData, StrLitExpr, TupleExpr, UnaryOpExpr,
-            UnaryOpKind, UnstableExpr, WhileExpr,
+            Adjustment, AdjustmentKind, ArrayExpr, AsExpr, AssignExpr, AwaitExpr, BinaryOpExpr, BinaryOpKind,
+            BlockExpr, BoolLitExpr, BreakExpr, CallExpr, CaptureKind, CaptureMode, CaptureProjection, CapturedPlace,
+            CharLitExpr, ClosureCaptures, ClosureExpr, ClosureKind, ClosureParam, CommonExprData, ConstExpr,
+            ContinueExpr, CtorExpr, CtorField, DesugarKind, ExprKind, ExprPrecedence, FieldExpr, FloatLitExpr,
+            FloatSuffix, ForExpr, IfExpr, IndexExpr, IntLitExpr, IntSuffix, LetExpr, LoopExpr, MatchArm, MatchExpr,
+            MethodExpr, PathExpr, PointerCoercion, QuestionMarkExpr, RangeExpr, RefExpr, ReturnExpr, StrLitData,
+            StrLitExpr, TupleExpr, UnaryOpExpr, UnaryOpKind, UnstableExpr, WhileExpr,
         },
         pat::PatKind,
         Ident, Safety, Syncness,
@@ -15,6 +16,7 @@ use marker_api::{
 };
 use rustc_hash::FxHashMap;
 use rustc_hir as hir;
+use rustc_middle::ty::adjustment as rustc_adjustment;
 use std::str::FromStr;
 
 use super::MarkerConverterInner;
@@ -230,13 +232,13 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             hir::ExprKind::Break(dest, expr) => ExprKind::Break(self.alloc(BreakExpr::new(
                 data,
                 dest.label.map(|label| self.to_ident(label.ident)),
-                self.to_expr_id(dest.target_id.expect("rustc would have errored")),
+                self.to_jump_target_id(dest),
                 expr.map(|expr| self.to_expr(expr)),
             ))),
             hir::ExprKind::Continue(dest) => ExprKind::Continue(self.alloc(ContinueExpr::new(
                 data,
                 dest.label.map(|label| self.to_ident(label.ident)),
-                self.to_expr_id(dest.target_id.expect("rustc would have errored")),
+                self.to_jump_target_id(dest),
             ))),
             hir::ExprKind::Ret(expr) => {
                 ExprKind::Return(self.alloc(ReturnExpr::new(data, expr.map(|expr| self.to_expr(expr)))))
@@ -275,6 +277,131 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         expr
     }
 
+    #[must_use]
+    pub fn to_adjustment(&self, adjustment: &rustc_adjustment::Adjustment<'tcx>) -> Adjustment<'ast> {
+        let kind = match &adjustment.kind {
+            rustc_adjustment::Adjust::NeverToAny => AdjustmentKind::NeverToAny,
+            rustc_adjustment::Adjust::Deref(None) => AdjustmentKind::Deref,
+            rustc_adjustment::Adjust::Deref(Some(deref)) => {
+                AdjustmentKind::OverloadedDeref(self.to_mutability(deref.mutbl))
+            },
+            rustc_adjustment::Adjust::Borrow(rustc_adjustment::AutoBorrow::Ref(_, mutbl)) => {
+                AdjustmentKind::Borrow(self.to_mutability((*mutbl).into()))
+            },
+            rustc_adjustment::Adjust::Borrow(rustc_adjustment::AutoBorrow::RawPtr(mutbl)) => {
+                AdjustmentKind::RawBorrow(self.to_mutability(*mutbl))
+            },
+            // `dyn*` coercions are unstable and similar to unsizing coercions
+            rustc_adjustment::Adjust::Pointer(rustc_adjustment::PointerCoercion::Unsize)
+            | rustc_adjustment::Adjust::DynStar => AdjustmentKind::Unsize,
+            rustc_adjustment::Adjust::Pointer(coercion) => AdjustmentKind::Pointer(match coercion {
+                rustc_adjustment::PointerCoercion::ReifyFnPointer => PointerCoercion::ReifyFn,
+                rustc_adjustment::PointerCoercion::UnsafeFnPointer => PointerCoercion::UnsafeFn,
+                rustc_adjustment::PointerCoercion::ClosureFnPointer(_) => PointerCoercion::ClosureFn,
+                rustc_adjustment::PointerCoercion::MutToConstPointer => PointerCoercion::MutToConst,
+                rustc_adjustment::PointerCoercion::ArrayToPointer => PointerCoercion::ArrayToElement,
+                rustc_adjustment::PointerCoercion::Unsize => unreachable!("handled above"),
+            }),
+        };
+        Adjustment::new(kind, self.to_sem_ty(adjustment.target))
+    }
+
+    #[must_use]
+    pub fn to_closure_captures(&self, id: hir::HirId) -> &'ast ClosureCaptures<'ast> {
+        let hir::ExprKind::Closure(closure) = self.rustc_cx.hir().expect_expr(id).kind else {
+            unreachable!("the id should belong to a closure expression")
+        };
+        let ty_check = self.rustc_ty_check();
+        let kind = match ty_check.node_type(id).kind() {
+            rustc_middle::ty::Closure(_, args) => match args.as_closure().kind() {
+                rustc_middle::ty::ClosureKind::Fn => ClosureKind::Fn,
+                rustc_middle::ty::ClosureKind::FnMut => ClosureKind::FnMut,
+                rustc_middle::ty::ClosureKind::FnOnce => ClosureKind::FnOnce,
+            },
+            // Coroutines are currently converted to blocks or unstable
+            // expressions, this keeps the kind correct once they're exposed.
+            _ => ClosureKind::Coroutine,
+        };
+        let captures: Vec<_> = ty_check
+            .closure_min_captures_flattened(closure.def_id)
+            .map(|captured| self.to_captured_place(captured))
+            .collect();
+        let captures = self.alloc_slice(captures);
+        self.alloc(ClosureCaptures::new(kind, captures))
+    }
+
+    #[must_use]
+    fn to_captured_place(&self, captured: &rustc_middle::ty::CapturedPlace<'tcx>) -> CapturedPlace<'ast> {
+        let projections: Vec<_> = captured
+            .place
+            .projections
+            .iter()
+            .filter_map(|proj| match proj.kind {
+                rustc_middle::hir::place::ProjectionKind::Deref => Some(CaptureProjection::Deref),
+                rustc_middle::hir::place::ProjectionKind::Field(field, _) => {
+                    Some(CaptureProjection::Field(field.as_u32()))
+                },
+                // Closures don't capture indices, subslices or opaque casts
+                _ => None,
+            })
+            .collect();
+        let projections = self.alloc_slice(projections);
+        let mode = match captured.info.capture_kind {
+            rustc_middle::ty::UpvarCapture::ByValue => CaptureMode::Value,
+            rustc_middle::ty::UpvarCapture::ByRef(rustc_middle::ty::BorrowKind::ImmBorrow) => CaptureMode::Ref,
+            rustc_middle::ty::UpvarCapture::ByRef(
+                rustc_middle::ty::BorrowKind::UniqueImmBorrow | rustc_middle::ty::BorrowKind::MutBorrow,
+            ) => CaptureMode::MutRef,
+        };
+        CapturedPlace::new(
+            self.to_var_id(captured.get_root_variable()),
+            projections,
+            mode,
+            self.to_sem_ty(captured.place.ty()),
+            self.storage.alloc_str(&captured.to_string(self.rustc_cx)),
+            self.to_span_id(captured.get_path_span(self.rustc_cx)),
+        )
+    }
+
+    #[must_use]
+    pub fn to_desugar_kind(&self, expr: &hir::Expr<'tcx>) -> Option<DesugarKind> {
+        match expr.kind {
+            hir::ExprKind::Match(_, _, hir::MatchSource::ForLoopDesugar)
+            | hir::ExprKind::Loop(_, _, hir::LoopSource::ForLoop, _) => return Some(DesugarKind::ForLoop),
+            hir::ExprKind::Loop(_, _, hir::LoopSource::While, _) => return Some(DesugarKind::WhileLoop),
+            hir::ExprKind::Match(_, _, hir::MatchSource::TryDesugar(_)) => return Some(DesugarKind::QuestionMark),
+            hir::ExprKind::Match(_, _, hir::MatchSource::AwaitDesugar) => return Some(DesugarKind::Await),
+            _ if hir::is_range_literal(expr) => return Some(DesugarKind::Range),
+            _ => {},
+        }
+
+        match expr.span.desugaring_kind()? {
+            rustc_span::DesugaringKind::ForLoop => Some(DesugarKind::ForLoop),
+            rustc_span::DesugaringKind::WhileLoop => Some(DesugarKind::WhileLoop),
+            rustc_span::DesugaringKind::QuestionMark | rustc_span::DesugaringKind::YeetExpr => {
+                Some(DesugarKind::QuestionMark)
+            },
+            rustc_span::DesugaringKind::TryBlock => Some(DesugarKind::TryBlock),
+            rustc_span::DesugaringKind::Await => Some(DesugarKind::Await),
+            rustc_span::DesugaringKind::Async => Some(DesugarKind::Async),
+            // These desugarings are internal to the compiler and don't
+            // correspond to syntactic sugar in the source code
+            rustc_span::DesugaringKind::CondTemporary | rustc_span::DesugaringKind::OpaqueTy => None,
+        }
+    }
+
+    /// The target of labeled blocks is the [`hir::Block`] and not the
+    /// [`hir::Expr`] wrapping it. Marker doesn't distinguish between these two,
+    /// the id of the expression is used in that case.
+    #[must_use]
+    fn to_jump_target_id(&self, dest: &hir::Destination) -> marker_api::ast::ExprId {
+        let target = dest.target_id.expect("rustc would have errored");
+        match self.rustc_cx.hir().find(target) {
+            Some(hir::Node::Block(_)) => self.to_expr_id(self.rustc_cx.hir().parent_id(target)),
+            _ => self.to_expr_id(target),
+        }
+    }
+
     #[must_use]
     fn to_block_expr(
         &self,
@@ -433,6 +560,8 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             Some(hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Block)) => {
                 let block_expr = body.value;
                 if let hir::ExprKind::Block(block, None) = block_expr.kind {
+                    // The type of this expression is mapped to the coroutine
+                    // type of the closure, see `MarkerConverter::expr_ty`.
                     let api_block_expr = self.with_body(body_id, || {
                         self.to_block_expr(
                             CommonExprData::new(self.to_expr_id(block_expr.hir_id), self.to_span_id(block_expr.span)),
@@ -558,6 +687,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 return QuestionMarkExpr::new(
                     CommonExprData::new(self.to_expr_id(try_desugar.hir_id), self.to_span_id(try_desugar.span)),
                     self.to_expr(tested_expr),
+                    self.to_expr_id(scrutinee.hir_id),
                 );
             }
         }
@@ -627,7 +757,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             && let hir::ExprKind::Loop(block, label, hir::LoopSource::ForLoop, _loop_head) = loop_expr.kind
             && let [stmt] = block.stmts
             && let hir::StmtKind::Expr(none_some_match) = stmt.kind
-            && let hir::ExprKind::Match(_, [_none, some_arm], hir::MatchSource::ForLoopDesugar) = none_some_match.kind
+            && let hir::ExprKind::Match(next, [_, some_arm], hir::MatchSource::ForLoopDesugar) = none_some_match.kind
             && let hir::PatKind::Struct(_some, [field], false) = &some_arm.pat.kind
         {
             let pat = self.to_pat(field.pat);
@@ -642,7 +772,9 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 label.map(|label| self.to_ident(label.ident)),
                 pat,
                 iter_expr,
-                body
+                body,
+                self.to_expr_id(into_scrutinee.hir_id),
+                self.to_expr_id(next.hir_id),
             );
         }
 
@@ -690,6 +822,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     self.to_span_id(await_expr.span),
                 ),
                 self.to_expr(future_expr),
+                self.to_expr_id(into_scrutinee.hir_id),
             );
         }
 
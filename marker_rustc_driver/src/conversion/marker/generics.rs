@@ -1,14 +1,18 @@
 use marker_api::ast::{
     generic::{
-        Lifetime, LifetimeKind, SemBindingArg, SemConstArg, SemGenericArgKind, SemGenericArgs, SemTraitBound,
-        SynBindingArg, SynConstArg, SynConstParam, SynGenericArgKind, SynGenericArgs, SynGenericParamKind,
-        SynGenericParams, SynLifetimeArg, SynLifetimeClause, SynLifetimeParam, SynTraitBound, SynTyArg, SynTyClause,
-        SynTyParam, SynTyParamBound, SynWhereClauseKind,
+        Lifetime, LifetimeKind, PredicateSource, SemBindingArg, SemConstArg, SemConstBindingArg, SemGenericArgKind,
+        SemGenericArgs, SemGenericParam, SemGenericParamKind, SemLifetimeOutlivesPredicate, SemPredicate,
+        SemPredicateKind, SemProjectionPredicate, SemTraitBound, SemTraitPredicate, SemTyOutlivesPredicate,
+        SynBindingArg, SynConstArg, SynConstBindingArg, SynConstParam, SynConstraintArg, SynGenericArgKind,
+        SynGenericArgs, SynGenericParamKind, SynGenericParams, SynInferArg, SynLifetimeArg, SynLifetimeClause,
+        SynLifetimeParam, SynTraitBound, SynTyArg, SynTyClause, SynTyParam, SynTyParamBound, SynWhereClauseKind,
     },
-    ConstValue, TraitRef,
+    DriverGenericArgsId, TraitRef,
 };
+use rustc_hash::FxHashSet;
 use rustc_hir as hir;
 use rustc_middle as mid;
+use rustc_span::symbol::kw;
 
 use super::MarkerConverterInner;
 
@@ -31,14 +35,71 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
 }
 
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Converts the generic args of the given item, like an ADT or function.
     #[must_use]
-    pub fn to_sem_generic_args(&self, args: &[mid::ty::GenericArg<'tcx>]) -> SemGenericArgs<'ast> {
-        let args: Vec<_> = args
+    pub fn to_sem_generic_args(
+        &self,
+        item: hir::def_id::DefId,
+        rustc_args: mid::ty::GenericArgsRef<'tcx>,
+    ) -> SemGenericArgs<'ast> {
+        let args = rustc_args
+            .iter()
+            .filter_map(|arg| self.to_sem_generic_arg_kind(arg))
+            .collect();
+        self.alloc_sem_generic_args(item, rustc_args, args)
+    }
+
+    /// Converts the given subset of the args of an item, like the own args of
+    /// an associated type. All args of the item are stored as the rustc args.
+    #[must_use]
+    pub fn to_sem_own_generic_args(
+        &self,
+        item: hir::def_id::DefId,
+        rustc_args: mid::ty::GenericArgsRef<'tcx>,
+        own_args: &[mid::ty::GenericArg<'tcx>],
+    ) -> SemGenericArgs<'ast> {
+        let args = own_args
             .iter()
             .filter_map(|arg| self.to_sem_generic_arg_kind(*arg))
             .collect();
+        self.alloc_sem_generic_args(item, rustc_args, args)
+    }
 
-        SemGenericArgs::new(self.alloc_slice(args))
+    /// Converts the generic args of the given trait reference, without the
+    /// `Self` type, which is stored separately.
+    #[must_use]
+    pub fn to_sem_trait_generic_args(&self, trait_ref: mid::ty::TraitRef<'tcx>) -> SemGenericArgs<'ast> {
+        let args = trait_ref.args[1..]
+            .iter()
+            .filter_map(|arg| self.to_sem_generic_arg_kind(*arg))
+            .collect();
+        self.alloc_sem_generic_args(trait_ref.def_id, trait_ref.args, args)
+    }
+
+    /// Assigns a [`DriverGenericArgsId`] to the converted args. The rustc args
+    /// are stored under this id, to allow generic substitution with
+    /// [`MarkerConverterInner::to_rustc_generic_args`].
+    #[must_use]
+    fn alloc_sem_generic_args(
+        &self,
+        item: hir::def_id::DefId,
+        rustc_args: mid::ty::GenericArgsRef<'tcx>,
+        args: Vec<SemGenericArgKind<'ast>>,
+    ) -> SemGenericArgs<'ast> {
+        let driver_id = {
+            let mut rustc_generic_args = self.rustc_generic_args.borrow_mut();
+            rustc_generic_args.push((item, (rustc_args, self.generics_owner())));
+            DriverGenericArgsId::new(rustc_generic_args.len() as u64 - 1)
+        };
+        // Only type and const parameters can have defaults. The defaulted args
+        // are therefore the last converted args, before any bindings.
+        let generics = self.rustc_cx.generics_of(item);
+        let defaulted_len = if generics.count() == rustc_args.len() {
+            generics.own_args(rustc_args).len() - generics.own_args_no_defaults(self.rustc_cx, rustc_args).len()
+        } else {
+            0
+        };
+        SemGenericArgs::new(driver_id, self.alloc_slice(args), defaulted_len)
     }
 
     #[must_use]
@@ -46,15 +107,18 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         match &arg.unpack() {
             mid::ty::GenericArgKind::Lifetime(_) => None,
             mid::ty::GenericArgKind::Type(ty) => Some(SemGenericArgKind::Ty(self.to_sem_ty(*ty))),
-            mid::ty::GenericArgKind::Const(_) => Some(SemGenericArgKind::Const(
-                self.alloc(SemConstArg::new(ConstValue::new())),
+            mid::ty::GenericArgKind::Const(value) => Some(SemGenericArgKind::Const(
+                self.alloc(SemConstArg::new(self.to_const_value_from_ty_const(*value))),
             )),
         }
     }
 
+    /// Converts the bounds of a trait object. The given `self_ty` is the trait
+    /// object type itself, which is used as the `Self` type of the bounds.
     pub fn to_sem_trait_bounds(
         &self,
         bounds: &mid::ty::List<mid::ty::PolyExistentialPredicate<'tcx>>,
+        self_ty: mid::ty::Ty<'tcx>,
     ) -> &'ast [SemTraitBound<'ast>] {
         let mut marker_bounds = vec![];
 
@@ -71,11 +135,12 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         //
         // [`E0225`]: https://doc.rust-lang.org/stable/error_codes/E0225.html
         if let Some(main) = bounds.principal() {
-            let main = main.skip_binder();
+            let main = main.skip_binder().with_self_ty(self.rustc_cx, self_ty);
 
             let mut generics: Vec<_> = main
                 .args
                 .iter()
+                .skip(1)
                 .filter_map(|arg| self.to_sem_generic_arg_kind(arg))
                 .collect();
 
@@ -85,25 +150,372 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     mid::ty::TermKind::Ty(ty) => generics.push(SemGenericArgKind::Binding(self.alloc(
                         SemBindingArg::new(self.to_item_id(binding.item_def_id()), self.to_sem_ty(ty)),
                     ))),
-                    mid::ty::TermKind::Const(_) => todo!(),
+                    mid::ty::TermKind::Const(value) => {
+                        generics.push(SemGenericArgKind::ConstBinding(self.alloc(SemConstBindingArg::new(
+                            self.to_item_id(binding.item_def_id()),
+                            self.to_const_value_from_ty_const(value),
+                        ))));
+                    },
                 });
 
             marker_bounds.push(SemTraitBound::new(
                 false,
                 self.to_ty_def_id(main.def_id),
-                SemGenericArgs::new(self.alloc_slice(generics)),
+                self.alloc_sem_generic_args(main.def_id, main.args, generics),
             ));
         }
 
         bounds
             .auto_traits()
             .map(|auto_trait_id| {
-                SemTraitBound::new(false, self.to_ty_def_id(auto_trait_id), self.to_sem_generic_args(&[]))
+                let rustc_args = self.rustc_cx.mk_args(&[self_ty.into()]);
+                SemTraitBound::new(
+                    false,
+                    self.to_ty_def_id(auto_trait_id),
+                    self.alloc_sem_generic_args(auto_trait_id, rustc_args, vec![]),
+                )
             })
             .collect_into(&mut marker_bounds);
 
         self.alloc_slice(marker_bounds)
     }
+
+    /// Converts the trait bounds of an opaque type. Bindings, like `Item = u8`,
+    /// are stored as separate projection predicates by rustc. These are
+    /// reattached to the generic args of the bound trait, which declares or
+    /// inherits the bound associated item. Bounds of associated items, like the
+    /// `Debug` in `impl Iterator<Item: Debug>`, and the implicit `Sized` bound
+    /// are skipped.
+    #[must_use]
+    pub fn to_sem_opaque_bounds(&self, opaque: mid::ty::AliasTy<'tcx>) -> &'ast [SemTraitBound<'ast>] {
+        let tcx = self.rustc_cx;
+        let self_ty = opaque.to_ty(tcx);
+        let sized_trait = tcx.lang_items().sized_trait();
+        let clauses: Vec<_> = tcx
+            .explicit_item_bounds(opaque.def_id)
+            .iter_instantiated_copied(tcx, opaque.args)
+            .map(|(clause, _span)| clause.kind().skip_binder())
+            .collect();
+
+        let mut bounds: Vec<(mid::ty::TraitRef<'tcx>, Vec<SemGenericArgKind<'ast>>)> = clauses
+            .iter()
+            .filter_map(|clause| match clause {
+                mid::ty::ClauseKind::Trait(pred) if pred.self_ty() == self_ty && Some(pred.def_id()) != sized_trait => {
+                    // The first arg is the `Self` type, which is the opaque type itself
+                    let args = pred.trait_ref.args[1..]
+                        .iter()
+                        .filter_map(|arg| self.to_sem_generic_arg_kind(*arg))
+                        .collect();
+                    Some((pred.trait_ref, args))
+                },
+                _ => None,
+            })
+            .collect();
+
+        for clause in &clauses {
+            if let mid::ty::ClauseKind::Projection(pred) = clause
+                && pred.self_ty() == self_ty
+                && let item_trait = pred.projection_ty.trait_def_id(tcx)
+                && let Some((_, args)) = bounds
+                    .iter_mut()
+                    .find(|(trait_ref, _)| self.is_same_or_subtrait(trait_ref.def_id, item_trait))
+            {
+                let item_id = self.to_item_id(pred.projection_ty.def_id);
+                match pred.term.unpack() {
+                    mid::ty::TermKind::Ty(ty) => {
+                        args.push(SemGenericArgKind::Binding(
+                            self.alloc(SemBindingArg::new(item_id, self.to_sem_ty(ty))),
+                        ));
+                    },
+                    mid::ty::TermKind::Const(value) => {
+                        args.push(SemGenericArgKind::ConstBinding(self.alloc(SemConstBindingArg::new(
+                            item_id,
+                            self.to_const_value_from_ty_const(value),
+                        ))));
+                    },
+                }
+            }
+        }
+
+        let bounds: Vec<_> = bounds
+            .into_iter()
+            .map(|(trait_ref, args)| {
+                SemTraitBound::new(
+                    false,
+                    self.to_ty_def_id(trait_ref.def_id),
+                    self.alloc_sem_generic_args(trait_ref.def_id, trait_ref.args, args),
+                )
+            })
+            .collect();
+        self.alloc_slice(bounds)
+    }
+
+    fn is_same_or_subtrait(&self, trait_id: hir::def_id::DefId, super_trait_id: hir::def_id::DefId) -> bool {
+        trait_id == super_trait_id
+            || self
+                .rustc_cx
+                .super_predicates_of(trait_id)
+                .predicates
+                .iter()
+                .filter_map(|(clause, _span)| clause.as_trait_clause())
+                .any(|pred| self.is_same_or_subtrait(pred.def_id(), super_trait_id))
+    }
+}
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Converts the predicates of the given item, which pass the given filter.
+    /// See [`MarkerConverterInner::rustc_predicates_of`] for the collection of
+    /// the predicates.
+    #[must_use]
+    pub fn to_sem_predicates(
+        &self,
+        owner: hir::def_id::DefId,
+        filter: impl Fn(mid::ty::ClauseKind<'tcx>) -> bool,
+    ) -> &'ast [SemPredicate<'ast>] {
+        let predicates: Vec<_> = self
+            .rustc_predicates_of(owner)
+            .into_iter()
+            .filter(|(clause, _span, _source)| filter(clause.kind().skip_binder()))
+            .filter_map(|(clause, span, source)| self.to_sem_predicate(clause, span, source))
+            .collect();
+        self.alloc_slice(predicates)
+    }
+
+    /// Collects the predicates of the given item, together with their source:
+    /// * The own predicates of the item are declared, unless they were added by the compiler, like
+    ///   inferred outlives bounds and implicit `Sized` bounds. The bounds of associated and opaque
+    ///   types are declared as well.
+    /// * Predicates of the parent items, like the `impl` block of an associated item, are
+    ///   inherited.
+    /// * Supertrait bounds of trait predicates are implied. These are elaborated transitively.
+    fn rustc_predicates_of(
+        &self,
+        owner: hir::def_id::DefId,
+    ) -> Vec<(mid::ty::Clause<'tcx>, rustc_span::Span, PredicateSource)> {
+        let tcx = self.rustc_cx;
+        let inferred_outlives = tcx.inferred_outlives_of(owner);
+        let mut seen = FxHashSet::default();
+        let mut predicates = vec![];
+
+        let own_predicates = tcx.predicates_of(owner);
+        for &(clause, span) in own_predicates.predicates {
+            let source = if inferred_outlives.iter().any(|(inferred, _span)| *inferred == clause)
+                || self.is_implicit_clause(owner, clause, span)
+            {
+                PredicateSource::Implied
+            } else {
+                PredicateSource::Declared
+            };
+            if seen.insert(clause) {
+                predicates.push((clause, span, source));
+            } else if source == PredicateSource::Declared
+                && let Some(entry) = predicates.iter_mut().find(|(other, ..)| *other == clause)
+            {
+                // Explicit `T: Sized` bounds are stored next to the implicit ones
+                *entry = (clause, span, source);
+            }
+        }
+
+        // The bounds of associated and opaque types, like `type Item: Clone;`,
+        // are not part of their predicates.
+        if matches!(
+            tcx.def_kind(owner),
+            hir::def::DefKind::AssocTy | hir::def::DefKind::OpaqueTy
+        ) {
+            for &(clause, span) in tcx.explicit_item_bounds(owner).skip_binder() {
+                let source = if self.is_implicit_clause(owner, clause, span) {
+                    PredicateSource::Implied
+                } else {
+                    PredicateSource::Declared
+                };
+                if seen.insert(clause) {
+                    predicates.push((clause, span, source));
+                }
+            }
+        }
+
+        let mut parent = own_predicates.parent;
+        while let Some(parent_id) = parent {
+            let parent_predicates = tcx.predicates_of(parent_id);
+            for &(clause, span) in parent_predicates.predicates {
+                if seen.insert(clause) {
+                    predicates.push((clause, span, PredicateSource::Inherited));
+                }
+            }
+            parent = parent_predicates.parent;
+        }
+
+        // Elaborated predicates are appended, which makes sure that they are
+        // elaborated as well.
+        let mut index = 0;
+        while let Some(&(clause, span, _source)) = predicates.get(index) {
+            if let Some(trait_pred) = clause.as_trait_clause() {
+                let trait_ref = trait_pred.map_bound(|pred| pred.trait_ref);
+                for &(super_clause, _span) in tcx.super_predicates_of(trait_pred.def_id()).predicates {
+                    let implied = super_clause.subst_supertrait(tcx, &trait_ref);
+                    if seen.insert(implied) {
+                        predicates.push((implied, span, PredicateSource::Implied));
+                    }
+                }
+            }
+            index += 1;
+        }
+
+        predicates
+    }
+
+    /// Checks if the given clause was added by the compiler. These are the
+    /// implicit `Sized` bounds of type parameters, associated types and opaque
+    /// types and the `Self: Trait` predicate of traits.
+    fn is_implicit_clause(
+        &self,
+        owner: hir::def_id::DefId,
+        clause: mid::ty::Clause<'tcx>,
+        span: rustc_span::Span,
+    ) -> bool {
+        let tcx = self.rustc_cx;
+        let Some(trait_pred) = clause.as_trait_clause() else {
+            return false;
+        };
+        let trait_id = trait_pred.def_id();
+        if trait_id == owner {
+            return true;
+        }
+
+        // Implicit `Sized` bounds use the span of the parameter
+        if Some(trait_id) == tcx.lang_items().sized_trait()
+            && let mid::ty::TyKind::Param(param) = trait_pred.self_ty().skip_binder().kind()
+        {
+            let param_id = tcx.generics_of(owner).type_param(param, tcx).def_id;
+            return tcx.def_span(param_id) == span;
+        }
+
+        // Implicit `Sized` bounds of associated and opaque types use the span
+        // of the item, which starts before any declared bound
+        if Some(trait_id) == tcx.lang_items().sized_trait()
+            && let mid::ty::TyKind::Alias(_, alias) = trait_pred.self_ty().skip_binder().kind()
+            && alias.def_id == owner
+        {
+            return tcx.def_span(owner).lo() == span.lo();
+        }
+
+        false
+    }
+
+    fn to_sem_predicate(
+        &self,
+        clause: mid::ty::Clause<'tcx>,
+        span: rustc_span::Span,
+        source: PredicateSource,
+    ) -> Option<SemPredicate<'ast>> {
+        let kind = match clause.kind().skip_binder() {
+            mid::ty::ClauseKind::Trait(pred) => {
+                if pred.polarity != mid::ty::ImplPolarity::Positive {
+                    return None;
+                }
+                let bound = SemTraitBound::new(
+                    false,
+                    self.to_ty_def_id(pred.def_id()),
+                    self.to_sem_trait_generic_args(pred.trait_ref),
+                );
+                SemPredicateKind::Trait(self.alloc(SemTraitPredicate::new(self.to_sem_ty(pred.self_ty()), bound)))
+            },
+            mid::ty::ClauseKind::TypeOutlives(mid::ty::OutlivesPredicate(ty, region)) => {
+                SemPredicateKind::TyOutlives(self.alloc(SemTyOutlivesPredicate::new(
+                    self.to_sem_ty(ty),
+                    self.to_sem_lifetime(region),
+                )))
+            },
+            mid::ty::ClauseKind::RegionOutlives(mid::ty::OutlivesPredicate(longer, shorter)) => {
+                SemPredicateKind::LifetimeOutlives(self.alloc(SemLifetimeOutlivesPredicate::new(
+                    self.to_sem_lifetime(longer),
+                    self.to_sem_lifetime(shorter),
+                )))
+            },
+            mid::ty::ClauseKind::Projection(pred) => {
+                let mid::ty::TermKind::Ty(ty) = pred.term.unpack() else {
+                    return None;
+                };
+                let trait_ref = pred.projection_ty.trait_ref(self.rustc_cx);
+                SemPredicateKind::Projection(self.alloc(SemProjectionPredicate::new(
+                    self.to_sem_ty(trait_ref.self_ty()),
+                    self.to_ty_def_id(trait_ref.def_id),
+                    self.to_sem_trait_generic_args(trait_ref),
+                    self.to_item_id(pred.projection_ty.def_id),
+                    self.to_sem_ty(ty),
+                )))
+            },
+            _ => return None,
+        };
+
+        Some(SemPredicate::new(kind, source, self.to_span_id(span)))
+    }
+
+    #[must_use]
+    fn to_sem_lifetime(&self, region: mid::ty::Region<'tcx>) -> Lifetime<'ast> {
+        let kind = match region.kind() {
+            mid::ty::RegionKind::ReEarlyBound(early) => {
+                LifetimeKind::Label(self.to_symbol_id(early.name), self.to_generic_id(early.def_id))
+            },
+            mid::ty::RegionKind::ReFree(mid::ty::FreeRegion {
+                bound_region: mid::ty::BoundRegionKind::BrNamed(def_id, name),
+                ..
+            }) if name != kw::UnderscoreLifetime => {
+                LifetimeKind::Label(self.to_symbol_id(name), self.to_generic_id(def_id))
+            },
+            mid::ty::RegionKind::ReStatic => LifetimeKind::Static,
+            _ => LifetimeKind::Infer,
+        };
+        Lifetime::new(None, kind)
+    }
+
+    /// Returns the item, which declares the given generic parameter. The `Self`
+    /// parameter of traits uses the id of the trait itself.
+    pub fn generic_param_owner(&self, param_id: hir::def_id::DefId) -> hir::def_id::DefId {
+        match self.rustc_cx.def_kind(param_id) {
+            hir::def::DefKind::Trait | hir::def::DefKind::TraitAlias => param_id,
+            _ => self.rustc_cx.parent(param_id),
+        }
+    }
+
+    #[must_use]
+    pub fn to_sem_generic_param(
+        &self,
+        param_id: hir::def_id::DefId,
+        owner: hir::def_id::DefId,
+    ) -> &'ast SemGenericParam<'ast> {
+        let tcx = self.rustc_cx;
+        let (name, kind) = match tcx.def_kind(param_id) {
+            hir::def::DefKind::LifetimeParam => (tcx.item_name(param_id), SemGenericParamKind::Lifetime),
+            hir::def::DefKind::TyParam => (tcx.item_name(param_id), SemGenericParamKind::Ty),
+            hir::def::DefKind::ConstParam => (tcx.item_name(param_id), SemGenericParamKind::Const),
+            hir::def::DefKind::Trait | hir::def::DefKind::TraitAlias => (kw::SelfUpper, SemGenericParamKind::Ty),
+            _ => unreachable!("generic ids always belong to generic parameters"),
+        };
+
+        let generics = tcx.generics_of(owner);
+        let is_param_ty = |ty: mid::ty::Ty<'tcx>| match ty.kind() {
+            mid::ty::TyKind::Param(param) => generics.type_param(param, tcx).def_id == param_id,
+            _ => false,
+        };
+        let bounds = self.to_sem_predicates(owner, |clause| match clause {
+            mid::ty::ClauseKind::Trait(pred) => is_param_ty(pred.self_ty()),
+            mid::ty::ClauseKind::Projection(pred) => is_param_ty(pred.projection_ty.self_ty()),
+            mid::ty::ClauseKind::TypeOutlives(mid::ty::OutlivesPredicate(ty, _)) => is_param_ty(ty),
+            mid::ty::ClauseKind::RegionOutlives(mid::ty::OutlivesPredicate(region, _)) => {
+                matches!(region.kind(), mid::ty::RegionKind::ReEarlyBound(early) if early.def_id == param_id)
+            },
+            _ => false,
+        });
+
+        self.alloc(SemGenericParam::new(
+            self.to_generic_id(param_id),
+            self.to_item_id(owner),
+            self.to_symbol_id(name),
+            kind,
+            bounds,
+        ))
+    }
 }
 
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
@@ -132,7 +544,9 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     self.to_span_id(arg.span),
                     self.to_const_expr(arg.value),
                 )))),
-                rustc_hir::GenericArg::Infer(_) => todo!(),
+                rustc_hir::GenericArg::Infer(infer) => Some(SynGenericArgKind::Infer(
+                    self.alloc(SynInferArg::new(self.to_span_id(infer.span))),
+                )),
             })
             .collect();
         args.extend(rustc_args.bindings.iter().map(|binding| match &binding.kind {
@@ -144,9 +558,19 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                         self.to_syn_ty(rustc_ty),
                     )
                 })),
-                rustc_hir::Term::Const(_) => todo!(),
+                rustc_hir::Term::Const(anon) => SynGenericArgKind::ConstBinding(self.alloc(SynConstBindingArg::new(
+                    self.to_span_id(binding.span),
+                    self.to_symbol_id(binding.ident.name),
+                    self.to_const_expr(*anon),
+                ))),
+            },
+            rustc_hir::TypeBindingKind::Constraint { bounds } => {
+                SynGenericArgKind::Constraint(self.alloc(SynConstraintArg::new(
+                    self.to_span_id(binding.span),
+                    self.to_symbol_id(binding.ident.name),
+                    self.to_syn_ty_param_bound(bounds),
+                )))
             },
-            rustc_hir::TypeBindingKind::Constraint { .. } => todo!(),
         }));
         SynGenericArgs::new(self.alloc_slice(args))
     }
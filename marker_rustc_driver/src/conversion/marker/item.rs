@@ -3,11 +3,12 @@ use marker_api::{
         expr,
         item::{
             AdtKind, AssocItemKind, Body, CommonItemData, ConstItem, EnumItem, EnumVariant, ExternBlockItem,
-            ExternCrateItem, ExternItemKind, Field, FnItem, FnParam, ImplItem, ItemKind, ModItem, StaticItem,
-            StructItem, TraitItem, TyAliasItem, UnionItem, UnstableItem, UseItem, UseKind, Visibility,
+            ExternCrateItem, ExternItemKind, Field, FnItem, FnParam, ForeignTyItem, ImplItem, ItemKind, ModItem,
+            StaticItem, StructItem, TraitItem, TyAliasItem, UnionItem, UnstableItem, UseItem, UseKind, Visibility,
+            VisibilityKind,
         },
         pat::{CommonPatData, IdentPat, PatKind},
-        Abi, Constness, Mutability, Safety, Syncness,
+        Abi, Constness, ItemId, Mutability, Safety, Syncness,
     },
     CtorBlocker,
 };
@@ -31,6 +32,25 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         self.to_item(item)
     }
 
+    /// Associated items and items of `extern` blocks are converted as part of
+    /// their owner. The owner is therefore converted first, to then take the
+    /// requested item from the cache.
+    #[must_use]
+    pub fn to_item_from_owner_id(&self, owner_id: hir::OwnerId) -> Option<ItemKind<'ast>> {
+        match self.rustc_cx.hir().owner(owner_id) {
+            hir::OwnerNode::Item(item) => self.to_item(item),
+            hir::OwnerNode::ForeignItem(_) | hir::OwnerNode::TraitItem(_) | hir::OwnerNode::ImplItem(_) => {
+                let parent = self
+                    .rustc_cx
+                    .hir()
+                    .get_parent_item(hir::HirId::make_owner(owner_id.def_id));
+                self.to_item_from_owner_id(parent)?;
+                self.items.borrow().get(&self.to_item_id(owner_id)).copied()
+            },
+            hir::OwnerNode::Crate(_) => None,
+        }
+    }
+
     #[must_use]
     pub fn to_item(&self, rustc_item: &'tcx hir::Item<'tcx>) -> Option<ItemKind<'ast>> {
         let id = self.to_item_id(rustc_item.owner_id);
@@ -45,7 +65,13 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
 
         let ident = self.to_ident(rustc_item.ident);
-        let data = CommonItemData::new(id, self.to_span_id(rustc_item.span), ident);
+        let data = CommonItemData::new(
+            id,
+            self.to_span_id(rustc_item.span),
+            self.to_visibility(rustc_item.owner_id.def_id, Some(rustc_item.vis_span)),
+            ident,
+            None,
+        );
         let item =
             match &rustc_item.kind {
                 hir::ItemKind::ExternCrate(original_name) => ItemKind::ExternCrate(self.alloc({
@@ -57,7 +83,12 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                         hir::UseKind::Glob => UseKind::Glob,
                         hir::UseKind::ListStem => return None,
                     };
-                    ItemKind::Use(self.alloc(UseItem::new(data, self.to_path(path), use_kind)))
+                    ItemKind::Use(self.alloc(UseItem::new(
+                        data,
+                        self.to_path(path),
+                        use_kind,
+                        self.to_use_targets(&path.res),
+                    )))
                 },
                 hir::ItemKind::Static(rustc_ty, rustc_mut, rustc_body_id) => ItemKind::Static(self.alloc({
                     StaticItem::new(
@@ -90,7 +121,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 },
                 hir::ItemKind::ForeignMod { abi, items } => ItemKind::ExternBlock(self.alloc({
                     let abi = self.to_abi(*abi);
-                    ExternBlockItem::new(data, abi, self.to_external_items(items, abi))
+                    ExternBlockItem::new(data, abi, self.to_external_items(id, items, abi))
                 })),
                 hir::ItemKind::Macro(_, _) | hir::ItemKind::GlobalAsm(_) => return None,
                 hir::ItemKind::TyAlias(rustc_ty, rustc_generics) => ItemKind::TyAlias(self.alloc({
@@ -135,7 +166,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                         matches!(unsafety, hir::Unsafety::Unsafe),
                         self.to_syn_generic_params(generics),
                         self.to_syn_ty_param_bound(bounds),
-                        self.to_assoc_items(items),
+                        self.to_assoc_items(id, items),
                     )
                 })),
                 hir::ItemKind::TraitAlias(_, _) => ItemKind::Unstable(self.alloc(UnstableItem::new(
@@ -150,7 +181,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                         imp.of_trait.as_ref().map(|trait_ref| self.to_trait_ref(trait_ref)),
                         self.to_syn_generic_params(imp.generics),
                         self.to_syn_ty(imp.self_ty),
-                        self.to_assoc_items_from_impl(imp.items),
+                        self.to_assoc_items_from_impl(id, imp.items),
                     )
                 })),
             };
@@ -235,6 +266,45 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
     }
 
+    /// Trait items don't have a visibility modifier, they always inherit the
+    /// visibility of the trait. Therefore, `vis_span` should be `None` for them.
+    ///
+    /// HIR doesn't store the written restriction path. The kind is instead
+    /// derived from the module, which the visibility is resolved to. See
+    /// [`VisibilityKind`] for the order, in which the kinds are checked.
+    fn to_visibility(&self, def_id: hir::def_id::LocalDefId, vis_span: Option<rustc_span::Span>) -> Visibility<'ast> {
+        let tcx = self.rustc_cx;
+        let vis_span = vis_span.filter(|span| !span.is_empty());
+        let scope = match tcx.visibility(def_id) {
+            rustc_middle::ty::Visibility::Public => None,
+            rustc_middle::ty::Visibility::Restricted(module) => Some(module),
+        };
+        let kind = match (vis_span, scope) {
+            (None, _) => VisibilityKind::Inherited,
+            (Some(_), None) => VisibilityKind::Public,
+            (Some(_), Some(module)) if module.is_crate_root() => VisibilityKind::Crate,
+            (Some(_), Some(module)) => {
+                let own_module = tcx.parent_module_from_def_id(def_id);
+                if module == own_module.to_def_id() {
+                    VisibilityKind::SelfModule
+                } else if module == tcx.parent_module_from_def_id(own_module.to_local_def_id()).to_def_id() {
+                    VisibilityKind::Super
+                } else {
+                    VisibilityKind::InPath
+                }
+            },
+        };
+        let effective_vis = self.rustc_cx.effective_visibilities(());
+
+        Visibility::new(
+            kind,
+            vis_span.map(|span| self.to_span_id(span)),
+            scope.map(|module| self.to_item_id(module)),
+            effective_vis.is_exported(def_id),
+            effective_vis.is_reachable(def_id),
+        )
+    }
+
     fn to_adt_kind(&self, var_data: &'tcx hir::VariantData) -> AdtKind<'ast> {
         match var_data {
             hir::VariantData::Struct(fields, _recovered) => AdtKind::Field(self.to_fields(fields).into()),
@@ -245,11 +315,9 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
 
     fn to_fields(&self, fields: &'tcx [hir::FieldDef]) -> &'ast [Field<'ast>] {
         self.alloc_slice(fields.iter().map(|field| {
-            // FIXME update Visibility creation to use the stored local def id inside the
-            // field after the next sync. See #55
             Field::new(
                 self.to_field_id(field.hir_id),
-                Visibility::new(self.to_item_id(field.def_id)),
+                self.to_visibility(field.def_id, Some(field.vis_span)),
                 self.to_symbol_id(field.ident.name),
                 self.to_syn_ty(field.ty),
                 self.to_span_id(field.span),
@@ -257,23 +325,35 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }))
     }
 
-    fn to_external_items(&self, items: &'tcx [hir::ForeignItemRef], abi: Abi) -> &'ast [ExternItemKind<'ast>] {
-        self.alloc_slice(items.iter().map(|item| self.to_external_item(item, abi)))
+    fn to_external_items(
+        &self,
+        owner: ItemId,
+        items: &'tcx [hir::ForeignItemRef],
+        abi: Abi,
+    ) -> &'ast [ExternItemKind<'ast>] {
+        self.alloc_slice(items.iter().map(|item| self.to_external_item(owner, item, abi)))
     }
 
-    fn to_external_item(&self, rustc_item: &'tcx hir::ForeignItemRef, abi: Abi) -> ExternItemKind<'ast> {
+    fn to_external_item(&self, owner: ItemId, rustc_item: &'tcx hir::ForeignItemRef, abi: Abi) -> ExternItemKind<'ast> {
         let id = self.to_item_id(rustc_item.id.owner_id);
         if let Some(item) = self.items.borrow().get(&id) {
             return match item {
                 ItemKind::Static(data) => ExternItemKind::Static(data, CtorBlocker::new()),
                 ItemKind::Fn(data) => ExternItemKind::Fn(data, CtorBlocker::new()),
+                ItemKind::ForeignTy(data) => ExternItemKind::ForeignTy(data, CtorBlocker::new()),
                 #[expect(non_exhaustive_omitted_patterns)]
-                _ => unreachable!("only static and `Static` and `Fn` items can be found a foreign item id"),
+                _ => unreachable!("only `Static`, `Fn` and `ForeignTy` items can be found by a foreign item id"),
             };
         }
 
         let foreign_item = self.rustc_cx.hir().foreign_item(rustc_item.id);
-        let data = CommonItemData::new(id, self.to_span_id(rustc_item.span), self.to_ident(rustc_item.ident));
+        let data = CommonItemData::new(
+            id,
+            self.to_span_id(rustc_item.span),
+            self.to_visibility(rustc_item.id.owner_id.def_id, Some(foreign_item.vis_span)),
+            self.to_ident(rustc_item.ident),
+            Some(owner),
+        );
         let item = match &foreign_item.kind {
             hir::ForeignItemKind::Fn(decl, idents, generics) => {
                 let return_ty = if let hir::FnRetTy::Return(rust_ty) = decl.output {
@@ -308,7 +388,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 CtorBlocker::new(),
             ),
             hir::ForeignItemKind::Type => {
-                todo!("foreign type are currently sadly not supported. See rust-marker/marker#182")
+                ExternItemKind::ForeignTy(self.alloc(ForeignTyItem::new(data)), CtorBlocker::new())
             },
         };
 
@@ -316,11 +396,11 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         item
     }
 
-    fn to_assoc_items(&self, items: &[hir::TraitItemRef]) -> &'ast [AssocItemKind<'ast>] {
-        self.alloc_slice(items.iter().map(|item| self.to_assoc_item(item)))
+    fn to_assoc_items(&self, owner: ItemId, items: &[hir::TraitItemRef]) -> &'ast [AssocItemKind<'ast>] {
+        self.alloc_slice(items.iter().map(|item| self.to_assoc_item(owner, item)))
     }
 
-    fn to_assoc_item(&self, rustc_item: &hir::TraitItemRef) -> AssocItemKind<'ast> {
+    fn to_assoc_item(&self, owner: ItemId, rustc_item: &hir::TraitItemRef) -> AssocItemKind<'ast> {
         let id = self.to_item_id(rustc_item.id.owner_id);
         if let Some(item) = self.items.borrow().get(&id) {
             return match item {
@@ -333,7 +413,13 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
 
         let trait_item = self.rustc_cx.hir().trait_item(rustc_item.id);
-        let data = CommonItemData::new(id, self.to_span_id(rustc_item.span), self.to_ident(rustc_item.ident));
+        let data = CommonItemData::new(
+            id,
+            self.to_span_id(rustc_item.span),
+            self.to_visibility(rustc_item.id.owner_id.def_id, None),
+            self.to_ident(rustc_item.ident),
+            Some(owner),
+        );
 
         let item = match &trait_item.kind {
             hir::TraitItemKind::Const(ty, body_id) => AssocItemKind::Const(
@@ -365,11 +451,11 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         item
     }
 
-    fn to_assoc_items_from_impl(&self, items: &[hir::ImplItemRef]) -> &'ast [AssocItemKind<'ast>] {
-        self.alloc_slice(items.iter().map(|item| self.to_assoc_item_from_impl(item)))
+    fn to_assoc_items_from_impl(&self, owner: ItemId, items: &[hir::ImplItemRef]) -> &'ast [AssocItemKind<'ast>] {
+        self.alloc_slice(items.iter().map(|item| self.to_assoc_item_from_impl(owner, item)))
     }
 
-    fn to_assoc_item_from_impl(&self, rustc_item: &hir::ImplItemRef) -> AssocItemKind<'ast> {
+    fn to_assoc_item_from_impl(&self, owner: ItemId, rustc_item: &hir::ImplItemRef) -> AssocItemKind<'ast> {
         let id = self.to_item_id(rustc_item.id.owner_id);
         if let Some(item) = self.items.borrow().get(&id) {
             return match item {
@@ -382,7 +468,13 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
 
         let impl_item = self.rustc_cx.hir().impl_item(rustc_item.id);
-        let data = CommonItemData::new(id, self.to_span_id(rustc_item.span), self.to_ident(rustc_item.ident));
+        let data = CommonItemData::new(
+            id,
+            self.to_span_id(rustc_item.span),
+            self.to_visibility(rustc_item.id.owner_id.def_id, Some(impl_item.vis_span)),
+            self.to_ident(rustc_item.ident),
+            Some(owner),
+        );
 
         let item = match &impl_item.kind {
             hir::ImplItemKind::Const(ty, body_id) => AssocItemKind::Const(
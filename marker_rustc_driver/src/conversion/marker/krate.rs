@@ -0,0 +1,110 @@
+use std::path::Path;
+
+use marker_adapter::DEPENDENCY_VERSIONS_ENV;
+use marker_api::ast::{CfgOption, Crate, CrateType, Dependency, Edition};
+use rustc_hash::FxHashMap;
+use rustc_hir as hir;
+
+use super::MarkerConverterInner;
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    #[must_use]
+    pub fn to_crate(
+        &self,
+        rustc_crate_id: hir::def_id::CrateNum,
+        rustc_root_mod: &'tcx hir::Mod<'tcx>,
+    ) -> &'ast Crate<'ast> {
+        let sess = self.rustc_cx.sess;
+        self.alloc(Crate::new(
+            self.to_crate_id(rustc_crate_id),
+            self.to_symbol_id(self.rustc_cx.crate_name(rustc_crate_id)),
+            self.to_edition(sess.edition()),
+            self.to_crate_type(self.rustc_cx.crate_types()),
+            sess.opts.test,
+            self.to_cfg_options(),
+            self.manifest_path(),
+            self.to_dependencies(),
+            self.to_items(rustc_root_mod.item_ids),
+        ))
+    }
+
+    fn to_edition(&self, edition: rustc_span::edition::Edition) -> Edition {
+        match edition {
+            rustc_span::edition::Edition::Edition2015 => Edition::Edition2015,
+            rustc_span::edition::Edition::Edition2018 => Edition::Edition2018,
+            rustc_span::edition::Edition::Edition2021 => Edition::Edition2021,
+            rustc_span::edition::Edition::Edition2024 => Edition::Edition2024,
+        }
+    }
+
+    fn to_crate_type(&self, crate_types: &[rustc_session::config::CrateType]) -> CrateType {
+        // A crate can be compiled as multiple library types at once. However,
+        // binaries and proc macros always have a single crate type.
+        match crate_types {
+            [rustc_session::config::CrateType::Executable] => CrateType::Bin,
+            [rustc_session::config::CrateType::ProcMacro] => CrateType::ProcMacro,
+            _ => CrateType::Lib,
+        }
+    }
+
+    /// Rustc stores the `cfg`s in a hash set. They're sorted, to provide a
+    /// stable order.
+    fn to_cfg_options(&self) -> &'ast [CfgOption<'ast>] {
+        let mut cfgs: Vec<_> = self.rustc_cx.sess.parse_sess.config.iter().collect();
+        cfgs.sort_unstable_by_key(|(name, value)| (name.as_str(), value.as_ref().map(rustc_span::Symbol::as_str)));
+        self.alloc_slice(cfgs.into_iter().map(|(name, value)| {
+            CfgOption::new(
+                self.storage.alloc_str(name.as_str()),
+                value.map(|value| self.storage.alloc_str(value.as_str())),
+            )
+        }))
+    }
+
+    /// Cargo sets the `CARGO_MANIFEST_DIR` environment variable, for every
+    /// crate it compiles.
+    fn manifest_path(&self) -> Option<&'ast str> {
+        let dir = std::env::var_os("CARGO_MANIFEST_DIR")?;
+        let path = Path::new(&dir).join("Cargo.toml");
+        Some(self.storage.alloc_str(path.to_str()?))
+    }
+
+    fn to_dependencies(&self) -> &'ast [Dependency<'ast>] {
+        let tcx = self.rustc_cx;
+        let versions = self.dependency_versions();
+        let deps: Vec<_> = tcx
+            .crates(())
+            .iter()
+            .filter(|krate| {
+                tcx.extern_crate(krate.as_def_id())
+                    .is_some_and(rustc_session::cstore::ExternCrate::is_direct)
+            })
+            .map(|krate| {
+                let name = tcx.crate_name(*krate);
+                Dependency::new(
+                    self.to_crate_id(*krate),
+                    self.storage.alloc_str(name.as_str()),
+                    versions
+                        .get(name.as_str())
+                        .map(|version| self.storage.alloc_str(version)),
+                )
+            })
+            .collect();
+        self.alloc_slice(deps)
+    }
+
+    /// Rustc doesn't know the version of dependencies. `cargo-marker` provides
+    /// them via the [`DEPENDENCY_VERSIONS_ENV`] environment value, which is
+    /// tracked by the driver. The versions are unknown, if the driver is used
+    /// without `cargo-marker`, or for crates which aren't managed by Cargo,
+    /// like the ones from the sysroot.
+    fn dependency_versions(&self) -> FxHashMap<String, String> {
+        let Some(manifest) = self.manifest_path() else {
+            return FxHashMap::default();
+        };
+        std::env::var(DEPENDENCY_VERSIONS_ENV)
+            .ok()
+            .and_then(|env| serde_json::from_str::<FxHashMap<String, FxHashMap<String, String>>>(&env).ok())
+            .and_then(|mut packages| packages.remove(manifest))
+            .unwrap_or_default()
+    }
+}
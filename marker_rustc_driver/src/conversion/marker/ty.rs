@@ -1,83 +1,111 @@
 use marker_api::ast::{
+    generic::SemGenericArgs,
     ty::{
-        CommonSynTyData, NumKind, SemAdtTy, SemAliasTy, SemArrayTy, SemBoolTy, SemClosureTy, SemFnPtrTy, SemFnTy,
-        SemGenericTy, SemNeverTy, SemNumTy, SemRawPtrTy, SemRefTy, SemSliceTy, SemTextTy, SemTraitObjTy, SemTupleTy,
-        SemTyKind, SemUnstableTy, SynArrayTy, SynBoolTy, SynFnPtrTy, SynImplTraitTy, SynInferredTy, SynNeverTy,
-        SynNumTy, SynPathTy, SynRawPtrTy, SynRefTy, SynSliceTy, SynTextTy, SynTraitObjTy, SynTupleTy, SynTyKind,
-        TextKind,
+        CommonSemTyData, CommonSynTyData, CoroutineKind, Layout, NumKind, SemAdtDef, SemAdtKind, SemAdtTy, SemAliasTy,
+        SemArrayTy, SemBoolTy, SemClosureTy, SemCoroutineTy, SemFieldDef, SemFnParam, SemFnPtrTy, SemFnSig, SemFnTy,
+        SemForeignTy, SemGenericTy, SemNeverTy, SemNumTy, SemOpaqueTy, SemRawPtrTy, SemRefTy, SemSliceTy, SemTextTy,
+        SemTraitObjTy, SemTupleTy, SemTyKind, SemUnstableTy, SemVariantDef, SemVariantKind, SynArrayTy, SynBoolTy,
+        SynFnPtrTy, SynImplTraitTy, SynInferredTy, SynNeverTy, SynNumTy, SynPathTy, SynRawPtrTy, SynRefTy, SynSliceTy,
+        SynTextTy, SynTraitObjTy, SynTupleTy, SynTyKind, TextKind,
     },
-    CommonCallableData, ConstValue, Constness, Parameter, Syncness,
+    CommonCallableData, Constness, DriverTyId, Parameter, Syncness,
 };
 use rustc_hir as hir;
 use rustc_middle as mid;
 
-use super::MarkerConverterInner;
+use super::{MarkerConverterInner, RustcItemArgs, RustcTyInfo};
 
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
     #[must_use]
     pub fn to_sem_ty(&self, rustc_ty: mid::ty::Ty<'tcx>) -> SemTyKind<'ast> {
-        // Semantic types could be cached, the question is if they should and at
-        // which level.
+        let key = (rustc_ty, self.generics_owner());
+        if let Some(sem_ty) = self.sem_tys.borrow().get(&key) {
+            return *sem_ty;
+        }
+
+        let driver_id = {
+            let mut rustc_tys = self.rustc_tys.borrow_mut();
+            rustc_tys.push(key);
+            DriverTyId::new(rustc_tys.len() as u64 - 1)
+        };
+        let sem_ty = self.to_sem_ty_kind(rustc_ty, CommonSemTyData::new_semantic(driver_id));
+        self.sem_tys.borrow_mut().insert(key, sem_ty);
+        sem_ty
+    }
+
+    /// Returns the rustc type, which the given semantic type was converted from,
+    /// together with the item, which defines the generic parameters used by it.
+    #[must_use]
+    pub fn to_rustc_ty(&self, sem_ty: SemTyKind<'ast>) -> Option<RustcTyInfo<mid::ty::Ty<'tcx>>> {
+        self.to_rustc_ty_from_id(sem_ty.driver_id())
+    }
+
+    /// Returns the rustc type, which was assigned the given [`DriverTyId`].
+    /// See [`MarkerConverterInner::to_rustc_ty`].
+    #[must_use]
+    pub fn to_rustc_ty_from_id(&self, id: DriverTyId) -> Option<RustcTyInfo<mid::ty::Ty<'tcx>>> {
+        let index = usize::try_from(id.data()).ok()?;
+        self.rustc_tys.borrow().get(index).copied()
+    }
+
+    /// Returns the rustc generic args, which the given semantic generic args
+    /// were converted from, together with the item, which the args are for, and
+    /// the item, which defines the generic parameters used by them. The rustc
+    /// args of trait bounds include the `Self` type.
+    #[must_use]
+    pub fn to_rustc_generic_args(&self, args: &SemGenericArgs<'ast>) -> Option<RustcItemArgs<'tcx>> {
+        let index = usize::try_from(args.driver_id().data()).ok()?;
+        self.rustc_generic_args.borrow().get(index).copied()
+    }
+
+    #[must_use]
+    fn to_sem_ty_kind(&self, rustc_ty: mid::ty::Ty<'tcx>, data: CommonSemTyData<'ast>) -> SemTyKind<'ast> {
         match &rustc_ty.kind() {
-            mid::ty::TyKind::Bool => SemTyKind::Bool(self.alloc(SemBoolTy::new())),
-            mid::ty::TyKind::Char => SemTyKind::Text(self.alloc(SemTextTy::new(TextKind::Char))),
-            mid::ty::TyKind::Int(int_ty) => {
-                let num_ty = match int_ty {
-                    mid::ty::IntTy::Isize => NumKind::Isize,
-                    mid::ty::IntTy::I8 => NumKind::I8,
-                    mid::ty::IntTy::I16 => NumKind::I16,
-                    mid::ty::IntTy::I32 => NumKind::I32,
-                    mid::ty::IntTy::I64 => NumKind::I64,
-                    mid::ty::IntTy::I128 => NumKind::I128,
-                };
-                SemTyKind::Num(self.alloc(SemNumTy::new(num_ty)))
+            mid::ty::TyKind::Bool => SemTyKind::Bool(self.alloc(SemBoolTy::new(data))),
+            mid::ty::TyKind::Char => SemTyKind::Text(self.alloc(SemTextTy::new(data, TextKind::Char))),
+            mid::ty::TyKind::Int(_) | mid::ty::TyKind::Uint(_) | mid::ty::TyKind::Float(_) => {
+                let num_ty = self
+                    .to_num_kind(rustc_ty)
+                    .expect("all integer and float types have a `NumKind`");
+                SemTyKind::Num(self.alloc(SemNumTy::new(data, num_ty)))
             },
-            mid::ty::TyKind::Uint(uint_ty) => {
-                let num_ty = match uint_ty {
-                    mid::ty::UintTy::Usize => NumKind::Usize,
-                    mid::ty::UintTy::U8 => NumKind::U8,
-                    mid::ty::UintTy::U16 => NumKind::U16,
-                    mid::ty::UintTy::U32 => NumKind::U32,
-                    mid::ty::UintTy::U64 => NumKind::U64,
-                    mid::ty::UintTy::U128 => NumKind::U128,
-                };
-                SemTyKind::Num(self.alloc(SemNumTy::new(num_ty)))
-            },
-            mid::ty::TyKind::Float(float_ty) => {
-                let num_ty = match float_ty {
-                    mid::ty::FloatTy::F32 => NumKind::F32,
-                    mid::ty::FloatTy::F64 => NumKind::F64,
-                };
-                SemTyKind::Num(self.alloc(SemNumTy::new(num_ty)))
-            },
-            mid::ty::TyKind::Str => SemTyKind::Text(self.alloc(SemTextTy::new(TextKind::Str))),
+            mid::ty::TyKind::Str => SemTyKind::Text(self.alloc(SemTextTy::new(data, TextKind::Str))),
             mid::ty::TyKind::Adt(def, generics) => SemTyKind::Adt(self.alloc(SemAdtTy::new(
+                data,
                 self.to_ty_def_id(def.did()),
-                self.to_sem_generic_args(generics),
+                self.to_sem_generic_args(def.did(), generics),
             ))),
-            mid::ty::TyKind::Foreign(_) => {
-                todo!("foreign type are currently sadly not supported. See rust-marker/marker#182")
+            mid::ty::TyKind::Foreign(def_id) => {
+                SemTyKind::Foreign(self.alloc(SemForeignTy::new(data, self.to_item_id(*def_id))))
             },
-            mid::ty::TyKind::Array(inner, _len) => {
-                SemTyKind::Array(self.alloc(SemArrayTy::new(self.to_sem_ty(*inner), ConstValue::new())))
+            mid::ty::TyKind::Array(inner, len) => SemTyKind::Array(self.alloc(SemArrayTy::new(
+                data,
+                self.to_sem_ty(*inner),
+                self.to_const_value_from_ty_const(*len),
+            ))),
+            mid::ty::TyKind::Slice(inner) => {
+                SemTyKind::Slice(self.alloc(SemSliceTy::new(data, self.to_sem_ty(*inner))))
             },
-            mid::ty::TyKind::Slice(inner) => SemTyKind::Slice(self.alloc(SemSliceTy::new(self.to_sem_ty(*inner)))),
             mid::ty::TyKind::Tuple(ty_lst) => SemTyKind::Tuple(self.alloc(SemTupleTy::new(
+                data,
                 self.alloc_slice(ty_lst.iter().map(|ty| self.to_sem_ty(ty))),
             ))),
             mid::ty::TyKind::RawPtr(ty_and_mut) => SemTyKind::RawPtr(self.alloc(SemRawPtrTy::new(
+                data,
                 self.to_mutability(ty_and_mut.mutbl),
                 self.to_sem_ty(ty_and_mut.ty),
             ))),
             mid::ty::TyKind::Ref(_lifetime, inner, muta) => {
-                SemTyKind::Ref(self.alloc(SemRefTy::new(self.to_mutability(*muta), self.to_sem_ty(*inner))))
+                SemTyKind::Ref(self.alloc(SemRefTy::new(data, self.to_mutability(*muta), self.to_sem_ty(*inner))))
             },
             mid::ty::TyKind::FnDef(fn_id, generic_args) => SemTyKind::FnTy(self.alloc(SemFnTy::new(
+                data,
                 self.to_item_id(*fn_id),
-                self.to_sem_generic_args(generic_args),
+                self.to_sem_generic_args(*fn_id, generic_args),
             ))),
             mid::ty::TyKind::FnPtr(fn_info) => SemTyKind::FnPtr(
                 self.alloc(SemFnPtrTy::new(
+                    data,
                     self.to_safety(fn_info.unsafety()),
                     self.to_abi(fn_info.abi()),
                     self.alloc_slice(
@@ -94,33 +122,38 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 if !matches!(kind, mid::ty::DynKind::Dyn) {
                     unimplemented!("the docs are not totally clear, when `DynStar` is used, her it is: {rustc_ty:#?}")
                 }
-                SemTyKind::TraitObj(self.alloc(SemTraitObjTy::new(self.to_sem_trait_bounds(binders))))
+                SemTyKind::TraitObj(self.alloc(SemTraitObjTy::new(data, self.to_sem_trait_bounds(binders, rustc_ty))))
             },
             mid::ty::TyKind::Closure(id, generics) => SemTyKind::ClosureTy(self.alloc(SemClosureTy::new(
+                data,
                 self.to_ty_def_id(*id),
-                self.to_sem_generic_args(generics),
+                self.to_sem_generic_args(*id, generics),
             ))),
-            mid::ty::TyKind::Generator(_, _, _)
-            | mid::ty::TyKind::GeneratorWitness(_)
-            | mid::ty::TyKind::GeneratorWitnessMIR(_, _) => SemTyKind::Unstable(self.alloc(SemUnstableTy::new())),
-            mid::ty::TyKind::Never => SemTyKind::Never(self.alloc(SemNeverTy::new())),
-            mid::ty::TyKind::Alias(_, info) => {
-                SemTyKind::Alias(self.alloc(SemAliasTy::new(self.to_item_id(info.def_id))))
+            mid::ty::TyKind::Generator(id, args, _) => {
+                SemTyKind::Coroutine(self.alloc(self.to_sem_coroutine_ty(data, *id, args)))
+            },
+            // Witness types are only used inside the generic args of generators. Marker
+            // exposes them via `SemCoroutineTy::interior_tys`, see `to_sem_coroutine_interior_tys`.
+            mid::ty::TyKind::GeneratorWitness(_) | mid::ty::TyKind::GeneratorWitnessMIR(_, _) => {
+                SemTyKind::Unstable(self.alloc(SemUnstableTy::new(data)))
+            },
+            mid::ty::TyKind::Never => SemTyKind::Never(self.alloc(SemNeverTy::new(data))),
+            mid::ty::TyKind::Alias(mid::ty::AliasKind::Opaque, info) => {
+                SemTyKind::Opaque(self.alloc(SemOpaqueTy::new(
+                    data,
+                    self.to_item_id(info.def_id),
+                    self.to_sem_generic_args(info.def_id, info.args),
+                )))
+            },
+            mid::ty::TyKind::Alias(kind, info) => {
+                SemTyKind::Alias(self.alloc(self.to_sem_alias_ty(data, *kind, *info)))
             },
             mid::ty::TyKind::Param(param) => {
-                let body_id = self
-                    .rustc_body
-                    .borrow()
-                    .expect("semantic `TyKind::Param` is only valid inside bodies");
-                // This is a local id, this makes sense, since rustc only accesses
-                // expressions and therefore semantic types of the current crate.
-                // This should be fine...
-                let owner = self.rustc_cx.hir().body_owner_def_id(body_id);
-                let generic_info = self
-                    .rustc_cx
-                    .generics_of(owner.to_def_id())
-                    .type_param(param, self.rustc_cx);
-                SemTyKind::Generic(self.alloc(SemGenericTy::new(self.to_generic_id(generic_info.def_id))))
+                let owner = self
+                    .generics_owner()
+                    .expect("semantic `TyKind::Param` is only valid inside bodies or definitions");
+                let generic_info = self.rustc_cx.generics_of(owner).type_param(param, self.rustc_cx);
+                SemTyKind::Generic(self.alloc(SemGenericTy::new(data, self.to_generic_id(generic_info.def_id))))
             },
             mid::ty::TyKind::Bound(_, _) => {
                 unreachable!("used by rustc for higher ranked types, which are not represented in marker")
@@ -131,6 +164,229 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             mid::ty::TyKind::Error(_) => unreachable!("would have triggered a rustc error"),
         }
     }
+
+    /// Projections, like `<I as Iterator>::Item`, are split into the `Self`
+    /// type, the args of the trait and the own args of the associated type.
+    #[must_use]
+    fn to_sem_alias_ty(
+        &self,
+        data: CommonSemTyData<'ast>,
+        kind: mid::ty::AliasKind,
+        info: mid::ty::AliasTy<'tcx>,
+    ) -> SemAliasTy<'ast> {
+        let alias_item = self.to_item_id(info.def_id);
+        if let mid::ty::AliasKind::Projection = kind {
+            let (trait_ref, own_args) = info.trait_ref_and_own_args(self.rustc_cx);
+            SemAliasTy::new(
+                data,
+                alias_item,
+                Some(self.to_sem_ty(trait_ref.self_ty())),
+                self.to_sem_trait_generic_args(trait_ref),
+                self.to_sem_own_generic_args(info.def_id, info.args, own_args),
+            )
+        } else {
+            SemAliasTy::new(
+                data,
+                alias_item,
+                None,
+                self.to_sem_own_generic_args(info.def_id, info.args, &[]),
+                self.to_sem_generic_args(info.def_id, info.args),
+            )
+        }
+    }
+
+    #[must_use]
+    fn to_sem_coroutine_ty(
+        &self,
+        data: CommonSemTyData<'ast>,
+        id: hir::def_id::DefId,
+        args: mid::ty::GenericArgsRef<'tcx>,
+    ) -> SemCoroutineTy<'ast> {
+        let tcx = self.rustc_cx;
+        let generator = args.as_generator();
+        let kind = match tcx.generator_kind(id) {
+            Some(hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Fn)) => CoroutineKind::AsyncFn,
+            Some(hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Block)) => CoroutineKind::AsyncBlock,
+            Some(hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Closure)) => CoroutineKind::AsyncClosure,
+            Some(hir::GeneratorKind::Gen) | None => CoroutineKind::Generator,
+        };
+        let body = id
+            .as_local()
+            .and_then(|local_id| tcx.hir().maybe_body_owned_by(local_id))
+            .map(|body| self.to_body_id(body));
+
+        SemCoroutineTy::new(
+            data,
+            self.to_ty_def_id(id),
+            kind,
+            body,
+            // The parent args belong to the item, which contains the coroutine
+            self.to_sem_generic_args(tcx.typeck_root_def_id(id), tcx.mk_args(generator.parent_args())),
+            self.to_sem_ty(generator.yield_ty()),
+            self.to_sem_ty(generator.return_ty()),
+        )
+    }
+
+    /// Converts the types stored by a coroutine across `.await` and `yield`
+    /// points. These are computed on demand, since they require the analysis
+    /// of the coroutine body.
+    #[must_use]
+    pub fn to_sem_coroutine_interior_tys(&self, args: mid::ty::GenericArgsRef<'tcx>) -> &'ast [SemTyKind<'ast>] {
+        let tcx = self.rustc_cx;
+        match args.as_generator().witness().kind() {
+            mid::ty::TyKind::GeneratorWitness(tys) => {
+                self.alloc_slice(tys.skip_binder().iter().map(|ty| self.to_sem_ty(ty)))
+            },
+            // This representation is used with `-Zdrop-tracking-mir`
+            mid::ty::TyKind::GeneratorWitnessMIR(witness_id, witness_args) => {
+                let tys: Vec<_> = tcx
+                    .generator_hidden_types(*witness_id)
+                    .map(|ty| self.to_sem_ty(ty.instantiate(tcx, witness_args)))
+                    .collect();
+                self.alloc_slice(tys)
+            },
+            _ => &[],
+        }
+    }
+
+    #[must_use]
+    pub fn to_layout(&self, layout: mid::ty::layout::TyAndLayout<'tcx>) -> &'ast Layout<'ast> {
+        let variant_sizes: &[u64] = match layout.ty.kind() {
+            mid::ty::TyKind::Adt(adt, _) if adt.is_enum() => {
+                let layout_cx = mid::ty::layout::LayoutCx {
+                    tcx: self.rustc_cx,
+                    param_env: mid::ty::ParamEnv::reveal_all(),
+                };
+                self.alloc_slice(
+                    adt.variants()
+                        .indices()
+                        .map(|index| layout.for_variant(&layout_cx, index).size.bytes()),
+                )
+            },
+            _ => &[],
+        };
+
+        self.alloc(Layout::new(
+            layout.size.bytes(),
+            layout.align.abi.bytes(),
+            layout.abi.is_uninhabited(),
+            layout.largest_niche.is_some(),
+            variant_sizes,
+        ))
+    }
+
+    /// The generic owner has to be set to the [`DefId`](hir::def_id::DefId) of
+    /// the ADT, to convert the types of the fields.
+    #[must_use]
+    pub fn to_sem_adt_def(&self, adt: mid::ty::AdtDef<'tcx>) -> &'ast SemAdtDef<'ast> {
+        let kind = match adt.adt_kind() {
+            mid::ty::AdtKind::Struct => SemAdtKind::Struct,
+            mid::ty::AdtKind::Union => SemAdtKind::Union,
+            mid::ty::AdtKind::Enum => SemAdtKind::Enum,
+        };
+        let variants = self.alloc_slice(adt.variants().iter().map(|variant| {
+            let kind = match variant.ctor_kind() {
+                None => SemVariantKind::Field,
+                Some(hir::def::CtorKind::Fn) => SemVariantKind::Tuple,
+                Some(hir::def::CtorKind::Const) => SemVariantKind::Unit,
+            };
+            let fields = self.alloc_slice(variant.fields.iter().map(|field| {
+                let vis_scope = match self.rustc_cx.visibility(field.did) {
+                    mid::ty::Visibility::Public => None,
+                    mid::ty::Visibility::Restricted(module) => Some(self.to_item_id(module)),
+                };
+                SemFieldDef::new(
+                    self.to_symbol_id(field.name),
+                    self.to_sem_ty(self.rustc_cx.type_of(field.did).instantiate_identity()),
+                    vis_scope,
+                )
+            }));
+            SemVariantDef::new(
+                self.to_variant_id(variant.def_id),
+                self.to_symbol_id(variant.name),
+                kind,
+                fields,
+                variant.is_field_list_non_exhaustive(),
+            )
+        }));
+        // `#[non_exhaustive]` on structs and unions is stored on the variant
+        let is_non_exhaustive = adt.is_variant_list_non_exhaustive()
+            || (!adt.is_enum() && adt.non_enum_variant().is_field_list_non_exhaustive());
+
+        self.alloc(SemAdtDef::new(
+            self.to_ty_def_id(adt.did()),
+            self.to_item_id(adt.did()),
+            kind,
+            variants,
+            is_non_exhaustive,
+        ))
+    }
+
+    /// The generic owner has to be set to the [`DefId`](hir::def_id::DefId) of
+    /// the function, to convert the types of the signature.
+    #[must_use]
+    pub fn to_sem_fn_sig(&self, fn_id: hir::def_id::DefId) -> &'ast SemFnSig<'ast> {
+        let tcx = self.rustc_cx;
+        let sig = tcx.fn_sig(fn_id).instantiate_identity().skip_binder();
+        // Tuple constructors have no parameter names and are always const
+        let is_ctor = matches!(tcx.def_kind(fn_id), hir::def::DefKind::Ctor(..));
+        let (names, constness, syncness) = if is_ctor {
+            (&[][..], Constness::Const, Syncness::Sync)
+        } else {
+            (
+                tcx.fn_arg_names(fn_id),
+                self.to_constness(tcx.constness(fn_id)),
+                self.to_syncness(tcx.asyncness(fn_id)),
+            )
+        };
+        let params = self.alloc_slice(sig.inputs().iter().enumerate().map(|(index, ty)| {
+            let ident = names
+                .get(index)
+                .filter(|ident| ident.name != rustc_span::symbol::kw::Empty)
+                .map(|ident| self.to_symbol_id(ident.name));
+            SemFnParam::new(ident, self.to_sem_ty(*ty))
+        }));
+
+        self.alloc(SemFnSig::new(
+            self.to_item_id(fn_id),
+            self.to_safety(sig.unsafety),
+            self.to_abi(sig.abi),
+            constness,
+            syncness,
+            params,
+            self.to_sem_ty(sig.output()),
+            sig.c_variadic,
+        ))
+    }
+
+    /// Returns the [`NumKind`] of integer and float types.
+    #[must_use]
+    pub fn to_num_kind(&self, rustc_ty: mid::ty::Ty<'tcx>) -> Option<NumKind> {
+        let kind = match rustc_ty.kind() {
+            mid::ty::TyKind::Int(int_ty) => match int_ty {
+                mid::ty::IntTy::Isize => NumKind::Isize,
+                mid::ty::IntTy::I8 => NumKind::I8,
+                mid::ty::IntTy::I16 => NumKind::I16,
+                mid::ty::IntTy::I32 => NumKind::I32,
+                mid::ty::IntTy::I64 => NumKind::I64,
+                mid::ty::IntTy::I128 => NumKind::I128,
+            },
+            mid::ty::TyKind::Uint(uint_ty) => match uint_ty {
+                mid::ty::UintTy::Usize => NumKind::Usize,
+                mid::ty::UintTy::U8 => NumKind::U8,
+                mid::ty::UintTy::U16 => NumKind::U16,
+                mid::ty::UintTy::U32 => NumKind::U32,
+                mid::ty::UintTy::U64 => NumKind::U64,
+                mid::ty::UintTy::U128 => NumKind::U128,
+            },
+            mid::ty::TyKind::Float(float_ty) => match float_ty {
+                mid::ty::FloatTy::F32 => NumKind::F32,
+                mid::ty::FloatTy::F64 => NumKind::F64,
+            },
+            _ => return None,
+        };
+        Some(kind)
+    }
 }
 
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
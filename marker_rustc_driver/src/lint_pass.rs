@@ -1,7 +1,7 @@
 use std::cell::OnceCell;
 
 use marker_adapter::{Adapter, LintCrateInfo};
-use marker_api::lint::Lint;
+use marker_api::LintPassInfo;
 
 use crate::context::{storage::Storage, RustcContext};
 
@@ -31,17 +31,8 @@ impl RustcLintPass {
         })
     }
 
-    pub fn marker_lints() -> Vec<&'static Lint> {
-        ADAPTER.with(|adapter| {
-            adapter
-                .get()
-                .unwrap()
-                .lint_pass_infos()
-                .iter()
-                .flat_map(marker_api::LintPassInfo::lints)
-                .copied()
-                .collect()
-        })
+    pub fn marker_lint_pass_infos() -> Vec<LintPassInfo> {
+        ADAPTER.with(|adapter| adapter.get().unwrap().lint_pass_infos())
     }
 }
 
@@ -79,4 +70,8 @@ fn process_crate_lifetime<'ast, 'tcx: 'ast>(
         .to_crate(rustc_hir::def_id::LOCAL_CRATE, driver_cx.rustc_cx.hir().root_module());
 
     adapter.process_krate(driver_cx.ast_cx(), krate);
+
+    driver_cx.check_lint_expectations();
+
+    crate::suppression_report::write_report_if_requested(driver_cx);
 }
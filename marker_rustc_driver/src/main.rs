@@ -23,6 +23,7 @@ extern crate rustc_hash;
 extern crate rustc_hir;
 extern crate rustc_hir_analysis;
 extern crate rustc_interface;
+extern crate rustc_lexer;
 extern crate rustc_lint;
 extern crate rustc_lint_defs;
 extern crate rustc_middle;
@@ -33,13 +34,14 @@ extern crate rustc_target;
 pub mod context;
 pub mod conversion;
 mod lint_pass;
+mod suppression_report;
 
 use std::env;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
-use marker_adapter::{LintCrateInfo, LINT_CRATES_ENV};
+use marker_adapter::{LintCrateInfo, DEPENDENCY_VERSIONS_ENV, LINT_CRATES_ENV, SUPPRESSION_REPORT_ENV};
 use marker_error::Context;
 use rustc_session::config::ErrorOutputType;
 use rustc_session::EarlyErrorHandler;
@@ -86,12 +88,27 @@ impl rustc_driver::Callbacks for MarkerCallback {
             lint_pass::RustcLintPass::init_adapter(&lint_crates).unwrap();
             // Register lints from lint crates. This is required to have rustc track
             // the lint level correctly.
-            let lints: Vec<_> = lint_pass::RustcLintPass::marker_lints()
-                .into_iter()
+            let lint_pass_infos = lint_pass::RustcLintPass::marker_lint_pass_infos();
+            let lints: Vec<_> = lint_pass_infos
+                .iter()
+                .flat_map(marker_api::LintPassInfo::lints)
+                .copied()
                 .map(RustcConverter::static_to_lint)
                 .collect();
             lint_store.register_lints(&lints);
 
+            // Renamed and removed lints are registered afterwards, as rustc requires
+            // the new lints to be registered already.
+            for info in &lint_pass_infos {
+                for renamed in info.renamed_lints() {
+                    let new_lint = RustcConverter::static_to_lint(renamed.new_lint());
+                    lint_store.register_renamed(&renamed.old_name().to_lowercase(), &new_lint.name_lower());
+                }
+                for removed in info.removed_lints() {
+                    lint_store.register_removed(&removed.name().to_lowercase(), removed.reason());
+                }
+            }
+
             lint_store.register_late_pass(|_| Box::new(lint_pass::RustcLintPass));
         }));
     }
@@ -316,7 +333,7 @@ fn try_main() -> Result<(), MainError> {
     let in_primary_package = env::var("CARGO_PRIMARY_PACKAGE").is_ok();
 
     let enable_marker = !cap_lints_allow && (!no_deps || in_primary_package);
-    let env_vars = vec![(LINT_CRATES_ENV, std::env::var(LINT_CRATES_ENV).unwrap_or_default())];
+    let mut env_vars = vec![(LINT_CRATES_ENV, std::env::var(LINT_CRATES_ENV).unwrap_or_default())];
     if !enable_marker {
         rustc_driver::RunCompiler::new(&orig_args, &mut DefaultCallbacks { env_vars }).run()?;
         return Ok(());
@@ -336,6 +353,15 @@ fn try_main() -> Result<(), MainError> {
             .map(|krate| format!(r#"--cfg=marker="{}""#, krate.name)),
     );
 
+    // Tracking the report directory forces a new check of cached crates, which
+    // is required for a complete suppression report.
+    if let Ok(report_dir) = std::env::var(SUPPRESSION_REPORT_ENV) {
+        env_vars.push((SUPPRESSION_REPORT_ENV, report_dir));
+    }
+    if let Ok(versions) = std::env::var(DEPENDENCY_VERSIONS_ENV) {
+        env_vars.push((DEPENDENCY_VERSIONS_ENV, versions));
+    }
+
     let mut callback = MarkerCallback { env_vars, lint_crates };
     rustc_driver::RunCompiler::new(&orig_args, &mut callback).run()?;
 
@@ -0,0 +1,144 @@
+//! This module creates the suppression report used by `cargo marker allows`.
+//! The report lists every `allow` and `expect` attribute which targets a lint
+//! in the `marker::` namespace.
+//!
+//! Every linted crate writes its own JSON file into the directory specified by
+//! [`SUPPRESSION_REPORT_ENV`]. `cargo-marker` collects and displays these files
+//! once `cargo check` has finished.
+
+use std::path::Path;
+
+use marker_adapter::SUPPRESSION_REPORT_ENV;
+use rustc_hir as hir;
+use rustc_hir::def_id::{LocalDefId, CRATE_DEF_ID, LOCAL_CRATE};
+use rustc_span::sym;
+use serde::Serialize;
+
+use crate::context::RustcContext;
+
+/// A single lint name, inside an `allow` or `expect` attribute.
+///
+/// The field names are part of the format shared with `cargo-marker`.
+#[derive(Debug, Serialize)]
+struct SuppressionEntry {
+    /// The full name of the suppressed lint, like `marker::lint_name`
+    lint: String,
+    /// The lint level set by the attribute, either `allow` or `expect`
+    level: &'static str,
+    /// A short description of the node, the attribute is attached to
+    item: String,
+    reason: Option<String>,
+    /// Indicates if the attribute suppressed a lint emission during this run
+    used: bool,
+    file: String,
+    line: usize,
+    column: usize,
+}
+
+/// This writes the suppression report of the current crate, if requested by
+/// the [`SUPPRESSION_REPORT_ENV`] environment value.
+pub fn write_report_if_requested(driver_cx: &RustcContext<'_, '_>) {
+    let Some(dir) = std::env::var_os(SUPPRESSION_REPORT_ENV) else {
+        return;
+    };
+
+    let tcx = driver_cx.rustc_cx;
+    let entries = collect_entries(driver_cx);
+    let file = Path::new(&dir).join(format!("{}-{}.json", tcx.crate_name(LOCAL_CRATE), std::process::id()));
+    let result = serde_json::to_string(&entries)
+        .map_err(std::io::Error::from)
+        .and_then(|json| std::fs::write(&file, json));
+    if let Err(err) = result {
+        tcx.sess.warn(format!(
+            "failed to write the suppression report to `{}`: {err}",
+            file.display()
+        ));
+    }
+}
+
+fn collect_entries(driver_cx: &RustcContext<'_, '_>) -> Vec<SuppressionEntry> {
+    let tcx = driver_cx.rustc_cx;
+    let source_map = tcx.sess.source_map();
+    let marker_tool = rustc_span::Symbol::intern("marker");
+
+    let mut entries = vec![];
+    let owners = std::iter::once(hir::CRATE_OWNER_ID).chain(tcx.hir_crate_items(()).owners());
+    for owner in owners {
+        for &(local_id, attrs) in tcx.hir_attrs(owner).map.iter() {
+            let hir_id = hir::HirId { owner, local_id };
+            for attr in attrs {
+                let level = if attr.has_name(sym::allow) {
+                    "allow"
+                } else if attr.has_name(sym::expect) {
+                    "expect"
+                } else {
+                    continue;
+                };
+                let Some(metas) = attr.meta_item_list() else {
+                    continue;
+                };
+
+                let reason = metas
+                    .iter()
+                    .filter_map(rustc_ast::NestedMetaItem::meta_item)
+                    .find(|meta| meta.has_name(sym::reason))
+                    .and_then(rustc_ast::MetaItem::value_str)
+                    .map(|reason| reason.to_string());
+
+                for nested in &metas {
+                    let Some(meta) = nested.meta_item() else {
+                        continue;
+                    };
+                    let [tool, name] = &*meta.path.segments else {
+                        continue;
+                    };
+                    if !meta.is_word() || tool.ident.name != marker_tool {
+                        continue;
+                    }
+
+                    let span = nested.span();
+                    let loc = source_map.lookup_char_pos(span.lo());
+                    entries.push(SuppressionEntry {
+                        lint: format!("marker::{}", name.ident.name),
+                        level,
+                        item: describe_node(driver_cx, hir_id),
+                        reason: reason.clone(),
+                        used: driver_cx.is_lint_attr_used(span),
+                        file: loc.file.name.prefer_local().to_string(),
+                        line: loc.line,
+                        column: loc.col_display + 1,
+                    });
+                }
+            }
+        }
+    }
+    entries
+}
+
+fn describe_node(driver_cx: &RustcContext<'_, '_>, hir_id: hir::HirId) -> String {
+    let tcx = driver_cx.rustc_cx;
+    let describe_def = |def_id: LocalDefId| {
+        if def_id == CRATE_DEF_ID {
+            format!("crate `{}`", tcx.crate_name(LOCAL_CRATE))
+        } else {
+            format!("{} `{}`", tcx.def_descr(def_id.to_def_id()), tcx.def_path_str(def_id))
+        }
+    };
+
+    if hir_id.local_id == hir::ItemLocalId::from_u32(0) {
+        return describe_def(hir_id.owner.def_id);
+    }
+    let kind = match tcx.hir().get(hir_id) {
+        hir::Node::Field(field) => return describe_def(field.def_id),
+        hir::Node::Variant(variant) => return describe_def(variant.def_id),
+        hir::Node::GenericParam(param) => return describe_def(param.def_id),
+        hir::Node::Expr(_) => "expression",
+        hir::Node::Stmt(_) => "statement",
+        hir::Node::Local(_) => "local",
+        hir::Node::Param(_) => "parameter",
+        hir::Node::Arm(_) => "match arm",
+        hir::Node::ExprField(_) | hir::Node::PatField(_) => "field",
+        _ => "node",
+    };
+    format!("{kind} in {}", describe_def(hir_id.owner.def_id))
+}
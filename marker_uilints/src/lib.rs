@@ -1,16 +1,32 @@
 #![doc = include_str!("../README.md")]
 #![warn(clippy::pedantic)]
 
+use std::{collections::HashMap, ops::ControlFlow};
+
 use marker_api::{
     ast::{
-        item::{EnumVariant, Field, StaticItem},
-        ty::SemTyKind,
-        Span,
+        expr::BinaryOpKind,
+        generic::{
+            Lifetime, PredicateSource, SemGenericArgKind, SemGenericArgs, SemPredicate, SemPredicateKind,
+            SynGenericArgKind, SynGenericParamKind,
+        },
+        item::{EnumVariant, Field, FnItem, StaticItem, Visibility},
+        stmt::LetStmt,
+        ty::{SemAdtDef, SemTyKind},
+        AstPathTarget, Comment, Crate, ExprId, ItemId, Span,
     },
     diagnostic::{Applicability, EmissionNode},
     prelude::*,
     LintPass, LintPassInfo, LintPassInfoBuilder,
 };
+use marker_utils::{
+    cfg::{BasicBlock, Cfg, CfgNode},
+    dataflow::{bound_vars, solve, DefSite, Liveness, MaybeUninit, ReachingDefinitions},
+    markdown::Markdown,
+    match_ast,
+    sugg::Sugg,
+    visitor::{traverse_expr, Visitor},
+};
 
 #[derive(Default)]
 struct TestLintPass {}
@@ -66,7 +82,16 @@ fn emit_item_with_test_name_lint<'ast>(
 
 impl LintPass for TestLintPass {
     fn info(&self) -> LintPassInfo {
-        LintPassInfoBuilder::new(Box::new([TEST_LINT, ITEM_WITH_TEST_NAME, PRINT_EVERY_EXPR])).build()
+        LintPassInfoBuilder::new(Box::new([TEST_LINT, ITEM_WITH_TEST_NAME, PRINT_EVERY_EXPR]))
+            .rename_lint("marker::old_item_with_test_name", ITEM_WITH_TEST_NAME)
+            .remove_lint("marker::removed_test_lint", "it was only used for testing")
+            .build()
+    }
+
+    fn check_crate<'ast>(&mut self, _cx: &'ast AstContext<'ast>, krate: &'ast Crate<'ast>) {
+        if krate.name() == "print_crate_info" {
+            print_crate_info(krate);
+        }
     }
 
     fn check_item<'ast>(&mut self, cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
@@ -74,8 +99,14 @@ impl LintPass for TestLintPass {
             if let Some(ident) = item.ident() {
                 if ident.name() == "test_ty_id_resolution_trigger" {
                     test_ty_id_resolution(cx);
+                } else if ident.name() == "test_item_path_resolution_trigger" {
+                    test_item_path_resolution(cx);
                 } else if ident.name() == "uilints_please_ice_on_this" {
                     panic!("free ice cream for everyone!!!");
+                } else if ident.name().starts_with("print_cfg") {
+                    print_cfg(cx, item);
+                } else if ident.name().starts_with("print_dataflow") {
+                    print_dataflow(cx, item);
                 }
             }
         }
@@ -84,6 +115,18 @@ impl LintPass for TestLintPass {
             check_static_item(cx, item);
         }
 
+        if let ItemKind::Use(use_item) = item {
+            if use_item.ident().is_some_and(|ident| ident.name().starts_with("_use")) {
+                cx.emit_lint(TEST_LINT, item.id(), "print use resolution", item.span(), |diag| {
+                    for path in RESOLVED_PATHS {
+                        if marker_utils::paths::is_use_of(cx, use_item, path) {
+                            diag.note(format!("Imports `{path}`"));
+                        }
+                    }
+                });
+            }
+        }
+
         if matches!(
             item.ident().map(marker_api::ast::Ident::name),
             Some(name) if name.starts_with("FindMe") || name.starts_with("FIND_ME") || name.starts_with("find_me")
@@ -138,9 +181,30 @@ impl LintPass for TestLintPass {
                 );
             }
         }
+
+        if matches!(
+            item.ident().map(marker_api::ast::Ident::name),
+            Some(name) if name.starts_with("print_predicates") || name.starts_with("PrintPredicates")
+        ) {
+            print_predicates(cx, item);
+        }
+
+        print_item_path(cx, item);
+        print_item_owner(cx, item);
+        print_item_docs(cx, item);
+        print_item_comments(cx, item);
+        if matches!(
+            item.ident().map(marker_api::ast::Ident::name),
+            Some(name) if name.starts_with("print_vis") || name.starts_with("PrintVis")
+        ) {
+            print_visibility(cx, item.id(), item.ident().unwrap().span(), item.visibility());
+        }
     }
 
     fn check_field<'ast>(&mut self, cx: &'ast AstContext<'ast>, field: &'ast Field<'ast>) {
+        if field.ident().starts_with("print_vis") {
+            print_visibility(cx, field.id(), field.span(), field.visibility());
+        }
         if field.ident().starts_with("find_me") {
             emit_item_with_test_name_lint(cx, field.id(), "a field", field.span());
         }
@@ -173,26 +237,77 @@ impl LintPass for TestLintPass {
                 cx.emit_lint(TEST_LINT, stmt.id(), "print type test", stmt.span(), |diag| {
                     diag.note(format!("{:#?}", expr.ty()));
                 });
-            } else if ident.name().starts_with("_check_path") {
-                cx.emit_lint(TEST_LINT, stmt.id(), "check type resolution", stmt.span(), |diag| {
+            } else if ident.name().starts_with("_const") {
+                print_const_value(cx, lets, expr);
+            } else if ident.name().starts_with("_comments") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print comments", stmt.span(), |diag| {
+                    diag.note(format!(
+                        "Before stmt: {:#?}",
+                        comment_texts(cx.comments_before(stmt.id()))
+                    ));
+                    diag.note(format!(
+                        "Before expr: {:#?}",
+                        comment_texts(cx.comments_before(expr.id()))
+                    ));
+                    diag.note(format!("In expr: {:#?}", comment_texts(cx.comments_in(expr.span()))));
+                });
+            } else if ident.name().starts_with("_adt_def") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print ADT definition", stmt.span(), |diag| {
                     let SemTyKind::Adt(adt) = expr.ty() else {
-                        unreachable!("how? Everything should be an ADT")
+                        unreachable!("the initializer should be an ADT")
                     };
-                    let path = "std::vec::Vec";
-                    let ids = cx.resolve_ty_ids(path);
-                    diag.note(format!("Is this a {:#?} -> {}", path, ids.contains(&adt.def_id())));
-
-                    let path = "std::string::String";
-                    let ids = cx.resolve_ty_ids(path);
-                    diag.note(format!("Is this a {:#?} -> {}", path, ids.contains(&adt.def_id())));
-
-                    let path = "std::option::Option";
-                    let ids = cx.resolve_ty_ids(path);
-                    diag.note(format!("Is this a {:#?} -> {}", path, ids.contains(&adt.def_id())));
-
-                    let path = "crate::TestType";
-                    let ids = cx.resolve_ty_ids(path);
-                    diag.note(format!("Is this a {:#?} -> {}", path, ids.contains(&adt.def_id())));
+                    print_adt_def(cx, diag, adt.def());
+                });
+            } else if ident.name().starts_with("_fn_sig") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print fn signature", stmt.span(), |diag| {
+                    print_fn_sig(cx, diag, expr.ty());
+                });
+            } else if ident.name().starts_with("_layout") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print layout", stmt.span(), |diag| {
+                    print_layout(cx, diag, expr.ty());
+                });
+            } else if ident.name().starts_with("_sem_ty") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print semantic type utils", stmt.span(), |diag| {
+                    print_ty_utils(diag, expr.ty());
+                });
+            } else if ident.name().starts_with("_match") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print AST matches", stmt.span(), |diag| {
+                    print_ast_matches(cx, diag, lets, expr);
+                });
+            } else if ident.name().starts_with("_jump_target") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print jump targets", stmt.span(), |diag| {
+                    print_jump_targets(cx, diag, expr);
+                });
+            } else if ident.name().starts_with("_resolve_call") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print call resolution", stmt.span(), |diag| {
+                    print_call_resolution(cx, diag, expr);
+                });
+            } else if ident.name().starts_with("_sugg") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print suggestions", stmt.span(), |diag| {
+                    print_suggs(diag, expr);
+                });
+            } else if ident.name().starts_with("_adjust") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print adjustments", stmt.span(), |diag| {
+                    print_adjustments(cx, diag, expr);
+                });
+            } else if ident.name().starts_with("_captures") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print closure captures", stmt.span(), |diag| {
+                    print_closure_captures(cx, diag, expr);
+                });
+            } else if ident.name().starts_with("_future") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print future type", stmt.span(), |diag| {
+                    print_future_ty(cx, diag, expr.ty());
+                });
+            } else if ident.name().starts_with("_desugar") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "print desugaring", stmt.span(), |diag| {
+                    print_desugaring(cx, diag, expr);
+                    if let Some(els) = lets.els() {
+                        diag.note(format!("Else desugaring: {:?}", cx.expr_desugaring(els.id())));
+                    }
+                });
+            } else if ident.name().starts_with("_check_path") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "check type resolution", stmt.span(), |diag| {
+                    print_ty_resolution(cx, diag, expr.ty());
                 });
             }
         }
@@ -230,6 +345,916 @@ fn check_static_item<'ast>(cx: &'ast AstContext<'ast>, item: &'ast StaticItem<'a
     }
 }
 
+fn print_visibility<'ast>(
+    cx: &'ast AstContext<'ast>,
+    node: impl Into<EmissionNode>,
+    span: &Span<'ast>,
+    vis: &Visibility<'ast>,
+) {
+    cx.emit_lint(TEST_LINT, node, "printing visibility", span, |diag| {
+        diag.note(format!("Kind: {:?}", vis.kind()));
+        diag.note(format!("Snippet: {:?}", vis.span().map(|span| span.snippet_or(".."))));
+        let scope = vis.scope().map(|id| cx.item_path(id).to_string());
+        diag.note(format!("Scope: {scope:?}"));
+        diag.note(format!(
+            "is_pub: {}, is_exported: {}, is_reachable: {}",
+            vis.is_pub(),
+            vis.is_exported(),
+            vis.is_reachable()
+        ));
+    });
+}
+
+fn print_item_path<'ast>(cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+    if matches!(
+        item.ident().map(marker_api::ast::Ident::name),
+        Some(name) if name.starts_with("print_path") || name.starts_with("PrintPath") || name.starts_with("PRINT_PATH")
+    ) {
+        cx.emit_lint(
+            TEST_LINT,
+            item.id(),
+            "printing item path",
+            item.ident().unwrap().span(),
+            |diag| {
+                let path = cx.item_path(item.id());
+                diag.note(format!("Path: {path}"));
+                diag.note(format!("Segments: {:?}", path.segments().collect::<Vec<_>>()));
+                diag.note(format!(
+                    "Resolves to item: {}",
+                    cx.resolve_item_ids(path.as_str()).contains(&item.id())
+                ));
+            },
+        );
+    }
+}
+
+fn print_item_owner<'ast>(cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+    if matches!(
+        item.ident().map(marker_api::ast::Ident::name),
+        Some(name) if name.starts_with("print_owner") || name.starts_with("PRINT_OWNER")
+    ) {
+        cx.emit_lint(
+            TEST_LINT,
+            item.id(),
+            "printing item owner",
+            item.ident().unwrap().span(),
+            |diag| {
+                let owner = item.owner_id().and_then(|id| cx.item(id)).map(|owner| match owner {
+                    ItemKind::Trait(_) => "trait",
+                    ItemKind::Impl(_) => "impl",
+                    ItemKind::ExternBlock(_) => "extern block",
+                    _ => "unexpected",
+                });
+                diag.note(format!("Owner: {owner:?}"));
+
+                let trait_item = cx.trait_item_for_impl_item(item.id()).and_then(|id| cx.item(id));
+                match trait_item {
+                    Some(ItemKind::Fn(trait_fn)) => {
+                        diag.note(format!(
+                            "Trait item: {:?} (provided: {})",
+                            trait_fn.ident().map(marker_api::ast::Ident::name),
+                            trait_fn.body_id().is_some()
+                        ));
+                    },
+                    Some(trait_item) => {
+                        diag.note(format!(
+                            "Trait item: {:?}",
+                            trait_item.ident().map(marker_api::ast::Ident::name)
+                        ));
+                    },
+                    None => diag.note("Trait item: None"),
+                }
+            },
+        );
+    }
+}
+
+fn print_item_docs<'ast>(cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+    if matches!(
+        item.ident().map(marker_api::ast::Ident::name),
+        Some(name) if name.starts_with("print_docs") || name.starts_with("PrintDocs")
+    ) {
+        cx.emit_lint(
+            TEST_LINT,
+            item.id(),
+            "printing item docs",
+            item.ident().unwrap().span(),
+            |diag| {
+                let docs = item.docs();
+                diag.note(format!("Docs: {:?}", docs.as_str()));
+                if let Some(first) = docs.lines().first() {
+                    diag.span_note("First line", first.span());
+                }
+
+                let markdown = Markdown::new(docs);
+                for heading in markdown.headings() {
+                    diag.note(format!("Heading: {} (level {})", heading.text(), heading.level()));
+                }
+                for block in markdown.code_blocks() {
+                    diag.note(format!(
+                        "Code block: lang={:?}, is_rust={}, code={:?}",
+                        block.lang(),
+                        block.is_rust(),
+                        block.code()
+                    ));
+                }
+                for link in markdown.links() {
+                    diag.note(format!(
+                        "Link: [{}] -> {:?} (intra-doc: {})",
+                        link.text(),
+                        link.target(),
+                        link.is_intra_doc()
+                    ));
+                }
+            },
+        );
+    }
+}
+
+fn print_item_comments<'ast>(cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+    if matches!(
+        item.ident().map(marker_api::ast::Ident::name),
+        Some(name) if name.starts_with("print_comments")
+    ) {
+        cx.emit_lint(
+            TEST_LINT,
+            item.id(),
+            "printing item comments",
+            item.ident().unwrap().span(),
+            |diag| {
+                let before = cx.comments_before(item.id());
+                diag.note(format!("Before: {:#?}", comment_texts(before)));
+                if let Some(first) = before.first() {
+                    diag.span_note("First comment", first.span());
+                }
+                diag.note(format!("Inside: {:#?}", comment_texts(cx.comments_in(item.span()))));
+                for comment in cx.comments_in_file(item.span()) {
+                    diag.note(format!(
+                        "In file: {:?}, doc: {:?}, content: {:?}",
+                        comment.kind(),
+                        comment.doc_style(),
+                        comment.content()
+                    ));
+                }
+            },
+        );
+    }
+}
+
+fn comment_texts<'ast>(comments: &[Comment<'ast>]) -> Vec<&'ast str> {
+    comments.iter().map(Comment::text).collect()
+}
+
+fn print_adt_def<'ast>(
+    cx: &'ast AstContext<'ast>,
+    diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>,
+    def: &SemAdtDef<'ast>,
+) {
+    diag.note(format!(
+        "{:?} `{}` (non-exhaustive: {})",
+        def.kind(),
+        cx.item_path(def.item_id()),
+        def.is_non_exhaustive()
+    ));
+    for variant in def.variants() {
+        diag.note(format!(
+            "Variant: {} {:?} (non-exhaustive: {})",
+            variant.ident(),
+            variant.kind(),
+            variant.is_non_exhaustive()
+        ));
+        for field in variant.fields() {
+            diag.note(format!(
+                "    Field: {}: {} (pub: {})",
+                field.ident(),
+                short_ty(cx, field.ty()),
+                field.is_pub()
+            ));
+        }
+    }
+}
+
+fn print_cfg<'ast>(cx: &'ast AstContext<'ast>, item: &'ast FnItem<'ast>) {
+    let Some(body_id) = item.body_id() else {
+        return;
+    };
+    let cfg = Cfg::new(cx.body(body_id));
+    cx.emit_lint(
+        TEST_LINT,
+        item.id(),
+        "printing cfg",
+        item.ident().unwrap().span(),
+        |diag| {
+            for block in cfg.blocks() {
+                if !cfg.is_reachable(block.id()) && !block.nodes().is_empty() {
+                    for node in block.nodes() {
+                        if let CfgNode::Expr(expr) = node {
+                            diag.note(format!("Unreachable: `{}`", expr.span().snippet().unwrap_or("<..>")));
+                        }
+                    }
+                }
+            }
+            diag.note(cfg.to_dot());
+        },
+    );
+}
+
+fn print_dataflow<'ast>(cx: &'ast AstContext<'ast>, item: &'ast FnItem<'ast>) {
+    let Some(body_id) = item.body_id() else {
+        return;
+    };
+    let cfg = Cfg::new(cx.body(body_id));
+    let liveness = solve(&cfg, Liveness::new(cx));
+    let reaching = solve(&cfg, ReachingDefinitions::new());
+    let uninit = solve(
+        &cfg,
+        MaybeUninit::with_moves(cx, |expr| {
+            !matches!(expr.ty(), SemTyKind::Num(_) | SemTyKind::Bool(_) | SemTyKind::Ref(_))
+        }),
+    );
+
+    let mut def_snippets = HashMap::new();
+    for node in cfg.blocks().iter().flat_map(BasicBlock::nodes) {
+        match node {
+            CfgNode::Let(stmt) => def_snippets.insert(DefSite::Let(stmt.id()), stmt.span().snippet()),
+            CfgNode::Expr(expr) => def_snippets.insert(DefSite::Assign(expr.id()), expr.span().snippet()),
+            CfgNode::Pat(pat, owner) => def_snippets.insert(DefSite::Pat(owner.id()), pat.span().snippet()),
+            _ => None,
+        };
+    }
+
+    cx.emit_lint(
+        TEST_LINT,
+        item.id(),
+        "printing dataflow",
+        item.ident().unwrap().span(),
+        |diag| {
+            for block in cfg.blocks().iter().filter(|block| cfg.is_reachable(block.id())) {
+                for (index, node) in block.nodes().iter().enumerate() {
+                    let live = liveness.state_after(&cfg, block.id(), index);
+                    match node {
+                        CfgNode::Let(stmt) if stmt.init().is_some() => {
+                            if bound_vars(stmt.pat()).iter().all(|var| !live.contains(var)) {
+                                diag.note(format!("Never read: `{}`", stmt.span().snippet().unwrap_or("<..>")));
+                            }
+                        },
+                        CfgNode::Expr(ExprKind::Assign(assign)) => {
+                            if let PatKind::Place(ExprKind::Path(path), _) = assign.assignee() {
+                                if let AstPathTarget::Var(var) = path.path().resolve() {
+                                    let snippet = assign.span().snippet().unwrap_or("<..>");
+                                    if !live.contains(&var) {
+                                        diag.note(format!("Never read: `{snippet}`"));
+                                    }
+                                    if uninit.state_before(&cfg, block.id(), index).contains(&var) {
+                                        diag.note(format!("Initializes: `{snippet}`"));
+                                    }
+                                }
+                            }
+                        },
+                        CfgNode::Expr(ExprKind::Path(path)) => {
+                            if let AstPathTarget::Var(var) = path.path().resolve() {
+                                let name = path.span().snippet().unwrap_or("<..>");
+                                let defs: Vec<_> = reaching
+                                    .state_before(&cfg, block.id(), index)
+                                    .into_iter()
+                                    .filter(|def| def.var() == var)
+                                    .map(|def| format!("`{}`", def_snippets[&def.site()].unwrap_or("<..>")))
+                                    .collect();
+                                diag.note(format!("`{name}` defined by: [{}]", defs.join(", ")));
+                                if uninit.state_before(&cfg, block.id(), index).contains(&var) {
+                                    diag.note(format!("`{name}` might be uninitialized or moved"));
+                                }
+                            }
+                        },
+                        _ => {},
+                    }
+                }
+            }
+        },
+    );
+}
+
+/// Prints the value of the initializer and of the [`ConstExpr`]s in the type
+/// annotation and in the generic args of a path initializer.
+fn print_const_value<'ast>(cx: &'ast AstContext<'ast>, lets: &'ast LetStmt<'ast>, init: ExprKind<'ast>) {
+    cx.emit_lint(TEST_LINT, lets.id(), "print const value", lets.span(), |diag| {
+        diag.note(format!("{:?}", cx.eval_const(init)));
+        if let Some(SynTyKind::Array(array)) = lets.ty() {
+            if let Some(len) = array.len() {
+                diag.note(format!("Array length: {:?}", cx.eval_const(len.expr())));
+            }
+        }
+        if let ExprKind::Path(path) = init {
+            for arg in path.path().generics().args() {
+                if let SynGenericArgKind::Const(arg) = arg {
+                    diag.note(format!("Const arg: {:?}", cx.eval_const(arg.expr().expr())));
+                }
+            }
+        }
+    });
+}
+
+fn print_fn_sig<'ast>(
+    cx: &'ast AstContext<'ast>,
+    diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>,
+    ty: SemTyKind<'ast>,
+) {
+    let SemTyKind::FnTy(fn_ty) = ty else {
+        unreachable!("the initializer should be a function")
+    };
+    let sig = fn_ty.sig();
+    diag.note(format!("Path: {}", cx.item_path(sig.fn_id())));
+    diag.note(format!(
+        "{:?}, {:?}, {:?}, {:?}, variadic: {}",
+        sig.safety(),
+        sig.abi(),
+        sig.constness(),
+        sig.syncness(),
+        sig.is_variadic()
+    ));
+    for param in sig.params() {
+        diag.note(format!("Param: {:?}: {}", param.ident(), short_ty(cx, param.ty())));
+    }
+    diag.note(format!("Return: {}", short_ty(cx, sig.return_ty())));
+}
+
+fn print_layout<'ast>(
+    cx: &'ast AstContext<'ast>,
+    diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>,
+    ty: SemTyKind<'ast>,
+) {
+    let Some(layout) = cx.layout_of(ty) else {
+        diag.note(format!("No layout for `{}`", short_ty(cx, ty)));
+        return;
+    };
+    diag.note(format!(
+        "Layout of `{}`: size: {}, align: {}, zst: {}, uninhabited: {}, niche: {}",
+        short_ty(cx, ty),
+        layout.size(),
+        layout.align(),
+        layout.is_zero_sized(),
+        layout.is_uninhabited(),
+        layout.has_niche(),
+    ));
+    if !layout.variant_sizes().is_empty() {
+        diag.note(format!("Variant sizes: {:?}", layout.variant_sizes()));
+    }
+}
+
+fn print_adjustments<'ast>(
+    cx: &'ast AstContext<'ast>,
+    diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>,
+    expr: ExprKind<'ast>,
+) {
+    let mut exprs = vec![expr];
+    match expr {
+        ExprKind::Method(method) => {
+            exprs.push(method.receiver());
+            exprs.extend(method.args());
+        },
+        ExprKind::Call(call) => exprs.extend(call.args()),
+        ExprKind::Ref(reference) => exprs.push(reference.expr()),
+        _ => {},
+    }
+
+    for expr in exprs {
+        let adjustments: Vec<_> = cx
+            .expr_adjustments(expr.id())
+            .iter()
+            .map(|adj| format!("{:?} -> `{}`", adj.kind(), adj.target().display().trim_paths()))
+            .collect();
+        diag.note(format!(
+            "`{}`: [{}], adjusted type: `{}`",
+            expr.span().snippet_or(".."),
+            adjustments.join(", "),
+            cx.expr_ty_adjusted(expr.id()).display().trim_paths(),
+        ));
+    }
+}
+
+fn print_closure_captures<'ast>(
+    cx: &'ast AstContext<'ast>,
+    diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>,
+    expr: ExprKind<'ast>,
+) {
+    let ExprKind::Closure(closure) = expr else {
+        unreachable!("the initializer should be a closure")
+    };
+    let captures = cx.closure_captures(closure);
+    diag.note(format!("Kind: {:?}", captures.kind()));
+    for capture in captures.captures() {
+        diag.note(format!(
+            "Captures `{}` by {:?} as `{}`, projections: {:?}, at: `{}`",
+            capture.name(),
+            capture.mode(),
+            capture.ty().display().trim_paths(),
+            capture.projections(),
+            capture.span().snippet_or(".."),
+        ));
+    }
+}
+
+fn print_ty_resolution<'ast>(
+    cx: &'ast AstContext<'ast>,
+    diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>,
+    ty: SemTyKind<'ast>,
+) {
+    let SemTyKind::Adt(adt) = ty else {
+        unreachable!("how? Everything should be an ADT")
+    };
+    for path in [
+        "std::vec::Vec",
+        "std::string::String",
+        "std::option::Option",
+        "crate::TestType",
+    ] {
+        let ids = cx.resolve_ty_ids(path);
+        diag.note(format!("Is this a {:#?} -> {}", path, ids.contains(&adt.def_id())));
+    }
+}
+
+fn print_future_ty<'ast>(
+    cx: &'ast AstContext<'ast>,
+    diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>,
+    ty: SemTyKind<'ast>,
+) {
+    diag.note(format!("Type: `{}`", ty.display().trim_paths()));
+    if let SemTyKind::Opaque(opaque) = ty {
+        for bound in opaque.bounds() {
+            diag.note(format!(
+                "Bound: `{}` with {} generic args",
+                cx.ty_def_path(bound.trait_id()),
+                bound.trait_generic_args().args().len(),
+            ));
+        }
+    }
+
+    let SemTyKind::Coroutine(coroutine) = ty.peel_aliases() else {
+        return;
+    };
+    diag.note(format!(
+        "Coroutine: {:?}, async: {}, return type: `{}`",
+        coroutine.kind(),
+        coroutine.is_async(),
+        coroutine.return_ty().display().trim_paths(),
+    ));
+    if let Some(body) = coroutine.body_id() {
+        let body = cx.body(body);
+        let snippet: Vec<_> = body.expr().span().snippet_or("..").split_whitespace().collect();
+        diag.note(format!("Body: `{}`", snippet.join(" ")));
+    }
+    let interior: Vec<_> = coroutine
+        .interior_tys()
+        .iter()
+        .map(|ty| format!("`{}`", ty.display().trim_paths()))
+        .collect();
+    diag.note(format!("Interior types: [{}]", interior.join(", ")));
+}
+
+fn print_desugaring<'ast>(
+    cx: &'ast AstContext<'ast>,
+    diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>,
+    expr: ExprKind<'ast>,
+) {
+    diag.note(format!("Desugaring: {:?}", cx.expr_desugaring(expr.id())));
+    match expr {
+        ExprKind::For(for_expr) => {
+            diag.note(format!(
+                "Iterator type: `{}`",
+                for_expr.iter_ty().display().trim_paths()
+            ));
+            if let Some(item_ty) = for_expr.item_ty() {
+                diag.note(format!("Item type: `{}`", item_ty.display().trim_paths()));
+            }
+        },
+        ExprKind::QuestionMark(question) => {
+            if let Some(residual_ty) = question.residual_ty() {
+                diag.note(format!("Residual type: `{}`", residual_ty.display().trim_paths()));
+            }
+            diag.note(format!("Output type: `{}`", question.ty().display().trim_paths()));
+        },
+        ExprKind::Loop(loop_expr) => {
+            diag.note(format!(
+                "Body desugaring: {:?}",
+                cx.expr_desugaring(loop_expr.block().id())
+            ));
+        },
+        ExprKind::Await(await_expr) => {
+            diag.note(format!(
+                "Future type: `{}`",
+                await_expr.future_ty().display().trim_paths()
+            ));
+            diag.note(format!("Output type: `{}`", await_expr.ty().display().trim_paths()));
+        },
+        _ => {},
+    }
+}
+
+fn print_ast_matches<'ast>(
+    cx: &'ast AstContext<'ast>,
+    diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>,
+    lets: &LetStmt<'ast>,
+    expr: ExprKind<'ast>,
+) {
+    fn snippet(expr: ExprKind<'_>) -> &str {
+        expr.span().snippet().unwrap_or("<expr>")
+    }
+
+    match_ast!(cx, expr, method_call!(recv: _, "unwrap", []) => {
+        diag.note(format!("Unwrap of `{}`", snippet(recv)));
+    });
+    match_ast!(cx, expr, path!("std::mem::forget")(arg: ty!(_, "std::vec::Vec")) => {
+        diag.note(format!("Forget of the `Vec` `{}`", snippet(arg)));
+    });
+    match_ast!(cx, expr, binary!(left, Add, binary!(_, Mul, right)) => {
+        diag.note(format!("Add `{}` to a product with `{}`", snippet(left), snippet(right)));
+    });
+    match_ast!(cx, expr, method_call!(ty!(vec, "std::vec::Vec"), "push", [unary!(Neg, value)]) => {
+        diag.note(format!("Push of `-{}` to `{}`", snippet(value), snippet(vec)));
+    });
+    match_ast!(cx, expr, borrow!(field!(base, "0")) => {
+        diag.note(format!("Borrow of the first field of `{}`", snippet(base)));
+    });
+    match_ast!(cx, expr, call!(_, [first, ..]) => {
+        diag.note(format!("Call with the first argument `{}`", snippet(first)));
+    });
+
+    match_ast!(cx, lets.pat(), ident_pat!(ident) => {
+        if let Some(binding) = ident.binding_pat() {
+            match_ast!(cx, binding, tuple_pat!(_, ref_pat!(ident_pat!(inner)), ..) => {
+                diag.note(format!("Binding `{}` with the nested reference `{}`", ident.name(), inner.name()));
+            });
+        }
+    });
+
+    if let Some(ty) = lets.ty() {
+        match_ast!(cx, ty, ref_ty!(slice_ty!(_)) => {
+            diag.note("Type is a slice reference");
+        });
+        match_ast!(cx, ty, tuple_ty!(path_ty!("std::string::String"), _) => {
+            diag.note("Type is a tuple starting with `String`");
+        });
+    }
+
+    if !marker_utils::matches_ast!(cx, expr, call!(_, [..])) {
+        diag.note("Not a call");
+    }
+}
+
+const RESOLVED_PATHS: &[&str] = &[
+    "std::mem::transmute",
+    "core::intrinsics::transmute",
+    "std::mem::forget",
+    "crate::reexport::drop_it",
+    "std::option::Option::unwrap",
+    "std::clone::Clone::clone",
+    "std::iter::Iterator::next",
+    "crate::local_fn",
+];
+
+fn print_jump_targets<'ast>(
+    cx: &'ast AstContext<'ast>,
+    diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>,
+    expr: ExprKind<'ast>,
+) {
+    #[derive(Default)]
+    struct JumpCollector {
+        snippets: HashMap<ExprId, String>,
+        jumps: Vec<(String, ExprId)>,
+    }
+    impl Visitor<()> for JumpCollector {
+        fn visit_expr<'v>(&mut self, _cx: &'v AstContext<'v>, expr: ExprKind<'v>) -> ControlFlow<()> {
+            let snippet = expr.span().snippet_or("..").to_string();
+            match expr {
+                ExprKind::Break(e) => self.jumps.push((snippet.clone(), e.target_id())),
+                ExprKind::Continue(e) => self.jumps.push((snippet.clone(), e.target_id())),
+                _ => {},
+            }
+            self.snippets.insert(expr.id(), snippet);
+            ControlFlow::Continue(())
+        }
+    }
+
+    let mut collector = JumpCollector::default();
+    let _ = traverse_expr(cx, &mut collector, expr);
+    for (jump, target) in &collector.jumps {
+        let target = collector
+            .snippets
+            .get(target)
+            .and_then(|snippet| snippet.lines().next())
+            .unwrap_or("<unknown>");
+        diag.note(format!("`{jump}` targets `{target}`"));
+    }
+}
+
+fn print_call_resolution<'ast>(
+    cx: &'ast AstContext<'ast>,
+    diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>,
+    expr: ExprKind<'ast>,
+) {
+    let Some(target) = marker_utils::paths::call_target(expr) else {
+        diag.note("Not a call");
+        return;
+    };
+    diag.note(format!("Call target: `{}`", cx.item_path(target).as_str()));
+    for path in RESOLVED_PATHS {
+        if marker_utils::paths::is_call_to(cx, expr, path) {
+            diag.note(format!("Calls `{path}`"));
+        }
+    }
+}
+
+fn print_suggs<'ast>(diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>, expr: ExprKind<'ast>) {
+    let mut app = Applicability::MachineApplicable;
+    let sugg = Sugg::expr(expr, "<expr>", &mut app);
+    diag.note(format!("Applicability: {app:?}"));
+    diag.note(format!("Not: `{}`", !sugg.clone()));
+    diag.note(format!("Deref: `{}`", sugg.clone().deref()));
+    diag.note(format!("Cast: `{}`", sugg.clone().cast("u64")));
+    diag.note(format!(
+        "Mul: `{}`",
+        sugg.clone().bin_op(BinaryOpKind::Mul, Sugg::atom("2"))
+    ));
+    diag.note(format!(
+        "Lesser: `{}`",
+        sugg.clone().bin_op(BinaryOpKind::Lesser, Sugg::atom("2"))
+    ));
+    diag.note(format!("Method: `{}`", sugg.method_call("pow", &["2"])));
+}
+
+fn print_ty_utils<'ast>(diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>, ty: SemTyKind<'ast>) {
+    diag.note(format!("Display: `{ty}`"));
+    diag.note(format!("Trimmed: `{}`", ty.display().trim_paths()));
+    diag.note(format!("Peeled refs: `{}`", ty.peel_refs().display().trim_paths()));
+    match ty {
+        SemTyKind::Tuple(tuple) => {
+            let distinct: std::collections::HashSet<_> = tuple.types().iter().collect();
+            diag.note(format!("Distinct types: {}", distinct.len()));
+        },
+        SemTyKind::Adt(adt) => {
+            for field in adt.def().fields() {
+                let field_ty = field.ty().subst(adt.generics());
+                diag.note(format!(
+                    "Field `{}`: `{}` -> `{}` -> `{}`",
+                    field.ident(),
+                    field.ty().display().trim_paths(),
+                    field_ty.display().trim_paths(),
+                    field_ty.peel_aliases().display().trim_paths(),
+                ));
+                // Args of other items are not applied
+                if let SemTyKind::Adt(field_adt) = field_ty {
+                    if field_adt.def_id() != adt.def_id() {
+                        let foreign_ty = field.ty().subst(field_adt.generics());
+                        diag.note(format!("With foreign args: `{}`", foreign_ty.display().trim_paths()));
+                    }
+                }
+            }
+        },
+        _ => {},
+    }
+}
+
+fn print_predicates<'ast>(cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+    let generics = match item {
+        ItemKind::Fn(item) => item.generics(),
+        ItemKind::Struct(item) => item.generics(),
+        ItemKind::Enum(item) => item.generics(),
+        ItemKind::Union(item) => item.generics(),
+        ItemKind::Trait(item) => item.generics(),
+        ItemKind::TyAlias(item) => item.generics(),
+        _ => return,
+    };
+    cx.emit_lint(
+        TEST_LINT,
+        item.id(),
+        "printing predicates",
+        item.ident().unwrap().span(),
+        |diag| {
+            for predicate in cx.predicates_of(item.id()) {
+                diag.note(format!("{:?}: `{}`", predicate.source(), predicate_str(cx, predicate)));
+            }
+            for param in generics.params() {
+                let id = match param {
+                    SynGenericParamKind::Lifetime(param) => param.id(),
+                    SynGenericParamKind::Ty(param) => param.id(),
+                    SynGenericParamKind::Const(param) => param.id(),
+                    _ => unreachable!(),
+                };
+                let param = cx.generic_param(id);
+                let bounds: Vec<_> = param
+                    .bounds()
+                    .iter()
+                    .map(|bound| format!("`{}`", predicate_str(cx, bound)))
+                    .collect();
+                diag.note(format!(
+                    "Param `{}` ({:?}): [{}]",
+                    param.name(),
+                    param.kind(),
+                    bounds.join(", ")
+                ));
+            }
+            if item
+                .ident()
+                .is_some_and(|ident| ident.name().starts_with("print_predicates_subst"))
+            {
+                print_subst_trait_predicates(cx, diag, item.id());
+            }
+        },
+    );
+}
+
+/// Prints the predicates of the traits bound by the given item, substituted
+/// with the generic args of the bound.
+fn print_subst_trait_predicates<'ast>(
+    cx: &'ast AstContext<'ast>,
+    diag: &mut marker_api::diagnostic::DiagnosticBuilder<'ast>,
+    id: ItemId,
+) {
+    for predicate in cx.predicates_of(id) {
+        let SemPredicateKind::Trait(bound_pred) = predicate.kind() else {
+            continue;
+        };
+        if predicate.source() != PredicateSource::Declared {
+            continue;
+        }
+        let args = bound_pred.bound().trait_generic_args();
+        let trait_path = cx.ty_def_path(bound_pred.bound().trait_id());
+        for trait_id in cx.resolve_item_ids(trait_path.as_str()) {
+            for trait_pred in cx.predicates_of(*trait_id) {
+                let SemPredicateKind::Trait(pred) = trait_pred.kind() else {
+                    continue;
+                };
+                let trait_args: Vec<_> = pred
+                    .bound()
+                    .trait_generic_args()
+                    .args()
+                    .iter()
+                    .filter_map(|arg| match arg {
+                        SemGenericArgKind::Ty(ty) => Some(ty.subst(args).display().trim_paths().to_string()),
+                        _ => None,
+                    })
+                    .collect();
+                let trait_name = cx
+                    .ty_def_path(pred.bound().trait_id())
+                    .segments()
+                    .last()
+                    .unwrap_or_default();
+                let trait_str = if trait_args.is_empty() {
+                    trait_name.to_string()
+                } else {
+                    format!("{trait_name}<{}>", trait_args.join(", "))
+                };
+                diag.note(format!(
+                    "Substituted `{}` -> `{}: {trait_str}`",
+                    predicate_str(cx, trait_pred),
+                    pred.self_ty().subst(args).display().trim_paths(),
+                ));
+            }
+        }
+    }
+}
+
+fn predicate_str<'ast>(cx: &'ast AstContext<'ast>, predicate: &SemPredicate<'ast>) -> String {
+    fn lifetime_str(lifetime: &Lifetime<'_>) -> String {
+        match lifetime.label() {
+            Some(label) => label.to_string(),
+            None if lifetime.is_static() => "'static".to_string(),
+            None => "'_".to_string(),
+        }
+    }
+    let trait_str = |trait_id, args: &SemGenericArgs<'ast>| {
+        let args: Vec<_> = args
+            .args()
+            .iter()
+            .filter_map(|arg| match arg {
+                SemGenericArgKind::Ty(ty) => Some(ty.display().trim_paths().to_string()),
+                _ => None,
+            })
+            .collect();
+        let path = cx.ty_def_path(trait_id);
+        let name = path.segments().last().unwrap_or_default();
+        if args.is_empty() {
+            name.to_string()
+        } else {
+            format!("{name}<{}>", args.join(", "))
+        }
+    };
+
+    match predicate.kind() {
+        SemPredicateKind::Trait(pred) => format!(
+            "{}: {}",
+            pred.self_ty().display().trim_paths(),
+            trait_str(pred.bound().trait_id(), pred.bound().trait_generic_args())
+        ),
+        SemPredicateKind::TyOutlives(pred) => format!(
+            "{}: {}",
+            pred.ty().display().trim_paths(),
+            lifetime_str(pred.lifetime())
+        ),
+        SemPredicateKind::LifetimeOutlives(pred) => {
+            format!("{}: {}", lifetime_str(pred.longer()), lifetime_str(pred.shorter()))
+        },
+        SemPredicateKind::Projection(pred) => format!(
+            "<{} as {}>::{} == {}",
+            pred.self_ty().display().trim_paths(),
+            trait_str(pred.trait_id(), pred.trait_generic_args()),
+            cx.item_path(pred.assoc_item()).segments().last().unwrap_or_default(),
+            pred.ty().display().trim_paths()
+        ),
+        _ => "<unknown>".to_string(),
+    }
+}
+
+/// A short representation of semantic types, to keep the test output readable.
+fn short_ty<'ast>(cx: &'ast AstContext<'ast>, ty: SemTyKind<'ast>) -> String {
+    match ty {
+        SemTyKind::Bool(_) => "bool".to_string(),
+        SemTyKind::Num(num) => format!("{:?}", num.numeric_kind()),
+        SemTyKind::Text(text) => format!("{:?}", text.textual_kind()),
+        SemTyKind::Never(_) => "!".to_string(),
+        SemTyKind::Tuple(tuple) => {
+            let types: Vec<_> = tuple.types().iter().map(|ty| short_ty(cx, *ty)).collect();
+            format!("({})", types.join(", "))
+        },
+        SemTyKind::Ref(reference) => format!("&{}", short_ty(cx, reference.inner_ty())),
+        SemTyKind::RawPtr(ptr) => format!("*{}", short_ty(cx, ptr.inner_ty())),
+        SemTyKind::Adt(adt) => cx.ty_def_path(adt.def_id()).to_string(),
+        SemTyKind::Generic(_) => "<generic>".to_string(),
+        // Opaque types are aliases for their hidden type
+        SemTyKind::Alias(_) | SemTyKind::Opaque(_) => "<alias>".to_string(),
+        _ => "<other>".to_string(),
+    }
+}
+
+fn print_crate_info(krate: &Crate<'_>) {
+    eprintln!("Name: {}", krate.name());
+    eprintln!("Edition: {:?}", krate.edition());
+    eprintln!("Crate type: {:?}", krate.crate_type());
+    eprintln!("Is test: {}", krate.is_test());
+    eprintln!("Features: {:?}", krate.features().collect::<Vec<_>>());
+    eprintln!("Cfg `foo`: {}", krate.is_cfg_active("foo", None));
+    eprintln!(
+        "Cfg `feature = \"bar\"`: {}",
+        krate.is_cfg_active("feature", Some("bar"))
+    );
+    eprintln!(
+        "Manifest: {:?}",
+        krate
+            .manifest_path()
+            .and_then(|path| std::path::Path::new(path).file_name())
+    );
+    for dep in krate.dependencies() {
+        eprintln!("Dependency: {} (version: {:?})", dep.name(), dep.version());
+    }
+
+    eprintln!();
+    eprintln!("=====================================================================");
+    eprintln!();
+}
+
+fn test_item_path_resolution<'ast>(cx: &'ast AstContext<'ast>) {
+    fn try_resolve_path(cx: &AstContext<'_>, path: &str) {
+        let paths: Vec<_> = cx
+            .resolve_item_ids(path)
+            .iter()
+            .map(|id| cx.item_path(*id).as_str())
+            .collect();
+        eprintln!("Resolving {path:?} yielded {paths:?}");
+    }
+
+    eprintln!("# Unresolvable");
+    try_resolve_path(cx, "");
+    try_resolve_path(cx, "something::weird");
+    try_resolve_path(cx, "std::option::Option::None");
+
+    eprintln!();
+    eprintln!("# Valid");
+    try_resolve_path(cx, "std::env");
+    try_resolve_path(cx, "std::mem::forget");
+    try_resolve_path(cx, "std::vec::Vec");
+    try_resolve_path(cx, "std::vec::Vec::new");
+    try_resolve_path(cx, "std::iter::Iterator::next");
+    try_resolve_path(cx, "std::f32::consts::PI");
+
+    eprintln!();
+    eprintln!("# Valid local items");
+    try_resolve_path(cx, "crate::net");
+    try_resolve_path(cx, "crate::net::Client");
+    try_resolve_path(cx, "item_path::net::Client::connect");
+    try_resolve_path(cx, "crate::net::Connect::connect");
+    try_resolve_path(cx, "crate::STATIC_VALUE");
+
+    eprintln!();
+    eprintln!("# Type paths");
+    for id in cx.resolve_ty_ids("std::string::String") {
+        eprintln!("Type path: {}", cx.ty_def_path(*id));
+    }
+
+    eprintln!();
+    eprintln!("=====================================================================");
+    eprintln!();
+}
+
 fn test_ty_id_resolution<'ast>(cx: &'ast AstContext<'ast>) {
     fn try_resolve_path(cx: &AstContext<'_>, path: &str) {
         let ids = cx.resolve_ty_ids(path);
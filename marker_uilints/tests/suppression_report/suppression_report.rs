@@ -0,0 +1,11 @@
+#![feature(lint_reasons)]
+#![feature(register_tool)]
+#![register_tool(marker)]
+
+#[allow(marker::test_lint, reason = "the lint is emitted for this function")]
+fn print_path_suppressed() {}
+
+#[allow(marker::test_lint)]
+fn not_linted() {}
+
+fn main() {}
@@ -0,0 +1,25 @@
+#![allow(unused)]
+
+fn foo(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+fn main() {
+    let opt = Some(1);
+    let tuple = (1, 2);
+    let mut vec = vec![1, 2];
+    let (a, b, c) = (1, 2, 3);
+
+    let _match_unwrap = opt.unwrap();
+    let _match_forget = std::mem::forget(vec.clone());
+    let _match_forget_other = std::mem::forget(String::new());
+    let _match_add = a + b * c;
+    let _match_add_other = a * b + c;
+    let _match_push = vec.push(-a);
+    let _match_field: &i32 = &tuple.0;
+    let _match_call = foo(1, 2);
+    let _match_call_empty = std::process::id();
+    let _match_binding @ (_, &x) = (1, &2);
+    let _match_slice: &[i32] = &[1, 2];
+    let _match_tuple: (String, u32) = (String::new(), 1);
+}
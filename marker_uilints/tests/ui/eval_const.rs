@@ -0,0 +1,68 @@
+#![feature(inline_const)]
+
+const ANSWER: i32 = 42;
+const GREETING: &str = "Hello Marker";
+const BYTES: &[u8; 3] = b"abc";
+const SLICE: &[u8] = &[1, 2];
+const TUPLE: (u8, bool, char) = (7, true, 'x');
+const ARRAY: [u16; 3] = [1, 2, 3];
+const FLOAT: f32 = 0.1;
+const UNITS: &[(); 4] = &[(); 4];
+const WORDS: &[u16; 2] = &[1, 2];
+
+struct Duck;
+
+impl Duck {
+    const QUACKS: usize = ANSWER as usize * 2;
+}
+
+fn generic<const N: usize>(value: u32) {
+    // Const values
+    let _const_generic = N;
+    let _const_not_const = value + 1;
+}
+
+fn main() {
+    // Literals
+    let _const_int = 1_000;
+    let _const_neg = -128_i8;
+    let _const_float = 1.5_f64;
+    let _const_bool = true;
+    let _const_char = 'M';
+    let _const_str = "marker";
+    let _const_byte_str = b"marker";
+    let _const_byte = b'm';
+
+    // Operations
+    let _const_add = 1_u8 + 2;
+    let _const_overflow = 255_u8.wrapping_add(1);
+    let _const_shift = 1_i8 << 7;
+    #[allow(unconditional_panic)]
+    let _const_div_by_zero = 1 / 0;
+    let _const_cmp = 1.0 < 2.0 && 'a' != 'b';
+    let _const_not = !0_u16;
+    let _const_cast = -1_i32 as u8;
+    let _const_float_cast = 300.7_f64 as u8;
+    let _const_tuple = (1_u8, "two", [3.0_f32; 2]);
+
+    // Constant items
+    let _const_item = ANSWER * 2;
+    let _const_str_item = GREETING;
+    let _const_bytes_item = BYTES;
+    let _const_slice_item = SLICE;
+    let _const_tuple_item = TUPLE;
+    let _const_array_item = ARRAY;
+    let _const_float_item = FLOAT;
+    let _const_units_item = UNITS;
+    let _const_words_item = WORDS;
+    let _const_assoc_item = Duck::QUACKS;
+    let _const_block = const { ANSWER + 1 };
+    let _const_std_item = u8::MAX;
+
+    // Constant expressions
+    let _const_array_len: [u8; 2 * 3] = [0; 6];
+    let _const_generic_arg = generic::<{ ANSWER as usize + 1 }>;
+    let _const_large_repeat = [0_u8; 1 << 30];
+
+    generic::<3>(1);
+}
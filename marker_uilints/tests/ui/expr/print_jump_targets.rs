@@ -0,0 +1,24 @@
+fn cond() -> bool {
+    true
+}
+
+fn main() {
+    let _jump_target_block = 'block: {
+        if cond() {
+            break 'block 1;
+        }
+        2
+    };
+    let _jump_target_loops = 'outer: loop {
+        while cond() {
+            if cond() {
+                continue 'outer;
+            }
+            if cond() {
+                break;
+            }
+            break 'outer;
+        }
+        break;
+    };
+}
@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+fn for_loops(vec: Vec<u32>, map: HashMap<String, u8>) {
+    let _desugar_iter = for _ in vec.iter() {};
+    let _desugar_map = for (_key, _value) in &map {};
+    let _desugar_into_iter = for _ in vec {};
+}
+
+fn question_marks(option: Option<u32>, result: Result<i32, String>) -> Option<()> {
+    let _desugar_option = option?;
+    let _desugar_result = result.ok()?;
+    None
+}
+
+fn results(result: Result<i32, String>) -> Result<(), String> {
+    let _desugar_result = result?;
+    Ok(())
+}
+
+async fn number() -> u8 {
+    16
+}
+
+async fn awaits() {
+    let _desugar_await = number().await;
+}
+
+fn others(cond: bool, option: Option<u32>) {
+    let _desugar_while = while cond {};
+    let _desugar_range = 0..16;
+    let _desugar_match = match option {
+        Some(x) => x,
+        None => 0,
+    };
+}
+
+#[allow(irrefutable_let_patterns)]
+fn non_expr_nodes(option: Option<u32>) {
+    let _desugar_loop = loop {
+        break;
+    };
+    let _desugar_let_else = option else {
+        return;
+    };
+    let (mut a, mut b) = (1, 2);
+    let _desugar_assign = (a, b) = (b, a);
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+/// Frees the given pointer.
+///
+/// Uses [`Box::from_raw`] internally, see [the book][book] or
+/// [std docs](https://doc.rust-lang.org/std/) for more information.
+///
+/// # Safety
+///
+/// The pointer must come from [`Box::into_raw`].
+///
+/// ## Panics ##
+///
+/// ```
+/// # // This is not a heading
+/// let x = Box::into_raw(Box::new(1));
+/// unsafe { print_docs_fn(x) };
+/// ```
+///
+/// ```text
+/// [not a link]
+/// ```
+///
+/// [book]: https://doc.rust-lang.org/book/
+pub unsafe fn print_docs_fn(ptr: *mut i32) {
+    drop(Box::from_raw(ptr));
+}
+
+#[doc = "Docs from an attribute."]
+/** Block
+    comment */
+pub struct PrintDocsStruct;
+
+pub fn print_docs_empty() {}
+
+#[doc = "    Indented by four"]
+#[doc = "\u{2003}\u{2003}Indented by em spaces"]
+#[doc = "  Indented by two"]
+pub fn print_docs_uneven() {}
+
+fn main() {}
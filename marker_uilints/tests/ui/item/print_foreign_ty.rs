@@ -0,0 +1,11 @@
+#![feature(extern_types)]
+
+extern "C" {
+    type PrintMeOpaque;
+}
+
+fn use_opaque(opaque: *mut PrintMeOpaque) {
+    let _ty = opaque;
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+trait Trait {
+    const PRINT_OWNER_CONST: u32;
+
+    fn print_owner_required(&self);
+
+    fn print_owner_provided(&self) {}
+
+    fn print_owner_not_overridden(&self) {}
+}
+
+struct Foo;
+
+impl Trait for Foo {
+    const PRINT_OWNER_CONST: u32 = 1;
+
+    fn print_owner_required(&self) {}
+
+    fn print_owner_provided(&self) {}
+}
+
+impl Foo {
+    fn print_owner_inherent(&self) {}
+}
+
+extern "C" {
+    fn print_owner_extern();
+}
+
+fn print_owner_free() {}
+
+fn main() {}
@@ -0,0 +1,64 @@
+pub fn print_vis_pub() {}
+
+pub(crate) fn print_vis_crate() {}
+
+fn print_vis_private() {}
+
+pub(self) fn print_vis_self_in_root() {}
+
+mod private {
+    pub fn print_vis_pub_in_private() {}
+
+    pub fn print_vis_reexported() {}
+
+    pub struct PrintVisReachable;
+
+    pub(super) fn print_vis_super() {}
+
+    pub(in crate::private) fn print_vis_in_path() {}
+
+    pub(self) fn print_vis_self() {}
+
+    pub(in self) fn print_vis_in_self() {}
+
+    mod nested {
+        pub(super) fn print_vis_nested_super() {}
+
+        pub(in crate) fn print_vis_nested_in_crate() {}
+
+        pub(in crate::private) fn print_vis_nested_in_path() {}
+
+        mod deeper {
+            pub(in crate::private) fn print_vis_deeper_in_path() {}
+        }
+    }
+}
+
+macro_rules! vis_fn {
+    ($vis:vis $name:ident) => {
+        $vis fn $name() {}
+    };
+}
+
+vis_fn!(pub(crate) print_vis_from_macro);
+
+pub use private::print_vis_reexported;
+
+pub fn reachable() -> private::PrintVisReachable {
+    private::PrintVisReachable
+}
+
+pub struct PrintVisStruct {
+    pub print_vis_pub_field: u32,
+    print_vis_private_field: u32,
+}
+
+pub trait PrintVisTrait {
+    fn print_vis_trait_fn(&self);
+}
+
+impl PrintVisStruct {
+    pub fn print_vis_method(&self) {}
+}
+
+fn main() {}
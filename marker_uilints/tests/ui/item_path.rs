@@ -0,0 +1,69 @@
+pub mod net {
+    pub struct Client;
+
+    impl Client {
+        pub fn connect() {}
+
+        pub fn print_path_inherent_fn(&self) {
+            let _closure = || {
+                fn print_path_nested() {}
+            };
+        }
+    }
+
+    pub trait Connect {
+        fn connect(&self);
+
+        fn print_path_trait_fn(&self) {}
+    }
+
+    impl Connect for Client {
+        fn connect(&self) {}
+
+        fn print_path_trait_fn(&self) {}
+    }
+
+    impl<T> Connect for Vec<T> {
+        fn connect(&self) {}
+
+        fn print_path_trait_fn(&self) {}
+    }
+
+    impl<T> Connect for [T] {
+        fn connect(&self) {}
+
+        fn print_path_trait_fn(&self) {}
+    }
+
+    pub enum PrintPathEnum {
+        A,
+    }
+
+    pub struct Wrapper<T>(T);
+
+    impl Wrapper<u8> {
+        pub fn print_path_instance(&self) {}
+    }
+
+    impl Wrapper<u16> {
+        pub fn print_path_instance(&self) {}
+    }
+
+    impl<U> Wrapper<U> {
+        pub fn print_path_identity(&self) {}
+    }
+
+    impl<'a, T> Wrapper<&'a [T]> {
+        pub fn print_path_generic_instance(&self) {}
+    }
+}
+
+extern "C" {
+    static PRINT_PATH_EXTERN: u32;
+}
+
+static STATIC_VALUE: u32 = 0;
+
+fn test_item_path_resolution_trigger() {}
+
+fn main() {}
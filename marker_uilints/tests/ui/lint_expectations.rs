@@ -0,0 +1,25 @@
+#![feature(lint_reasons)]
+#![cfg_attr(marker, feature(register_tool))]
+#![cfg_attr(marker, register_tool(marker))]
+
+mod fulfilled_expectation {
+    #[expect(marker::item_with_test_name)]
+    fn find_me_fn() {}
+}
+
+mod fulfilled_expectation_with_cfg_attr {
+    #[cfg_attr(marker, expect(marker::item_with_test_name))]
+    fn find_me_fn() {}
+}
+
+mod unfulfilled_expectation {
+    #[expect(marker::item_with_test_name)]
+    fn normal_fn() {}
+}
+
+mod unfulfilled_expectation_with_cfg_attr {
+    #[cfg_attr(marker, expect(marker::item_with_test_name, reason = "the name is fine"))]
+    fn normal_fn() {}
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#![cfg_attr(marker, feature(register_tool))]
+#![cfg_attr(marker, register_tool(marker))]
+
+mod renamed_lint {
+    // The renamed lint should still be allowed
+    #[allow(marker::old_item_with_test_name)]
+    fn find_me_fn() {}
+}
+
+mod removed_lint {
+    #[warn(marker::removed_test_lint)]
+    fn find_me_fn() {}
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+#![allow(unused)]
+
+mod reexport {
+    pub use std::mem::forget as drop_it;
+}
+
+use reexport::drop_it as _use_drop;
+use std::mem::transmute as _use_transmute;
+
+fn local_fn() {}
+
+fn main() {
+    let opt = Some(1);
+    let vec = vec![1, 2];
+
+    let _resolve_call_unwrap = opt.unwrap();
+    let _resolve_call_ufcs = Option::unwrap(opt);
+    let _resolve_call_clone = vec.clone();
+    let _resolve_call_next = vec.iter().next();
+    let _resolve_call_forget = reexport::drop_it(String::new());
+    let _resolve_call_local = local_fn();
+    let _resolve_call_none = opt;
+    unsafe {
+        let _resolve_call_transmute = std::mem::transmute::<u32, i32>(1);
+    }
+}
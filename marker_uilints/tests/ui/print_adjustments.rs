@@ -0,0 +1,25 @@
+fn takes_str(_: &str) {}
+fn takes_slice(_: &[u8]) {}
+fn takes_fn_ptr(_: fn() -> u32) {}
+fn value() -> u32 {
+    1
+}
+
+fn never() {
+    let _adjust_never: u32 = std::process::exit(0);
+}
+
+fn main() {
+    let vec = vec![1, 2, 3];
+    let string = String::from("marker");
+    let boxed = Box::new(String::new());
+
+    let _adjust_auto_ref = vec.len();
+    let _adjust_auto_deref = boxed.len();
+    let _adjust_deref_coercion = takes_str(&string);
+    let _adjust_unsize = takes_slice(&[1, 2, 3]);
+    let _adjust_reify = takes_fn_ptr(value);
+    let _adjust_closure: fn() -> u32 = || 2;
+    let _adjust_raw: *const u32 = &5;
+    let _adjust_none = 4 + 5;
+}
@@ -0,0 +1,40 @@
+#[non_exhaustive]
+pub struct Local<T> {
+    pub a: T,
+    b: u32,
+}
+
+pub enum LocalEnum {
+    A,
+    B(u32),
+    #[non_exhaustive]
+    C {
+        x: u8,
+    },
+}
+
+pub union LocalUnion {
+    pub int: u32,
+    pub(crate) float: f32,
+}
+
+pub unsafe fn local_fn<T>(value: T, _: &u32) -> T {
+    value
+}
+
+pub async fn local_async_fn() {}
+
+fn main() {
+    let _adt_def_local = Local { a: 1u8, b: 2 };
+    let _adt_def_enum = LocalEnum::A;
+    let _adt_def_union = LocalUnion { int: 1 };
+    let _adt_def_range = std::ops::Range { start: 0u32, end: 1 };
+    let _adt_def_int_error = std::num::IntErrorKind::Empty;
+
+    let _fn_sig_local = local_fn::<u8>;
+    let _fn_sig_async = local_async_fn;
+    let _fn_sig_replace = std::mem::replace::<u32>;
+    let _fn_sig_push = Vec::<u8>::push;
+    let _fn_sig_some_ctor = Some::<u8>;
+    let _fn_sig_local_ctor = LocalEnum::B;
+}
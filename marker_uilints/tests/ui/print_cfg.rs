@@ -0,0 +1,81 @@
+fn print_cfg_if(cond: bool) -> u32 {
+    let a = if cond { 1 } else { 2 };
+    if cond && a > 1 {
+        return a;
+    }
+    a + 1
+}
+
+fn print_cfg_match(opt: Option<u32>) -> u32 {
+    match opt {
+        Some(x) if x > 5 => x,
+        Some(x) => x + 1,
+        None => 0,
+    }
+}
+
+fn print_cfg_loops(data: &[u32]) -> u32 {
+    let mut sum = 0;
+    'outer: for x in data {
+        while sum < 10 {
+            if *x == 0 {
+                continue 'outer;
+            }
+            sum += x;
+        }
+        if sum > 100 {
+            break;
+        }
+    }
+    let value = loop {
+        break sum;
+    };
+    value
+}
+
+fn print_cfg_diverging(opt: Option<u32>) -> Option<u32> {
+    let x = opt?;
+    let Some(y) = opt else {
+        panic!("no value");
+    };
+    if x > y {
+        return None;
+        let _unreachable = x + y;
+    }
+    let _z = 'block: {
+        if x == 0 {
+            break 'block 1;
+        }
+        x
+    };
+    std::process::exit((x + y) as i32);
+}
+
+async fn print_cfg_async() -> u32 {
+    async fn value() -> u32 {
+        1
+    }
+    value().await + 2
+}
+
+fn print_cfg_async_block() -> u32 {
+    fn value() -> u32 {
+        2
+    }
+    let _pending = async {
+        if value() > 1 {
+            return 1;
+        }
+        async { 3 }.await
+    };
+    value()
+}
+
+fn print_cfg_assign(data: &mut [u32], index: fn() -> usize) {
+    let mut pair = (0, 0);
+    data[index()] = 1;
+    (pair.0, data[index() + 1]) = (2, 3);
+    pair.1 += data[0];
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+struct Data {
+    name: String,
+    values: Vec<u32>,
+}
+
+fn main() {
+    let mut counter = 0;
+    let data = Data {
+        name: String::from("marker"),
+        values: vec![1, 2, 3],
+    };
+    let text = String::from("text");
+    let reference = &mut counter;
+
+    let _captures_none = || 1;
+    let _captures_ref = || data.values.len() + data.name.len();
+    let _captures_move = move || data.values.len();
+    let _captures_mut = || *reference += 1;
+    let _captures_value = || drop(text);
+
+    let mut total = 0;
+    let pair = (1, String::new());
+    let _captures_fields = || total += pair.0;
+}
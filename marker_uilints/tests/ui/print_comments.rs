@@ -0,0 +1,33 @@
+//! Inner docs
+
+// Separated by an empty line
+
+/* A block comment */
+// A line comment
+/// Docs
+#[allow(dead_code)]
+// Between attributes
+#[inline]
+fn print_comments_fn() -> u32 {
+    let x = 1; // Trailing comment
+    // SAFETY: The value is on the stack
+    let _comments_stmt = unsafe { *(&x as *const u32) };
+
+    let _comments_expr = /* SAFETY: the value is on the stack */ unsafe { *(&x as *const u32) };
+
+    let _comments_none = "// not a comment";
+
+    let _comments_inside = {
+        // Inside
+        /** Unused docs */
+        /**/
+        x
+    };
+    x
+}
+
+/* Trailing */ fn print_comments_trailing() {}
+
+fn main() {
+    print_comments_fn();
+}
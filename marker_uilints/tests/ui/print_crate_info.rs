@@ -0,0 +1,7 @@
+//@compile-flags: --cfg foo --cfg feature="bar" --cfg feature="baz"
+//@rustc-env: CARGO_MANIFEST_DIR=/marker-test MARKER_DEPENDENCY_VERSIONS={"/marker-test/Cargo.toml":{"std":"1.0.0"}}
+
+// `cargo marker test-setup` provides the dependency versions of the workspace
+// members. This test uses a fake manifest, which needs fake versions as well.
+
+fn main() {}
@@ -0,0 +1,55 @@
+fn print_dataflow_liveness(cond: bool) -> u32 {
+    let mut a = 1;
+    let unused = 2;
+    a = 3;
+    if cond {
+        a += 1;
+    } else {
+        a = 10;
+    }
+    let b = a * 2;
+    let c = 4;
+    let f = || c + 1;
+    b + f()
+}
+
+fn print_dataflow_loop(data: &[u32]) -> u32 {
+    let mut sum = 0;
+    let mut last = 0;
+    for x in data {
+        sum += x;
+        last = *x;
+    }
+    sum
+}
+
+fn print_dataflow_uninit(cond: bool) -> String {
+    let text;
+    if cond {
+        text = String::from("hey");
+    } else {
+        text = String::new();
+    }
+    let mut other = String::new();
+    if cond {
+        drop(other);
+    }
+    other = text;
+    let x: u32;
+    x = 5;
+    other.push_str("!");
+    other.push_str(&x.to_string());
+    other
+}
+
+fn print_dataflow_receiver(cond: bool) -> usize {
+    let mut data = vec![1, 2];
+    let len = data.len();
+    if cond {
+        data.into_iter().count();
+    }
+    data = vec![3];
+    data.len() + len
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use std::rc::Rc;
+
+async fn number() -> u8 {
+    16
+}
+
+async fn with_rc() -> usize {
+    let rc = Rc::new(vec![1, 2, 3]);
+    number().await;
+    rc.len()
+}
+
+fn numbers() -> impl Iterator<Item = u8> + Clone {
+    0..16
+}
+
+fn callback() -> impl Fn(u8) -> u8 {
+    |x| x + 1
+}
+
+async fn main_async() {
+    let _future_fn = number();
+    let _future_rc = with_rc();
+    let _future_block = async {
+        let data = String::from("data");
+        number().await;
+        data.len()
+    };
+    let _future_iter = numbers();
+    let _future_callback = callback();
+}
+
+fn main() {}
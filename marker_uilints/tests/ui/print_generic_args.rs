@@ -0,0 +1,22 @@
+#![feature(associated_type_bounds)]
+#![feature(associated_const_equality)]
+#![feature(generic_arg_infer)]
+
+use std::fmt::Debug;
+
+trait Shape {
+    const SIDES: usize;
+}
+
+fn print_me_constraint(_: impl Iterator<Item: Debug>) {}
+
+fn print_me_const_binding(_: impl Shape<SIDES = 3>) {}
+
+fn create_array<const N: usize>() -> [u8; N] {
+    [0; N]
+}
+
+fn main() {
+    let _print_infer = create_array::<_>;
+    let _: [u8; 2] = _print_infer();
+}
@@ -0,0 +1,49 @@
+#![allow(unused)]
+
+use std::marker::PhantomData;
+
+enum LargeVariant {
+    Small(u8),
+    Large([u8; 1024]),
+    Empty,
+}
+
+enum Uninhabited {}
+
+struct Zst;
+
+#[repr(C)]
+struct ReprC {
+    a: u8,
+    b: u32,
+    c: u16,
+}
+
+fn generic<T: Default>() -> T {
+    let _layout_generic = T::default();
+    T::default()
+}
+
+fn never() -> ! {
+    panic!()
+}
+
+fn main() {
+    let _layout_large_variant = LargeVariant::Empty;
+    let _layout_zst = Zst;
+    let _layout_phantom: PhantomData<String> = PhantomData;
+    let _layout_repr_c = ReprC { a: 1, b: 2, c: 3 };
+    let _layout_ref = &1u8;
+    let _layout_option_ref = Some(&1u8);
+    let _layout_option_u32 = Some(1u32);
+    let _layout_string = String::new();
+    let _layout_slice: &[u8] = &[1, 2, 3];
+    let _layout_uninhabited: Option<Uninhabited> = None;
+    let fn_ptr: fn(&u8) -> &u8 = |x| x;
+    let _layout_fn_ptr = fn_ptr;
+    let _layout_closure = |x: u32| x + 1;
+
+    if false {
+        let _layout_never = never();
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt::Debug;
+
+trait PrintPredicatesSuper: Clone {}
+
+trait PrintPredicatesTrait<T>: PrintPredicatesSuper
+where
+    T: Default,
+{
+    fn print_predicates_assoc<U: Debug>(&self, _: U);
+}
+
+struct PrintPredicatesOutlives<'a, T> {
+    _data: &'a T,
+}
+
+struct Wrapper<T>(T);
+
+impl<T: Copy + Debug> Wrapper<T> {
+    fn print_predicates_inherited<'a, 'b: 'a>(&self, _: &'a T, _: &'b T) {}
+}
+
+fn print_predicates_where<I>(_: I)
+where
+    I: Iterator<Item = u8> + 'static,
+{
+}
+
+fn print_predicates_sized<T: Sized, U: ?Sized>(_: T, _: &U) {}
+
+trait PrintPredicatesBounds {
+    type PrintPredicatesItem: Clone + Iterator<Item = u8>;
+}
+
+trait PrintPredicatesPair<A, B>: Into<Vec<B>>
+where
+    A: Clone + Into<B>,
+{
+}
+
+fn print_predicates_subst<P: PrintPredicatesPair<u8, u32>>(_: P) {}
+
+fn main() {}
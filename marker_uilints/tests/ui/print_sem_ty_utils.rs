@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+struct Wrapper<T> {
+    value: T,
+    list: Vec<T>,
+}
+
+struct ItemOf<I: Iterator> {
+    item: I::Item,
+    iter: I,
+}
+
+struct Plain {
+    count: u32,
+}
+
+fn generic<T: Clone>(value: T) -> T {
+    let _sem_ty_generic = Wrapper {
+        value: value.clone(),
+        list: vec![],
+    };
+    value
+}
+
+trait Named {
+    fn named(&self) -> &Self {
+        let _sem_ty_self = self;
+        self
+    }
+}
+
+fn projections<I: Iterator, J: Iterator>(first: I::Item, second: J::Item, third: I::Item) {
+    let _sem_ty_projections = (first, second, third);
+}
+
+fn main() {
+    let _sem_ty_ref = &&String::new();
+    let _sem_ty_wrapper = Wrapper {
+        value: 1u16,
+        list: vec![],
+    };
+    let _sem_ty_plain = Plain { count: 0 };
+    let _sem_ty_item_of = ItemOf {
+        item: 1u8,
+        iter: vec![1u8].into_iter(),
+    };
+    let _sem_ty_map: HashMap<String, Vec<u8>> = HashMap::new();
+    let _sem_ty_tuple = (1u32, 2u32, 'c', "s", [0u8; 4], &[1u8][..], (1u32,));
+
+    let fn_ptr: unsafe extern "C" fn(u8, &u32) -> bool = {
+        unsafe extern "C" fn inner(_: u8, _: &u32) -> bool {
+            true
+        }
+        inner
+    };
+    let _sem_ty_fn_ptr = fn_ptr;
+    let system_fn_ptr: extern "system" fn() = {
+        extern "system" fn inner() {}
+        inner
+    };
+    let _sem_ty_system_fn_ptr = system_fn_ptr;
+    let _sem_ty_fn_item = generic::<u8>;
+
+    let dyn_iter: Box<dyn Iterator<Item = u8> + Send> = Box::new(std::iter::empty());
+    let _sem_ty_dyn = dyn_iter;
+    let raw: *const [u8; 2] = &[1, 2];
+    let _sem_ty_raw = raw;
+}
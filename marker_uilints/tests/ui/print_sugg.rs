@@ -0,0 +1,23 @@
+#![allow(unused)]
+
+macro_rules! add {
+    ($a:expr, $b:expr) => {
+        $a + $b
+    };
+}
+
+fn main() {
+    let a: i32 = 1;
+    let b: i32 = 2;
+    let x = &3;
+
+    let _sugg_path = a;
+    let _sugg_add = a + b;
+    let _sugg_cmp = a < b;
+    let _sugg_deref = *x;
+    let _sugg_cast = a as u8;
+    let _sugg_cast_sum = a + b as i32;
+    let _sugg_method = a.pow(2);
+    let _sugg_range = 0..10;
+    let _sugg_macro = add!(a, b);
+}
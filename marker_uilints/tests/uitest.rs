@@ -1,5 +1,9 @@
+use marker_adapter::SUPPRESSION_REPORT_ENV;
 use marker_uitest::ui_test::*;
-use std::{env, path::Path};
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
 
 fn main() -> color_eyre::Result<()> {
     let mut config = marker_uitest::simple_ui_test_config!("tests/ui", "../target")?;
@@ -22,12 +26,83 @@ fn main() -> color_eyre::Result<()> {
     }
 
     let test_name_filter = test_name_filter();
+    let check_report = test_name_filter(Path::new("suppression_report.rs"));
     run_tests_generic(
-        config,
+        config.clone(),
         move |path| default_file_filter(path) && test_name_filter(path),
         default_per_file_config,
         status_emitter::Text,
-    )
+    )?;
+
+    if check_report {
+        check_suppression_report(config)?;
+    }
+    Ok(())
+}
+
+/// Runs the tests in `tests/suppression_report` with the suppression report of
+/// `cargo marker allows` enabled and checks the JSON report written by the driver.
+fn check_suppression_report(mut config: Config) -> color_eyre::Result<()> {
+    let report_dir = tempfile::tempdir()?;
+    config.root_dir = PathBuf::from("tests/suppression_report");
+    config
+        .program
+        .envs
+        .push((SUPPRESSION_REPORT_ENV.into(), Some(report_dir.path().into())));
+    run_tests_generic(
+        config,
+        default_file_filter,
+        default_per_file_config,
+        status_emitter::Text,
+    )?;
+
+    let mut entries = vec![];
+    for file in std::fs::read_dir(report_dir.path())? {
+        let content = std::fs::read_to_string(file?.path())?;
+        let report: Vec<serde_json::Value> = serde_json::from_str(&content)?;
+        entries.extend(report);
+    }
+    entries.sort_by_key(|entry| entry["line"].as_u64());
+
+    let summary: Vec<_> = entries
+        .iter()
+        .map(|entry| {
+            (
+                entry["lint"].as_str().unwrap_or_default(),
+                entry["level"].as_str().unwrap_or_default(),
+                entry["item"].as_str().unwrap_or_default(),
+                entry["reason"].as_str(),
+                entry["used"].as_bool(),
+                entry["line"].as_u64(),
+                entry["column"].as_u64(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (
+                "marker::test_lint",
+                "allow",
+                "function `print_path_suppressed`",
+                Some("the lint is emitted for this function"),
+                Some(true),
+                Some(5),
+                Some(9)
+            ),
+            (
+                "marker::test_lint",
+                "allow",
+                "function `not_linted`",
+                None,
+                Some(false),
+                Some(8),
+                Some(9)
+            ),
+        ],
+        "unexpected suppression report: {entries:#?}"
+    );
+    Ok(())
 }
 
 // Gracefully stolen from Clippy, thank you!
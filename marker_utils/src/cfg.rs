@@ -0,0 +1,698 @@
+//! A control-flow graph (CFG) for function bodies.
+//!
+//! The [`Cfg`] splits the expression tree of a [`Body`] into [`BasicBlock`]s.
+//! Each block contains a list of [`CfgNode`]s, which are evaluated in order,
+//! followed by a [`Terminator`], which determines the successors of the block.
+//! Expressions are added after their operands, meaning that the nodes of a block
+//! are in evaluation order.
+//!
+//! ```ignore
+//! let cfg = Cfg::new(cx.body(body_id));
+//! for block in cfg.blocks() {
+//!     if !cfg.is_reachable(block.id()) {
+//!         // ...
+//!     }
+//! }
+//! ```
+//!
+//! Control flow is derived from `if`, `match`, `loop`, `while` and `for`
+//! expressions, labeled blocks, `break`, `continue`, `return`, the `?`
+//! operator, lazy boolean operators and `let ... else` statements. Function
+//! and method calls, which return [`!`](prim@never), are treated as diverging.
+//! `.await` expressions end a block with a [`Terminator::Await`], to make
+//! suspension points visible.
+//!
+//! Closures are represented by a single node, their bodies have their own CFG.
+//! The same applies to `async` blocks, which are only executed when the
+//! resulting future is polled. A `return`, `?` or `.await` inside them
+//! therefore doesn't affect the control flow of the enclosing body.
+
+use std::{collections::HashMap, fmt::Write};
+
+use marker_api::ast::{
+    expr::{BinaryOpKind, BlockExpr, ExprKind},
+    item::Body,
+    pat::PatKind,
+    stmt::{LetStmt, StmtKind},
+    ty::SemTyKind,
+    ExprId, Syncness,
+};
+
+/// The id of a [`BasicBlock`] in a [`Cfg`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BlockId(usize);
+
+impl BlockId {
+    /// The index of the block in [`Cfg::blocks`].
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A node in a [`BasicBlock`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum CfgNode<'ast> {
+    /// An expression, which is evaluated after its operands.
+    Expr(ExprKind<'ast>),
+    /// A `let` statement. The pattern is bound after the initializer has been
+    /// evaluated. Statements without initializer only declare the variables.
+    Let(&'ast LetStmt<'ast>),
+    /// A pattern, which binds the previously evaluated value. This is used for
+    /// `match` arms, `let` expressions and `for` loops. The expression is the
+    /// `match`, `let` or `for` expression, which contains the pattern.
+    Pat(PatKind<'ast>, ExprKind<'ast>),
+}
+
+/// The end of a [`BasicBlock`], which determines its successors.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Terminator<'ast> {
+    /// Continues with the given block.
+    Goto(BlockId),
+    /// Continues with one of the given blocks, depending on the value of the
+    /// expression. This is used for conditions, `match` scrutinees, `for` loops,
+    /// lazy boolean operators and the `?` operator. The order of the targets
+    /// follows the order of the source code. For conditions, the first target
+    /// is taken if the condition is `true`. For the `?` operator, the second
+    /// target returns from the body.
+    Switch(ExprKind<'ast>, Vec<BlockId>),
+    /// A suspension point of the given `.await` expression. The execution is
+    /// resumed in the given block.
+    Await(ExprKind<'ast>, BlockId),
+    /// Returns from the body, continues with [`Cfg::exit`].
+    Return,
+    /// The control flow diverges, for example, due to a call of a function
+    /// returning [`!`](prim@never).
+    Diverge,
+    /// The terminator of [`Cfg::exit`].
+    Exit,
+}
+
+impl<'ast> Terminator<'ast> {
+    fn successors(&self, exit: BlockId) -> Vec<BlockId> {
+        match self {
+            Terminator::Goto(target) | Terminator::Await(_, target) => vec![*target],
+            Terminator::Switch(_, targets) => targets.clone(),
+            Terminator::Return => vec![exit],
+            Terminator::Diverge | Terminator::Exit => vec![],
+        }
+    }
+}
+
+/// A sequence of [`CfgNode`]s, which are always evaluated together, followed
+/// by a [`Terminator`].
+#[derive(Debug)]
+pub struct BasicBlock<'ast> {
+    id: BlockId,
+    nodes: Vec<CfgNode<'ast>>,
+    terminator: Terminator<'ast>,
+}
+
+impl<'ast> BasicBlock<'ast> {
+    #[must_use]
+    pub fn id(&self) -> BlockId {
+        self.id
+    }
+
+    /// The nodes of this block, in evaluation order.
+    #[must_use]
+    pub fn nodes(&self) -> &[CfgNode<'ast>] {
+        &self.nodes
+    }
+
+    #[must_use]
+    pub fn terminator(&self) -> &Terminator<'ast> {
+        &self.terminator
+    }
+}
+
+/// A control-flow graph of a [`Body`]. See the [module documentation](self)
+/// for more information.
+#[derive(Debug)]
+pub struct Cfg<'ast> {
+    blocks: Vec<BasicBlock<'ast>>,
+    successors: Vec<Vec<BlockId>>,
+    predecessors: Vec<Vec<BlockId>>,
+    reachable: Vec<bool>,
+    expr_locations: HashMap<ExprId, (BlockId, usize)>,
+}
+
+impl<'ast> Cfg<'ast> {
+    const ENTRY: BlockId = BlockId(0);
+    const EXIT: BlockId = BlockId(1);
+
+    /// Builds the CFG of the given body.
+    #[must_use]
+    pub fn new(body: &Body<'ast>) -> Self {
+        let mut builder = CfgBuilder::new();
+        match body.expr() {
+            // The body of an `async fn` is an async block, which is the code of
+            // this body.
+            expr @ ExprKind::Block(block) => {
+                builder.lower_block(expr, block);
+                builder.push(CfgNode::Expr(expr));
+            },
+            expr => builder.lower_expr(expr),
+        }
+        builder.terminate(Terminator::Return);
+        builder.blocks[Self::EXIT.0].terminator = Some(Terminator::Exit);
+
+        let blocks: Vec<_> = builder
+            .blocks
+            .into_iter()
+            .enumerate()
+            .map(|(index, block)| BasicBlock {
+                id: BlockId(index),
+                nodes: block.nodes,
+                // Blocks without terminator are only created after diverging
+                // expressions and are therefore unreachable.
+                terminator: block.terminator.unwrap_or(Terminator::Diverge),
+            })
+            .collect();
+
+        let successors: Vec<_> = blocks
+            .iter()
+            .map(|block| block.terminator.successors(Self::EXIT))
+            .collect();
+        let mut predecessors = vec![vec![]; blocks.len()];
+        for (from, targets) in successors.iter().enumerate() {
+            for target in targets {
+                predecessors[target.0].push(BlockId(from));
+            }
+        }
+
+        let mut reachable = vec![false; blocks.len()];
+        let mut stack = vec![Self::ENTRY];
+        while let Some(block) = stack.pop() {
+            if !reachable[block.0] {
+                reachable[block.0] = true;
+                stack.extend(&successors[block.0]);
+            }
+        }
+
+        Self {
+            blocks,
+            successors,
+            predecessors,
+            reachable,
+            expr_locations: builder.expr_locations,
+        }
+    }
+
+    /// All blocks of this CFG, indexed by [`BlockId::index`].
+    #[must_use]
+    pub fn blocks(&self) -> &[BasicBlock<'ast>] {
+        &self.blocks
+    }
+
+    #[must_use]
+    pub fn block(&self, id: BlockId) -> &BasicBlock<'ast> {
+        &self.blocks[id.0]
+    }
+
+    /// The block, where the execution of the body starts.
+    #[must_use]
+    pub fn entry(&self) -> BlockId {
+        Self::ENTRY
+    }
+
+    /// The block, which is reached when the body returns. This block has no
+    /// nodes and a [`Terminator::Exit`].
+    #[must_use]
+    pub fn exit(&self) -> BlockId {
+        Self::EXIT
+    }
+
+    #[must_use]
+    pub fn successors(&self, id: BlockId) -> &[BlockId] {
+        &self.successors[id.0]
+    }
+
+    #[must_use]
+    pub fn predecessors(&self, id: BlockId) -> &[BlockId] {
+        &self.predecessors[id.0]
+    }
+
+    /// Returns `true`, if the given block can be reached from the entry block.
+    #[must_use]
+    pub fn is_reachable(&self, id: BlockId) -> bool {
+        self.reachable[id.0]
+    }
+
+    /// Returns the block, which contains the [`CfgNode::Expr`] of the given
+    /// expression. This returns `None` for expressions which are not part
+    /// of this CFG, like expressions inside closures.
+    #[must_use]
+    pub fn block_of(&self, expr: ExprId) -> Option<BlockId> {
+        self.location_of(expr).map(|(block, _)| block)
+    }
+
+    /// Returns the block and the index in [`BasicBlock::nodes`] of the
+    /// [`CfgNode::Expr`] of the given expression.
+    #[must_use]
+    pub fn location_of(&self, expr: ExprId) -> Option<(BlockId, usize)> {
+        self.expr_locations.get(&expr).copied()
+    }
+
+    /// Returns `true`, if the given expression can be reached from the entry
+    /// block.
+    #[must_use]
+    pub fn is_expr_reachable(&self, expr: ExprId) -> bool {
+        self.block_of(expr).is_some_and(|block| self.is_reachable(block))
+    }
+
+    /// Renders this CFG in the [DOT] format, which can be used to visualize
+    /// the graph during debugging. The format of the output is unstable.
+    ///
+    /// [DOT]: https://graphviz.org/doc/info/lang.html
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph cfg {\n    node [shape=box];\n");
+        for block in &self.blocks {
+            let mut label = format!("bb{}", block.id.0);
+            for node in &block.nodes {
+                label.push_str("\\l");
+                label.push_str(&escape_dot(&node_label(*node)));
+            }
+            let _ = write!(label, "\\l{}\\l", terminator_label(&block.terminator));
+            let style = if self.is_reachable(block.id) {
+                ""
+            } else {
+                ", style=dashed"
+            };
+            let _ = writeln!(dot, "    bb{} [label=\"{label}\"{style}];", block.id.0);
+            for (index, target) in self.successors(block.id).iter().enumerate() {
+                if matches!(block.terminator, Terminator::Switch(..)) {
+                    let _ = writeln!(dot, "    bb{} -> bb{} [label=\"{index}\"];", block.id.0, target.0);
+                } else {
+                    let _ = writeln!(dot, "    bb{} -> bb{};", block.id.0, target.0);
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn node_label(node: CfgNode<'_>) -> String {
+    match node {
+        CfgNode::Expr(expr) => {
+            let name = match expr {
+                ExprKind::Block(_) => "block",
+                ExprKind::If(_) => "if",
+                ExprKind::Match(_) => "match",
+                ExprKind::Loop(_) => "loop",
+                ExprKind::While(_) => "while",
+                ExprKind::For(_) => "for",
+                ExprKind::Closure(_) => "closure",
+                _ => return short_snippet(expr.span().snippet()),
+            };
+            format!("<{name}>")
+        },
+        CfgNode::Let(stmt) => format!("let {}", short_snippet(stmt.pat().span().snippet())),
+        CfgNode::Pat(pat, _) => format!("bind {}", short_snippet(pat.span().snippet())),
+    }
+}
+
+fn terminator_label(terminator: &Terminator<'_>) -> String {
+    match terminator {
+        Terminator::Goto(_) => "goto".to_string(),
+        Terminator::Switch(expr, _) => format!("switch {}", escape_dot(&short_snippet(expr.span().snippet()))),
+        Terminator::Await(..) => "await".to_string(),
+        Terminator::Return => "return".to_string(),
+        Terminator::Diverge => "diverge".to_string(),
+        Terminator::Exit => "exit".to_string(),
+    }
+}
+
+fn short_snippet(snippet: Option<&str>) -> String {
+    const MAX_LEN: usize = 30;
+    let snippet = snippet.unwrap_or("..").split_whitespace().collect::<Vec<_>>().join(" ");
+    if snippet.chars().count() > MAX_LEN {
+        let mut short: String = snippet.chars().take(MAX_LEN).collect();
+        short.push_str("..");
+        short
+    } else {
+        snippet
+    }
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[derive(Default)]
+struct BlockData<'ast> {
+    nodes: Vec<CfgNode<'ast>>,
+    terminator: Option<Terminator<'ast>>,
+}
+
+/// A scope, which can be targeted by `break` and `continue` expressions.
+struct BreakScope {
+    id: ExprId,
+    break_target: BlockId,
+    continue_target: Option<BlockId>,
+}
+
+struct CfgBuilder<'ast> {
+    blocks: Vec<BlockData<'ast>>,
+    current: BlockId,
+    scopes: Vec<BreakScope>,
+    expr_locations: HashMap<ExprId, (BlockId, usize)>,
+}
+
+impl<'ast> CfgBuilder<'ast> {
+    fn new() -> Self {
+        let mut builder = Self {
+            blocks: vec![],
+            current: Cfg::ENTRY,
+            scopes: vec![],
+            expr_locations: HashMap::new(),
+        };
+        let entry = builder.new_block();
+        let exit = builder.new_block();
+        debug_assert_eq!(entry, Cfg::ENTRY);
+        debug_assert_eq!(exit, Cfg::EXIT);
+        builder
+    }
+
+    fn new_block(&mut self) -> BlockId {
+        self.blocks.push(BlockData::default());
+        BlockId(self.blocks.len() - 1)
+    }
+
+    fn push(&mut self, node: CfgNode<'ast>) {
+        if let CfgNode::Expr(expr) = node {
+            let index = self.blocks[self.current.0].nodes.len();
+            self.expr_locations.insert(expr.id(), (self.current, index));
+        }
+        self.blocks[self.current.0].nodes.push(node);
+    }
+
+    /// Terminates the current block. The following nodes are added to a new
+    /// block, which is unreachable, unless it's targeted by another terminator.
+    fn terminate(&mut self, terminator: Terminator<'ast>) {
+        self.blocks[self.current.0].terminator = Some(terminator);
+        self.current = self.new_block();
+    }
+
+    /// Terminates the current block, with a jump to the given block and
+    /// continues with it.
+    fn goto(&mut self, target: BlockId) {
+        self.blocks[self.current.0].terminator = Some(Terminator::Goto(target));
+        self.current = target;
+    }
+
+    fn lower_stmt(&mut self, stmt: StmtKind<'ast>) {
+        match stmt {
+            StmtKind::Let(stmt) => {
+                if let Some(init) = stmt.init() {
+                    self.lower_expr(init);
+                    if let Some(els) = stmt.els() {
+                        let then_block = self.new_block();
+                        let else_block = self.new_block();
+                        self.blocks[self.current.0].terminator =
+                            Some(Terminator::Switch(init, vec![then_block, else_block]));
+                        self.current = else_block;
+                        self.lower_expr(els);
+                        self.terminate(Terminator::Diverge);
+                        self.current = then_block;
+                    }
+                }
+                self.push(CfgNode::Let(stmt));
+            },
+            StmtKind::Expr(expr, _) => self.lower_expr(*expr),
+            // Items have their own bodies
+            _ => {},
+        }
+    }
+
+    fn lower_exprs(&mut self, exprs: &[ExprKind<'ast>]) {
+        for expr in exprs {
+            self.lower_expr(*expr);
+        }
+    }
+
+    fn lower_block(&mut self, expr: ExprKind<'ast>, block: &'ast BlockExpr<'ast>) {
+        let scope = block.label().map(|_| self.enter_scope(expr.id(), None));
+        for stmt in block.stmts() {
+            self.lower_stmt(*stmt);
+        }
+        if let Some(value) = block.expr() {
+            self.lower_expr(value);
+        }
+        if let Some(after) = scope {
+            self.scopes.pop();
+            self.goto(after);
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn lower_expr(&mut self, expr: ExprKind<'ast>) {
+        match expr {
+            // Async blocks are evaluated to a future, their code is only executed
+            // when the future is polled.
+            ExprKind::Block(block) if matches!(block.syncness(), Syncness::Async) => {},
+            ExprKind::Block(block) => self.lower_block(expr, block),
+            ExprKind::UnaryOp(e) => self.lower_expr(e.expr()),
+            ExprKind::Ref(e) => self.lower_expr(e.expr()),
+            ExprKind::As(e) => self.lower_expr(e.expr()),
+            ExprKind::Field(e) => self.lower_expr(e.operand()),
+            ExprKind::Assign(e) => {
+                // The assigned value is evaluated first, unless this is a compound
+                // assignment with a non-primitive value, which calls an operator trait.
+                let primitive = matches!(
+                    e.value().ty(),
+                    SemTyKind::Bool(_) | SemTyKind::Num(_) | SemTyKind::Text(_)
+                );
+                if e.op().is_some() && !primitive {
+                    self.lower_assignee(e.assignee());
+                    self.lower_expr(e.value());
+                } else {
+                    self.lower_expr(e.value());
+                    self.lower_assignee(e.assignee());
+                }
+            },
+            ExprKind::BinaryOp(e) if matches!(e.kind(), BinaryOpKind::And | BinaryOpKind::Or) => {
+                self.lower_expr(e.left());
+                let right_block = self.new_block();
+                let join = self.new_block();
+                let targets = if matches!(e.kind(), BinaryOpKind::And) {
+                    vec![right_block, join]
+                } else {
+                    vec![join, right_block]
+                };
+                self.blocks[self.current.0].terminator = Some(Terminator::Switch(e.left(), targets));
+                self.current = right_block;
+                self.lower_expr(e.right());
+                self.goto(join);
+            },
+            ExprKind::BinaryOp(e) => {
+                self.lower_expr(e.left());
+                self.lower_expr(e.right());
+            },
+            ExprKind::Index(e) => {
+                self.lower_expr(e.operand());
+                self.lower_expr(e.index());
+            },
+            ExprKind::Call(e) => {
+                self.lower_expr(e.operand());
+                self.lower_exprs(e.args());
+            },
+            ExprKind::Method(e) => {
+                self.lower_expr(e.receiver());
+                self.lower_exprs(e.args());
+            },
+            ExprKind::Array(e) => self.lower_exprs(e.elements()),
+            ExprKind::Tuple(e) => self.lower_exprs(e.elements()),
+            ExprKind::Ctor(e) => {
+                for field in e.fields() {
+                    self.lower_expr(field.expr());
+                }
+                if let Some(base) = e.base() {
+                    self.lower_expr(base);
+                }
+            },
+            ExprKind::Range(e) => {
+                if let Some(start) = e.start() {
+                    self.lower_expr(start);
+                }
+                if let Some(end) = e.end() {
+                    self.lower_expr(end);
+                }
+            },
+            ExprKind::Let(e) => {
+                self.lower_expr(e.scrutinee());
+                self.push(CfgNode::Expr(expr));
+                self.push(CfgNode::Pat(e.pat(), expr));
+                return;
+            },
+            ExprKind::QuestionMark(e) => {
+                self.lower_expr(e.expr());
+                self.push(CfgNode::Expr(expr));
+                let cont = self.new_block();
+                let ret = self.new_block();
+                self.blocks[self.current.0].terminator = Some(Terminator::Switch(expr, vec![cont, ret]));
+                self.current = ret;
+                self.terminate(Terminator::Return);
+                self.current = cont;
+                return;
+            },
+            ExprKind::Await(e) => {
+                self.lower_expr(e.expr());
+                self.push(CfgNode::Expr(expr));
+                let resume = self.new_block();
+                self.blocks[self.current.0].terminator = Some(Terminator::Await(expr, resume));
+                self.current = resume;
+                return;
+            },
+            ExprKind::If(e) => {
+                self.lower_expr(e.condition());
+                let then_block = self.new_block();
+                let else_block = self.new_block();
+                let join = self.new_block();
+                self.blocks[self.current.0].terminator =
+                    Some(Terminator::Switch(e.condition(), vec![then_block, else_block]));
+                self.current = then_block;
+                self.lower_expr(e.then());
+                self.goto(join);
+                self.current = else_block;
+                if let Some(els) = e.els() {
+                    self.lower_expr(els);
+                }
+                self.goto(join);
+            },
+            ExprKind::Match(e) => {
+                self.lower_expr(e.scrutinee());
+                let arm_blocks: Vec<_> = e.arms().iter().map(|_| self.new_block()).collect();
+                let join = self.new_block();
+                self.blocks[self.current.0].terminator = Some(Terminator::Switch(e.scrutinee(), arm_blocks.clone()));
+                for (index, arm) in e.arms().iter().enumerate() {
+                    self.current = arm_blocks[index];
+                    self.push(CfgNode::Pat(arm.pat(), expr));
+                    if let Some(guard) = arm.guard() {
+                        self.lower_expr(guard);
+                        let body_block = self.new_block();
+                        // A failing guard continues with the next arm
+                        let mut targets = vec![body_block];
+                        targets.extend(arm_blocks.get(index + 1));
+                        self.blocks[self.current.0].terminator = Some(Terminator::Switch(guard, targets));
+                        self.current = body_block;
+                    }
+                    self.lower_expr(arm.expr());
+                    self.goto(join);
+                }
+                self.current = join;
+            },
+            ExprKind::Loop(e) => {
+                let header = self.new_block();
+                self.goto(header);
+                let after = self.enter_scope(expr.id(), Some(header));
+                self.lower_expr(e.block());
+                self.scopes.pop();
+                self.goto(header);
+                self.current = after;
+            },
+            ExprKind::While(e) => {
+                let header = self.new_block();
+                self.goto(header);
+                let after = self.enter_scope(expr.id(), Some(header));
+                self.lower_expr(e.condition());
+                let body_block = self.new_block();
+                self.blocks[self.current.0].terminator =
+                    Some(Terminator::Switch(e.condition(), vec![body_block, after]));
+                self.current = body_block;
+                self.lower_expr(e.block());
+                self.scopes.pop();
+                self.goto(header);
+                self.current = after;
+            },
+            ExprKind::For(e) => {
+                self.lower_expr(e.iterable());
+                let header = self.new_block();
+                self.goto(header);
+                let after = self.enter_scope(expr.id(), Some(header));
+                let body_block = self.new_block();
+                self.blocks[self.current.0].terminator = Some(Terminator::Switch(expr, vec![body_block, after]));
+                self.current = body_block;
+                self.push(CfgNode::Pat(e.pat(), expr));
+                self.lower_expr(e.block());
+                self.scopes.pop();
+                self.goto(header);
+                self.current = after;
+            },
+            ExprKind::Break(e) => {
+                if let Some(value) = e.expr() {
+                    self.lower_expr(value);
+                }
+                self.push(CfgNode::Expr(expr));
+                let target = self.scope(e.target_id()).map(|scope| scope.break_target);
+                self.terminate(target.map_or(Terminator::Diverge, Terminator::Goto));
+                return;
+            },
+            ExprKind::Continue(e) => {
+                self.push(CfgNode::Expr(expr));
+                let target = self.scope(e.target_id()).and_then(|scope| scope.continue_target);
+                self.terminate(target.map_or(Terminator::Diverge, Terminator::Goto));
+                return;
+            },
+            ExprKind::Return(e) => {
+                if let Some(value) = e.expr() {
+                    self.lower_expr(value);
+                }
+                self.push(CfgNode::Expr(expr));
+                self.terminate(Terminator::Return);
+                return;
+            },
+            // These expressions have no operands, which are evaluated as part
+            // of this body. Closures have their own body.
+            _ => {},
+        }
+
+        self.push(CfgNode::Expr(expr));
+
+        if matches!(expr, ExprKind::Call(_) | ExprKind::Method(_) | ExprKind::Unstable(_))
+            && matches!(expr.ty(), SemTyKind::Never(_))
+        {
+            self.terminate(Terminator::Diverge);
+        }
+    }
+
+    /// Lowers the operands of the place expressions in the assignee of an
+    /// assignment, like `f()` in `a[f()] = x`. Assigned variables are not read.
+    fn lower_assignee(&mut self, pat: PatKind<'ast>) {
+        match pat {
+            PatKind::Place(place, _) => self.lower_place(place),
+            PatKind::Struct(pat) => pat.fields().iter().for_each(|field| self.lower_assignee(field.pat())),
+            PatKind::Tuple(pat) => pat.elements().iter().for_each(|pat| self.lower_assignee(*pat)),
+            PatKind::Slice(pat) => pat.elements().iter().for_each(|pat| self.lower_assignee(*pat)),
+            _ => {},
+        }
+    }
+
+    fn lower_place(&mut self, place: ExprKind<'ast>) {
+        match place {
+            ExprKind::Path(_) => {},
+            ExprKind::Field(e) => self.lower_place(e.operand()),
+            _ => self.lower_expr(place),
+        }
+    }
+
+    /// Enters a scope, which can be targeted by `break` and `continue`. The
+    /// returned block is the target of `break` expressions.
+    fn enter_scope(&mut self, id: ExprId, continue_target: Option<BlockId>) -> BlockId {
+        let break_target = self.new_block();
+        self.scopes.push(BreakScope {
+            id,
+            break_target,
+            continue_target,
+        });
+        break_target
+    }
+
+    fn scope(&self, id: ExprId) -> Option<&BreakScope> {
+        self.scopes.iter().rev().find(|scope| scope.id == id)
+    }
+}
@@ -0,0 +1,595 @@
+//! A generic dataflow framework on top of the [`Cfg`].
+//!
+//! An [`Analysis`] defines a state, which is propagated through the CFG, until
+//! a fixpoint is reached. The [`solve`] function computes the state at the
+//! start and end of every [`BasicBlock`]. The state
+//! at a specific node can then be retrieved from the [`Results`].
+//!
+//! This module provides the following analyses, which track local variables by
+//! their [`VarId`]:
+//! * [`Liveness`]: Variables, which might be read later on.
+//! * [`ReachingDefinitions`]: Definitions, which might have assigned the current value of a
+//!   variable.
+//! * [`MaybeUninit`]: Variables, which might be uninitialized or moved-out.
+//!
+//! ```ignore
+//! let cfg = Cfg::new(cx.body(body_id));
+//! let liveness = solve(&cfg, Liveness::new(cx));
+//! if let Some(live) = liveness.state_after_expr(&cfg, assign.id())
+//!     && !live.contains(&var)
+//! {
+//!     // The assigned value is never read
+//! }
+//! ```
+
+use std::{collections::BTreeSet, ops::ControlFlow};
+
+use marker_api::{
+    ast::{
+        expr::{AdjustmentKind, CtorField, ExprData, ExprKind},
+        pat::PatKind,
+        AstPathTarget, ExprId, StmtId, VarId,
+    },
+    AstContext,
+};
+
+use crate::{
+    cfg::{BasicBlock, BlockId, Cfg, CfgNode},
+    visitor::{self, Visitor},
+};
+
+/// The direction, in which an [`Analysis`] propagates its state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Direction {
+    /// The state is propagated in evaluation order, from the entry to the exit
+    /// of the body.
+    Forward,
+    /// The state is propagated against the evaluation order, from the exit to
+    /// the entry of the body.
+    Backward,
+}
+
+/// A dataflow analysis, which can be solved with [`solve`].
+///
+/// The state of a block is the [`join`](Analysis::join) of the states of its
+/// predecessors, or successors for [backward](Direction::Backward) analyses.
+/// The state is then updated by the [`transfer`](Analysis::transfer) function,
+/// for each node of the block. The analysis has to be monotone, to ensure that
+/// a fixpoint is reached.
+pub trait Analysis<'ast> {
+    type Domain: Clone + PartialEq;
+
+    const DIRECTION: Direction;
+
+    /// The state at the entry of the body, or the exit for
+    /// [backward](Direction::Backward) analyses.
+    fn boundary_state(&self, cfg: &Cfg<'ast>) -> Self::Domain;
+
+    /// The initial state of all other blocks. This should be the identity of
+    /// the [`join`](Analysis::join) function.
+    fn bottom(&self, cfg: &Cfg<'ast>) -> Self::Domain;
+
+    /// Merges the `other` state into `state`.
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain);
+
+    /// Updates the state with the effect of the given node. For
+    /// [backward](Direction::Backward) analyses, the state after the node
+    /// is transformed into the state before the node.
+    fn transfer(&self, state: &mut Self::Domain, node: CfgNode<'ast>);
+}
+
+/// The result of an [`Analysis`], created by [`solve`].
+#[derive(Debug)]
+pub struct Results<'ast, A: Analysis<'ast>> {
+    analysis: A,
+    entry_states: Vec<A::Domain>,
+    exit_states: Vec<A::Domain>,
+}
+
+impl<'ast, A: Analysis<'ast>> Results<'ast, A> {
+    pub fn analysis(&self) -> &A {
+        &self.analysis
+    }
+
+    /// The state before the first node of the given block.
+    pub fn entry_state(&self, block: BlockId) -> &A::Domain {
+        &self.entry_states[block.index()]
+    }
+
+    /// The state after the last node of the given block.
+    pub fn exit_state(&self, block: BlockId) -> &A::Domain {
+        &self.exit_states[block.index()]
+    }
+
+    /// The state before the node at the given index of the given block.
+    pub fn state_before(&self, cfg: &Cfg<'ast>, block: BlockId, index: usize) -> A::Domain {
+        let nodes = cfg.block(block).nodes();
+        match A::DIRECTION {
+            Direction::Forward => {
+                let mut state = self.entry_state(block).clone();
+                for node in &nodes[..index] {
+                    self.analysis.transfer(&mut state, *node);
+                }
+                state
+            },
+            Direction::Backward => {
+                let mut state = self.exit_state(block).clone();
+                for node in nodes[index..].iter().rev() {
+                    self.analysis.transfer(&mut state, *node);
+                }
+                state
+            },
+        }
+    }
+
+    /// The state after the node at the given index of the given block.
+    pub fn state_after(&self, cfg: &Cfg<'ast>, block: BlockId, index: usize) -> A::Domain {
+        self.state_before(cfg, block, index + 1)
+    }
+
+    /// The state before the [`CfgNode::Expr`] of the given expression. This
+    /// returns `None`, if the expression is not part of the CFG.
+    pub fn state_before_expr(&self, cfg: &Cfg<'ast>, expr: ExprId) -> Option<A::Domain> {
+        let (block, index) = cfg.location_of(expr)?;
+        Some(self.state_before(cfg, block, index))
+    }
+
+    /// The state after the [`CfgNode::Expr`] of the given expression. This
+    /// returns `None`, if the expression is not part of the CFG.
+    pub fn state_after_expr(&self, cfg: &Cfg<'ast>, expr: ExprId) -> Option<A::Domain> {
+        let (block, index) = cfg.location_of(expr)?;
+        Some(self.state_after(cfg, block, index))
+    }
+}
+
+/// Runs the given analysis on the CFG, until a fixpoint is reached.
+pub fn solve<'ast, A: Analysis<'ast>>(cfg: &Cfg<'ast>, analysis: A) -> Results<'ast, A> {
+    let len = cfg.blocks().len();
+    let mut entry_states = vec![analysis.bottom(cfg); len];
+    let mut exit_states = vec![analysis.bottom(cfg); len];
+
+    let mut worklist: Vec<BlockId> = cfg.blocks().iter().map(BasicBlock::id).collect();
+    let mut queued = vec![true; len];
+    // The worklist is used as a stack. Reversing it for forward analyses,
+    // processes the blocks roughly in evaluation order.
+    if A::DIRECTION == Direction::Forward {
+        worklist.reverse();
+    }
+
+    while let Some(block) = worklist.pop() {
+        queued[block.index()] = false;
+        let nodes = cfg.block(block).nodes();
+        match A::DIRECTION {
+            Direction::Forward => {
+                let mut state = if block == cfg.entry() {
+                    analysis.boundary_state(cfg)
+                } else {
+                    analysis.bottom(cfg)
+                };
+                for pred in cfg.predecessors(block) {
+                    analysis.join(&mut state, &exit_states[pred.index()]);
+                }
+                entry_states[block.index()] = state.clone();
+                for node in nodes {
+                    analysis.transfer(&mut state, *node);
+                }
+                if state != exit_states[block.index()] {
+                    exit_states[block.index()] = state;
+                    for succ in cfg.successors(block) {
+                        if !queued[succ.index()] {
+                            queued[succ.index()] = true;
+                            worklist.push(*succ);
+                        }
+                    }
+                }
+            },
+            Direction::Backward => {
+                let mut state = if block == cfg.exit() {
+                    analysis.boundary_state(cfg)
+                } else {
+                    analysis.bottom(cfg)
+                };
+                for succ in cfg.successors(block) {
+                    analysis.join(&mut state, &entry_states[succ.index()]);
+                }
+                exit_states[block.index()] = state.clone();
+                for node in nodes.iter().rev() {
+                    analysis.transfer(&mut state, *node);
+                }
+                if state != entry_states[block.index()] {
+                    entry_states[block.index()] = state;
+                    for pred in cfg.predecessors(block) {
+                        if !queued[pred.index()] {
+                            queued[pred.index()] = true;
+                            worklist.push(*pred);
+                        }
+                    }
+                }
+            },
+        }
+    }
+
+    Results {
+        analysis,
+        entry_states,
+        exit_states,
+    }
+}
+
+/// A backward analysis of the variables, which might be read, before they're
+/// assigned again.
+///
+/// Variables are read by [`PathExpr`](marker_api::ast::expr::PathExpr)s,
+/// including paths in closures, compound assignments like `x += 1` and
+/// assignments to fields or indices of the variable. They're killed by `let`
+/// statements, patterns and assignments to the variable.
+#[derive(Debug)]
+pub struct Liveness<'ast> {
+    cx: &'ast AstContext<'ast>,
+}
+
+impl<'ast> Liveness<'ast> {
+    #[must_use]
+    pub fn new(cx: &'ast AstContext<'ast>) -> Self {
+        Self { cx }
+    }
+}
+
+impl<'ast> Analysis<'ast> for Liveness<'ast> {
+    type Domain = BTreeSet<VarId>;
+
+    const DIRECTION: Direction = Direction::Backward;
+
+    fn boundary_state(&self, _cfg: &Cfg<'ast>) -> Self::Domain {
+        BTreeSet::new()
+    }
+
+    fn bottom(&self, _cfg: &Cfg<'ast>) -> Self::Domain {
+        BTreeSet::new()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.extend(other);
+    }
+
+    fn transfer(&self, state: &mut Self::Domain, node: CfgNode<'ast>) {
+        match node {
+            CfgNode::Expr(ExprKind::Assign(assign)) => {
+                let mut targets = AssignTargets::default();
+                targets.collect(self.cx, assign.assignee());
+                for var in &targets.defs {
+                    state.remove(var);
+                }
+                if assign.op().is_some() {
+                    state.extend(&targets.defs);
+                }
+                state.extend(targets.uses);
+            },
+            CfgNode::Expr(ExprKind::Closure(closure)) => {
+                let mut uses = VarUses::default();
+                let _ = visitor::traverse_body(self.cx, &mut uses, self.cx.body(closure.body_id()));
+                state.extend(uses.vars);
+            },
+            CfgNode::Expr(expr) => {
+                if let Some(var) = var_of(expr) {
+                    state.insert(var);
+                }
+            },
+            CfgNode::Let(stmt) => {
+                for var in bound_vars(stmt.pat()) {
+                    state.remove(&var);
+                }
+            },
+            CfgNode::Pat(pat, _) => {
+                for var in bound_vars(pat) {
+                    state.remove(&var);
+                }
+            },
+        }
+    }
+}
+
+/// The location, where a variable is assigned a value. See [`Definition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum DefSite {
+    /// A `let` statement with an initializer.
+    Let(StmtId),
+    /// An [`AssignExpr`](marker_api::ast::expr::AssignExpr).
+    Assign(ExprId),
+    /// A pattern of a `match`, `let` or `for` expression. The id belongs to
+    /// that expression.
+    Pat(ExprId),
+}
+
+/// An assignment of a value to a variable, tracked by [`ReachingDefinitions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Definition {
+    var: VarId,
+    site: DefSite,
+}
+
+impl Definition {
+    #[must_use]
+    pub fn var(&self) -> VarId {
+        self.var
+    }
+
+    #[must_use]
+    pub fn site(&self) -> DefSite {
+        self.site
+    }
+}
+
+/// A forward analysis of the [`Definition`]s, which might have assigned the
+/// current value of a variable.
+///
+/// Variables, which are declared outside the body, like function parameters,
+/// have no definition.
+#[derive(Debug, Default)]
+pub struct ReachingDefinitions;
+
+impl ReachingDefinitions {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'ast> Analysis<'ast> for ReachingDefinitions {
+    type Domain = BTreeSet<Definition>;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn boundary_state(&self, _cfg: &Cfg<'ast>) -> Self::Domain {
+        BTreeSet::new()
+    }
+
+    fn bottom(&self, _cfg: &Cfg<'ast>) -> Self::Domain {
+        BTreeSet::new()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.extend(other);
+    }
+
+    fn transfer(&self, state: &mut Self::Domain, node: CfgNode<'ast>) {
+        let (vars, site) = match node {
+            CfgNode::Expr(ExprKind::Assign(assign)) => {
+                let mut targets = AssignTargets::default();
+                targets.collect_defs(assign.assignee());
+                (targets.defs, DefSite::Assign(assign.id()))
+            },
+            CfgNode::Let(stmt) if stmt.init().is_some() => (bound_vars(stmt.pat()), DefSite::Let(stmt.id())),
+            CfgNode::Pat(pat, owner) => (bound_vars(pat), DefSite::Pat(owner.id())),
+            _ => return,
+        };
+        state.retain(|def| !vars.contains(&def.var));
+        state.extend(vars.into_iter().map(|var| Definition { var, site }));
+    }
+}
+
+/// A forward analysis of the variables, which might be uninitialized or
+/// moved-out.
+///
+/// Variables are uninitialized after `let` statements without initializer.
+/// They become initialized by assignments and patterns. Marker doesn't know
+/// which types implement `Copy`, moves are therefore only tracked, if a
+/// predicate is provided with [`MaybeUninit::with_moves`].
+pub struct MaybeUninit<'ast> {
+    #[allow(clippy::type_complexity)]
+    moves: Option<(&'ast AstContext<'ast>, Box<dyn Fn(ExprKind<'ast>) -> bool + 'ast>)>,
+}
+
+impl<'ast> std::fmt::Debug for MaybeUninit<'ast> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MaybeUninit").finish_non_exhaustive()
+    }
+}
+
+impl<'ast> Default for MaybeUninit<'ast> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'ast> MaybeUninit<'ast> {
+    /// Creates an analysis, which only tracks the initialization of variables.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { moves: None }
+    }
+
+    /// Creates an analysis, which also tracks moves. The predicate is called
+    /// with [`PathExpr`](marker_api::ast::expr::PathExpr)s to variables, which
+    /// are used by value. It should return `true`, if the variable is moved.
+    /// This is the case, if the type of the expression doesn't implement `Copy`.
+    ///
+    /// Variables are used by value as arguments of calls and method calls, as
+    /// values of assignments, `let` statements, `return` and `break`
+    /// expressions and as elements of arrays, tuples and constructors. Method
+    /// receivers are used by value, if they are not
+    /// [auto-borrowed](marker_api::ast::expr::AdjustmentKind::Borrow).
+    pub fn with_moves(cx: &'ast AstContext<'ast>, is_move: impl Fn(ExprKind<'ast>) -> bool + 'ast) -> Self {
+        Self {
+            moves: Some((cx, Box::new(is_move))),
+        }
+    }
+
+    /// Returns `true`, if the method receiver is used by value, meaning that
+    /// it's neither auto-borrowed nor dereferenced.
+    fn is_by_value_receiver(&self, receiver: ExprKind<'ast>) -> bool {
+        self.moves.as_ref().is_some_and(|(cx, _)| {
+            cx.expr_adjustments(receiver.id()).iter().all(|adj| {
+                !matches!(
+                    adj.kind(),
+                    AdjustmentKind::Borrow(_) | AdjustmentKind::RawBorrow(_) | AdjustmentKind::Deref
+                )
+            })
+        })
+    }
+
+    fn move_operands(&self, state: &mut BTreeSet<VarId>, operands: impl IntoIterator<Item = ExprKind<'ast>>) {
+        if let Some((_, is_move)) = &self.moves {
+            for operand in operands {
+                if let Some(var) = var_of(operand) {
+                    if is_move(operand) {
+                        state.insert(var);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'ast> Analysis<'ast> for MaybeUninit<'ast> {
+    type Domain = BTreeSet<VarId>;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn boundary_state(&self, _cfg: &Cfg<'ast>) -> Self::Domain {
+        BTreeSet::new()
+    }
+
+    fn bottom(&self, _cfg: &Cfg<'ast>) -> Self::Domain {
+        BTreeSet::new()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.extend(other);
+    }
+
+    fn transfer(&self, state: &mut Self::Domain, node: CfgNode<'ast>) {
+        match node {
+            CfgNode::Expr(expr) => match expr {
+                ExprKind::Call(call) => self.move_operands(state, call.args().iter().copied()),
+                ExprKind::Method(method) => {
+                    let receiver = method.receiver();
+                    if self.is_by_value_receiver(receiver) {
+                        self.move_operands(state, [receiver]);
+                    }
+                    self.move_operands(state, method.args().iter().copied());
+                },
+                ExprKind::Array(array) => self.move_operands(state, array.elements().iter().copied()),
+                ExprKind::Tuple(tuple) => self.move_operands(state, tuple.elements().iter().copied()),
+                ExprKind::Ctor(ctor) => {
+                    self.move_operands(state, ctor.fields().iter().map(CtorField::expr));
+                },
+                ExprKind::Return(ret) => self.move_operands(state, ret.expr()),
+                ExprKind::Break(brk) => self.move_operands(state, brk.expr()),
+                ExprKind::Assign(assign) => {
+                    self.move_operands(state, [assign.value()]);
+                    let mut targets = AssignTargets::default();
+                    targets.collect_defs(assign.assignee());
+                    for var in &targets.defs {
+                        state.remove(var);
+                    }
+                },
+                _ => {},
+            },
+            CfgNode::Let(stmt) => {
+                self.move_operands(state, stmt.init());
+                let vars = bound_vars(stmt.pat());
+                if stmt.init().is_some() {
+                    for var in &vars {
+                        state.remove(var);
+                    }
+                } else {
+                    state.extend(vars);
+                }
+            },
+            CfgNode::Pat(pat, _) => {
+                for var in bound_vars(pat) {
+                    state.remove(&var);
+                }
+            },
+        }
+    }
+}
+
+/// Returns the variable, if the expression is a path to a local variable.
+fn var_of(expr: ExprKind<'_>) -> Option<VarId> {
+    match expr {
+        ExprKind::Path(path) => match path.path().resolve() {
+            AstPathTarget::Var(var) => Some(var),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns all variables, which are bound by the given pattern.
+#[must_use]
+pub fn bound_vars(pat: PatKind<'_>) -> Vec<VarId> {
+    fn collect(pat: PatKind<'_>, vars: &mut Vec<VarId>) {
+        match pat {
+            PatKind::Ident(ident) => {
+                if !vars.contains(&ident.var_id()) {
+                    vars.push(ident.var_id());
+                }
+                if let Some(binding) = ident.binding_pat() {
+                    collect(binding, vars);
+                }
+            },
+            PatKind::Ref(pat) => collect(pat.pattern(), vars),
+            PatKind::Struct(pat) => pat.fields().iter().for_each(|field| collect(field.pat(), vars)),
+            PatKind::Tuple(pat) => pat.elements().iter().for_each(|pat| collect(*pat, vars)),
+            PatKind::Slice(pat) => pat.elements().iter().for_each(|pat| collect(*pat, vars)),
+            PatKind::Or(pat) => pat.patterns().iter().for_each(|pat| collect(*pat, vars)),
+            _ => {},
+        }
+    }
+
+    let mut vars = vec![];
+    collect(pat, &mut vars);
+    vars
+}
+
+/// The variables, which are assigned or read by the assignee of an
+/// [`AssignExpr`](marker_api::ast::expr::AssignExpr).
+#[derive(Default)]
+struct AssignTargets {
+    defs: Vec<VarId>,
+    uses: Vec<VarId>,
+}
+
+impl AssignTargets {
+    fn collect<'ast>(&mut self, cx: &'ast AstContext<'ast>, pat: PatKind<'ast>) {
+        match pat {
+            PatKind::Place(expr, _) if var_of(expr).is_some() => self.defs.extend(var_of(expr)),
+            PatKind::Place(expr, _) => {
+                let mut uses = VarUses::default();
+                let _ = visitor::traverse_expr(cx, &mut uses, expr);
+                self.uses.extend(uses.vars);
+            },
+            PatKind::Struct(pat) => pat.fields().iter().for_each(|field| self.collect(cx, field.pat())),
+            PatKind::Tuple(pat) => pat.elements().iter().for_each(|pat| self.collect(cx, *pat)),
+            PatKind::Slice(pat) => pat.elements().iter().for_each(|pat| self.collect(cx, *pat)),
+            _ => self.defs.extend(bound_vars(pat)),
+        }
+    }
+
+    fn collect_defs(&mut self, pat: PatKind<'_>) {
+        match pat {
+            PatKind::Place(expr, _) => self.defs.extend(var_of(expr)),
+            PatKind::Struct(pat) => pat.fields().iter().for_each(|field| self.collect_defs(field.pat())),
+            PatKind::Tuple(pat) => pat.elements().iter().for_each(|pat| self.collect_defs(*pat)),
+            PatKind::Slice(pat) => pat.elements().iter().for_each(|pat| self.collect_defs(*pat)),
+            _ => self.defs.extend(bound_vars(pat)),
+        }
+    }
+}
+
+/// A visitor collecting all variables, which are referenced by paths.
+#[derive(Default)]
+struct VarUses {
+    vars: Vec<VarId>,
+}
+
+impl Visitor<()> for VarUses {
+    fn visit_expr<'ast>(&mut self, _cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) -> ControlFlow<()> {
+        self.vars.extend(var_of(expr));
+        ControlFlow::Continue(())
+    }
+}
@@ -5,4 +5,10 @@
 #![allow(clippy::unused_self)] // `self` is needed to potentualy change the behavior later
 #![allow(clippy::trivially_copy_pass_by_ref)] // Needed to potentualy change the behavior later
 
+pub mod cfg;
+pub mod dataflow;
+pub mod markdown;
+pub mod paths;
+pub mod pattern;
+pub mod sugg;
 pub mod visitor;
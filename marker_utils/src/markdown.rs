@@ -0,0 +1,482 @@
+//! A lightweight Markdown view for documentation.
+//!
+//! This module doesn't implement a full Markdown parser. It only extracts the
+//! elements, which are commonly checked by lints, like the headings of sections,
+//! code blocks and links. Every element stores the index of the line it starts
+//! on. This index can be used with [`Docs::lines()`] to get the [`Span`] of the
+//! element:
+//!
+//! ```ignore
+//! let docs = item.docs();
+//! let markdown = Markdown::new(docs);
+//! if !markdown.has_heading("Safety") {
+//!     // ...
+//! }
+//! for block in markdown.code_blocks() {
+//!     let span = docs.lines()[block.line()].span();
+//!     // ...
+//! }
+//! ```
+//!
+//! [`Span`]: marker_api::ast::Span
+
+use marker_api::ast::Docs;
+
+/// A Markdown view of documentation. See the [module documentation](self) for
+/// more information.
+#[derive(Debug)]
+pub struct Markdown<'a> {
+    headings: Vec<Heading<'a>>,
+    code_blocks: Vec<CodeBlock<'a>>,
+    links: Vec<Link<'a>>,
+}
+
+impl<'a> Markdown<'a> {
+    /// Creates a Markdown view of the given [`Docs`].
+    #[must_use]
+    pub fn new(docs: &Docs<'a>) -> Self {
+        Self::parse(docs.as_str())
+    }
+
+    /// Creates a Markdown view of the given text.
+    #[must_use]
+    pub fn parse(text: &'a str) -> Self {
+        let mut markdown = Self {
+            headings: vec![],
+            code_blocks: vec![],
+            links: vec![],
+        };
+
+        let mut definitions = vec![];
+        let mut fence: Option<Fence<'a>> = None;
+        let mut line_start = 0;
+        for (index, line) in text.split('\n').enumerate() {
+            let next_line_start = line_start + line.len() + 1;
+
+            if let Some(open) = &fence {
+                if open.is_closed_by(line) {
+                    let code = text
+                        .get(open.code_start..line_start.saturating_sub(1))
+                        .unwrap_or_default();
+                    markdown.code_blocks.push(CodeBlock {
+                        info: open.info,
+                        code,
+                        line: open.line,
+                    });
+                    fence = None;
+                }
+            } else if let Some(open) = Fence::open(line, index, next_line_start) {
+                fence = Some(open);
+            } else if let Some(heading) = Heading::parse(line, index) {
+                markdown.headings.push(heading);
+            } else if !is_indented_code(line) {
+                Link::parse_all(line, index, &mut markdown.links, &mut definitions);
+            }
+
+            line_start = next_line_start;
+        }
+
+        // Unclosed code blocks continue until the end of the documentation
+        if let Some(open) = fence {
+            markdown.code_blocks.push(CodeBlock {
+                info: open.info,
+                code: text.get(open.code_start..).unwrap_or_default(),
+                line: open.line,
+            });
+        }
+
+        for link in &mut markdown.links {
+            link.resolve(&definitions);
+        }
+
+        markdown
+    }
+
+    /// The headings, in the order they appear in the documentation.
+    #[must_use]
+    pub fn headings(&self) -> &[Heading<'a>] {
+        &self.headings
+    }
+
+    /// The fenced code blocks, in the order they appear in the documentation.
+    #[must_use]
+    pub fn code_blocks(&self) -> &[CodeBlock<'a>] {
+        &self.code_blocks
+    }
+
+    /// The links, in the order they appear in the documentation.
+    #[must_use]
+    pub fn links(&self) -> &[Link<'a>] {
+        &self.links
+    }
+
+    /// Returns `true`, if the documentation has a heading with the given text,
+    /// like `Panics` for `# Panics`. The level of the heading is ignored.
+    #[must_use]
+    pub fn has_heading(&self, text: &str) -> bool {
+        self.headings.iter().any(|heading| heading.text == text)
+    }
+}
+
+/// A Markdown heading, like `# Panics`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Heading<'a> {
+    level: u8,
+    text: &'a str,
+    line: usize,
+}
+
+impl<'a> Heading<'a> {
+    /// The level of the heading, this is the number of `#` characters.
+    #[must_use]
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// The text of the heading, like `Panics` for `# Panics`.
+    #[must_use]
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The index of the line, which contains this heading.
+    #[must_use]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    fn parse(line: &'a str, index: usize) -> Option<Self> {
+        let rest = strip_indent(line)?;
+        let level = rest.chars().take_while(|c| *c == '#').count();
+        if !(1..=6).contains(&level) {
+            return None;
+        }
+        let rest = &rest[level..];
+        if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+            return None;
+        }
+
+        // Closing sequences, like in `## Heading ##`, are not part of the text
+        let text = rest.trim();
+        let text = text.trim_end_matches('#');
+        let text = if text.is_empty() || text.ends_with([' ', '\t']) {
+            text.trim_end()
+        } else {
+            rest.trim()
+        };
+
+        Some(Self {
+            level: u8::try_from(level).ok()?,
+            text,
+            line: index,
+        })
+    }
+}
+
+/// A fenced code block, like:
+///
+/// ````text
+/// ```rust
+/// let x = 18;
+/// ```
+/// ````
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodeBlock<'a> {
+    info: &'a str,
+    code: &'a str,
+    line: usize,
+}
+
+impl<'a> CodeBlock<'a> {
+    /// The info string of the code block, like `rust,ignore` for ```` ```rust,ignore ````.
+    #[must_use]
+    pub fn info(&self) -> &'a str {
+        self.info
+    }
+
+    /// The language of the code block, this is the first tag of the info string.
+    /// This returns [`None`], if the info string is empty.
+    #[must_use]
+    pub fn lang(&self) -> Option<&'a str> {
+        self.tags().next()
+    }
+
+    /// Returns `true`, if rustdoc would treat this code block as Rust code. This
+    /// is the case for code blocks without a language and for code blocks which
+    /// only use rustdoc attributes, like `ignore` or `no_run`.
+    #[must_use]
+    pub fn is_rust(&self) -> bool {
+        self.tags().all(|tag| {
+            matches!(
+                tag,
+                "rust" | "ignore" | "should_panic" | "no_run" | "compile_fail" | "test_harness" | "standalone"
+            ) || tag.starts_with("edition")
+                || tag.starts_with("ignore-")
+        })
+    }
+
+    /// The code inside the code block, without the fences.
+    #[must_use]
+    pub fn code(&self) -> &'a str {
+        self.code
+    }
+
+    /// The index of the line, which contains the opening fence.
+    #[must_use]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    fn tags(&self) -> impl Iterator<Item = &'a str> {
+        self.info
+            .split([',', ' ', '\t'])
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+    }
+}
+
+/// A Markdown link, like `[text](target)`, `[text][reference]` or `` [`Item`] ``.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Link<'a> {
+    text: &'a str,
+    target: &'a str,
+    line: usize,
+    /// The label of reference and shortcut links, which is used to resolve
+    /// the target with the link reference definitions.
+    label: Option<&'a str>,
+}
+
+impl<'a> Link<'a> {
+    /// The text of the link, like `text` in `[text](target)`.
+    #[must_use]
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The target of the link, like `target` in `[text](target)`.
+    ///
+    /// Reference links, like `[text][reference]`, use the target of the matching
+    /// link reference definition, like `[reference]: target`. Without a definition,
+    /// the reference is used as the target. For shortcut links, like `` [`Item`] ``,
+    /// the target is the text without the backticks, unless a definition exists.
+    #[must_use]
+    pub fn target(&self) -> &'a str {
+        self.target
+    }
+
+    /// Returns `true`, if this is an intra-doc link, meaning that the target is
+    /// a path to an item, rather than a URL or an anchor.
+    #[must_use]
+    pub fn is_intra_doc(&self) -> bool {
+        // Links to other pages, like `struct.Vec.html#method.push`, are not intra-doc links
+        let page = self.target.split('#').next().unwrap_or_default();
+        !self.target.is_empty()
+            && !self.target.contains("://")
+            && !self.target.starts_with('#')
+            && !std::path::Path::new(page)
+                .extension()
+                .map_or(false, |ext| ext.eq_ignore_ascii_case("html"))
+    }
+
+    /// The index of the line, which contains this link.
+    #[must_use]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    fn parse_all(line: &'a str, index: usize, links: &mut Vec<Link<'a>>, definitions: &mut Vec<(&'a str, &'a str)>) {
+        // A link reference definition like `[label]: target`
+        if let Some(rest) = strip_indent(line).and_then(|rest| rest.strip_prefix('[')) {
+            if let Some((label, target)) = rest.split_once("]:") {
+                if !label.is_empty() && !label.contains(['[', ']']) {
+                    let target = target.split_whitespace().next().unwrap_or_default();
+                    definitions.push((label, target));
+                    return;
+                }
+            }
+        }
+
+        let bytes = line.as_bytes();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            match bytes[pos] {
+                b'\\' => pos += 2,
+                b'`' => pos = skip_code_span(line, pos),
+                b'[' => {
+                    let Some(text_end) = line[pos + 1..].find(']').map(|end| pos + 1 + end) else {
+                        return;
+                    };
+                    let text = &line[pos + 1..text_end];
+                    let after = &line[text_end + 1..];
+                    let (target, label, end) = if let Some(rest) = after.strip_prefix('(') {
+                        let Some(target_end) = rest.find(')') else {
+                            return;
+                        };
+                        let target = rest[..target_end].split_whitespace().next().unwrap_or_default();
+                        (target, None, text_end + 2 + target_end + 1)
+                    } else if let Some(rest) = after.strip_prefix('[') {
+                        let Some(reference_end) = rest.find(']') else {
+                            return;
+                        };
+                        let reference = &rest[..reference_end];
+                        let reference = if reference.is_empty() { text } else { reference };
+                        (
+                            reference.trim_matches('`'),
+                            Some(reference),
+                            text_end + 2 + reference_end + 1,
+                        )
+                    } else {
+                        (text.trim_matches('`'), Some(text), text_end + 1)
+                    };
+
+                    links.push(Link {
+                        text,
+                        target,
+                        line: index,
+                        label,
+                    });
+                    pos = end;
+                },
+                _ => pos += 1,
+            }
+        }
+    }
+
+    fn resolve(&mut self, definitions: &[(&'a str, &'a str)]) {
+        if let Some(label) = self.label {
+            if let Some((_, target)) = definitions
+                .iter()
+                .find(|(name, _)| name.trim().eq_ignore_ascii_case(label.trim()))
+            {
+                self.target = target;
+            }
+        }
+    }
+}
+
+struct Fence<'a> {
+    marker: char,
+    len: usize,
+    info: &'a str,
+    line: usize,
+    code_start: usize,
+}
+
+impl<'a> Fence<'a> {
+    fn open(line: &'a str, index: usize, code_start: usize) -> Option<Self> {
+        let rest = strip_indent(line)?;
+        let marker = rest.chars().next().filter(|c| *c == '`' || *c == '~')?;
+        let len = rest.chars().take_while(|c| *c == marker).count();
+        if len < 3 {
+            return None;
+        }
+        let info = rest[len..].trim();
+        if marker == '`' && info.contains('`') {
+            return None;
+        }
+
+        Some(Self {
+            marker,
+            len,
+            info,
+            line: index,
+            code_start,
+        })
+    }
+
+    fn is_closed_by(&self, line: &str) -> bool {
+        let Some(rest) = strip_indent(line) else {
+            return false;
+        };
+        let len = rest.chars().take_while(|c| *c == self.marker).count();
+        len >= self.len && rest[len..].trim().is_empty()
+    }
+}
+
+/// Markdown allows up to three spaces of indentation for headings and fences.
+fn strip_indent(line: &str) -> Option<&str> {
+    let rest = line.trim_start_matches(' ');
+    (line.len() - rest.len() <= 3).then_some(rest)
+}
+
+fn is_indented_code(line: &str) -> bool {
+    line.starts_with("    ") || line.starts_with('\t')
+}
+
+/// Returns the position after the code span starting at `start`. Code spans are
+/// delimited by backtick strings of equal length.
+fn skip_code_span(line: &str, start: usize) -> usize {
+    let len = line[start..].chars().take_while(|c| *c == '`').count();
+    let delimiter = &line[start..start + len];
+    let content_start = start + len;
+    match line[content_start..].find(delimiter) {
+        Some(end) => content_start + end + len,
+        // Unmatched backticks are just text
+        None => content_start,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_headings() {
+        let markdown = Markdown::parse("Text\n# Panics\n\n## Errors ##\n#NotAHeading\n    # Indented code");
+        let headings: Vec<_> = markdown
+            .headings()
+            .iter()
+            .map(|heading| (heading.level(), heading.text(), heading.line()))
+            .collect();
+        assert_eq!(headings, vec![(1, "Panics", 1), (2, "Errors", 3)]);
+        assert!(markdown.has_heading("Panics"));
+        assert!(!markdown.has_heading("Safety"));
+    }
+
+    #[test]
+    fn test_code_blocks() {
+        let markdown = Markdown::parse("```\nlet x = 1;\n# hidden\n```\n~~~text\nplain\n~~~\n```rust,ignore\nopen");
+        let blocks = markdown.code_blocks();
+        assert_eq!(blocks.len(), 3);
+
+        assert_eq!(blocks[0].code(), "let x = 1;\n# hidden");
+        assert_eq!(blocks[0].lang(), None);
+        assert!(blocks[0].is_rust());
+        assert_eq!(blocks[0].line(), 0);
+
+        assert_eq!(blocks[1].lang(), Some("text"));
+        assert!(!blocks[1].is_rust());
+        assert_eq!(blocks[1].code(), "plain");
+
+        assert_eq!(blocks[2].info(), "rust,ignore");
+        assert!(blocks[2].is_rust());
+        assert_eq!(blocks[2].code(), "open");
+
+        // Headings inside code blocks are ignored
+        assert!(markdown.headings().is_empty());
+    }
+
+    #[test]
+    fn test_links() {
+        let markdown = Markdown::parse(
+            "See [`Vec`], [docs](https://docs.rs) and [the item][`std::mem::take`].\n\
+            `[not_a_link]` \\[escaped] [book][] [Item]\n\
+            [Book]: https://doc.rust-lang.org/book/",
+        );
+        let links: Vec<_> = markdown
+            .links()
+            .iter()
+            .map(|link| (link.text(), link.target(), link.is_intra_doc(), link.line()))
+            .collect();
+        assert_eq!(
+            links,
+            vec![
+                ("`Vec`", "Vec", true, 0),
+                ("docs", "https://docs.rs", false, 0),
+                ("the item", "std::mem::take", true, 0),
+                ("book", "https://doc.rust-lang.org/book/", false, 1),
+                ("Item", "Item", true, 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Helpers to check which items are referenced by paths, calls and `use` items.
+//!
+//! All helpers take fully qualified paths, like `std::mem::transmute`. These
+//! paths are resolved by the driver, using [`AstContext::resolve_item_ids`]. This
+//! means that re-exports are handled transparently, `std::mem::transmute` and
+//! `core::intrinsics::transmute` refer to the same function. Associated items
+//! can be referenced through the type or trait they belong to, like
+//! `std::option::Option::unwrap` or `std::iter::Iterator::next`.
+//!
+//! ```ignore
+//! if is_call_to(cx, expr, "std::mem::transmute") {
+//!     // ...
+//! }
+//! ```
+//!
+//! The [`call_target`] function can be used, to check calls against a list of
+//! paths, like a configurable list of disallowed methods:
+//!
+//! ```ignore
+//! if let Some(id) = call_target(expr)
+//!     && disallowed.iter().any(|path| cx.resolve_item_ids(path).contains(&id))
+//! {
+//!     // ...
+//! }
+//! ```
+
+use marker_api::{
+    ast::{expr::ExprKind, item::UseItem, AstPathTarget, AstQPath, ItemId},
+    AstContext,
+};
+
+/// Returns `true`, if the given [`ItemId`] belongs to the item with the given
+/// path.
+#[must_use]
+pub fn is_item(cx: &AstContext<'_>, id: ItemId, target: &str) -> bool {
+    cx.resolve_item_ids(target).contains(&id)
+}
+
+/// Returns `true`, if the given path resolves to the item with the given path.
+#[must_use]
+pub fn is_path_to(cx: &AstContext<'_>, path: &AstQPath<'_>, target: &str) -> bool {
+    matches!(path.resolve(), AstPathTarget::Item(id) if is_item(cx, id, target))
+}
+
+/// Returns `true`, if the given expression is a [`PathExpr`], which resolves to
+/// the item with the given path.
+///
+/// [`PathExpr`]: marker_api::ast::expr::PathExpr
+#[must_use]
+pub fn is_expr_path_to(cx: &AstContext<'_>, expr: ExprKind<'_>, target: &str) -> bool {
+    matches!(expr, ExprKind::Path(path) if is_path_to(cx, path.path(), target))
+}
+
+/// Returns the [`ItemId`] of the function, which is called by the given
+/// expression. This works for [`CallExpr`]s, where the operand is a path to a
+/// function, and for [`MethodExpr`]s. For trait methods, the id of the method
+/// in the trait definition is returned.
+///
+/// [`CallExpr`]: marker_api::ast::expr::CallExpr
+/// [`MethodExpr`]: marker_api::ast::expr::MethodExpr
+#[must_use]
+pub fn call_target(expr: ExprKind<'_>) -> Option<ItemId> {
+    match expr {
+        ExprKind::Call(call) => match call.operand() {
+            ExprKind::Path(path) => match path.path().resolve() {
+                AstPathTarget::Item(id) => Some(id),
+                _ => None,
+            },
+            _ => None,
+        },
+        ExprKind::Method(method) => Some(method.resolve()),
+        _ => None,
+    }
+}
+
+/// Returns `true`, if the given expression calls the function with the given
+/// path. See [`call_target`] for the supported expressions.
+///
+/// Method calls are matched against the called method. A call to `clone()` on
+/// a `Vec` is therefore a call to `std::clone::Clone::clone`.
+#[must_use]
+pub fn is_call_to(cx: &AstContext<'_>, expr: ExprKind<'_>, target: &str) -> bool {
+    call_target(expr).is_some_and(|id| is_item(cx, id, target))
+}
+
+/// Returns `true`, if the given `use` item imports the item with the given path.
+#[must_use]
+pub fn is_use_of(cx: &AstContext<'_>, item: &UseItem<'_>, target: &str) -> bool {
+    item.resolve()
+        .iter()
+        .any(|res| matches!(res, AstPathTarget::Item(id) if is_item(cx, *id, target)))
+}
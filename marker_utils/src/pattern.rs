@@ -0,0 +1,378 @@
+//! Declarative patterns to match the shape of AST nodes.
+//!
+//! Lints often check for a specific shape of code, like a method call with a
+//! specific name. Written by hand, these checks turn into long chains of
+//! `if let` statements. The [`match_ast!`](crate::match_ast) macro allows lint
+//! authors to describe the shape with a pattern instead. The pattern is
+//! expanded to plain `if let` statements, meaning that it has no runtime cost.
+//!
+//! ```ignore
+//! use marker_utils::match_ast;
+//!
+//! // Matches `<expr>.unwrap()`
+//! match_ast!(cx, expr, method_call!(recv: _, "unwrap", []) => {
+//!     // `recv` is bound to the receiver expression
+//! });
+//!
+//! // Matches `std::mem::forget(<expr>)` and checks the type of the argument
+//! match_ast!(cx, expr, path!("std::mem::forget")(arg: ty!(_, "std::vec::Vec")) => {
+//!     // `arg` is bound to the argument expression
+//! });
+//! ```
+//!
+//! The macro evaluates to `Some(<body>)` if the pattern matched and to `None`
+//! otherwise. The [`matches_ast!`](crate::matches_ast) macro can be used, if
+//! only the match itself is relevant. It evaluates to a `bool`.
+//!
+//! ## Patterns
+//!
+//! Every pattern can be used on a specific kind of node. The following patterns
+//! can be used for all nodes:
+//!
+//! * `_`: Matches any node.
+//! * `name`: Matches any node and binds it to `name`.
+//! * `name: <pattern>`: Matches the given pattern and binds the node to `name`.
+//!
+//! Patterns for [`ExprKind`](marker_api::ast::expr::ExprKind):
+//!
+//! * `method_call!(<receiver>, "name", [<args>])`: A method call with the given method name.
+//! * `call!(<operand>, [<args>])`: A function call.
+//! * `path!("crate::path")`: A path expression, which resolves to the item with the given path.
+//! * `path!("crate::path")(<args>)`: A function call of the function with the given path.
+//! * `binary!(<left>, Op, <right>)`: A binary operation, where `Op` is the name of a
+//!   [`BinaryOpKind`](marker_api::ast::expr::BinaryOpKind) variant.
+//! * `unary!(Op, <expr>)`: A unary operation, where `Op` is the name of a
+//!   [`UnaryOpKind`](marker_api::ast::expr::UnaryOpKind) variant.
+//! * `borrow!(<expr>)`: A reference expression, like `&x` or `&mut x`.
+//! * `field!(<expr>, "name")`: A field access with the given field name.
+//! * `ty!(<pattern>, "crate::path")`: Matches the given pattern, if the semantic type of the
+//!   expression is the ADT with the given path. References are peeled before the type is checked.
+//!
+//! Patterns for [`PatKind`](marker_api::ast::pat::PatKind):
+//!
+//! * `ident_pat!(<ident>)`: An identifier pattern. The nested pattern is applied to the
+//!   [`IdentPat`](marker_api::ast::pat::IdentPat).
+//! * `ref_pat!(<pat>)`: A reference pattern, like `&x`.
+//! * `tuple_pat!(<pats>)`: A tuple pattern.
+//!
+//! Patterns for [`SynTyKind`](marker_api::ast::ty::SynTyKind):
+//!
+//! * `ref_ty!(<ty>)`: A reference type, like `&T`.
+//! * `slice_ty!(<ty>)`: A slice type, like `[T]`.
+//! * `tuple_ty!(<tys>)`: A tuple type.
+//! * `path_ty!("crate::path")`: A path type, which resolves to the item with the given path.
+//!
+//! Lists of nodes, like `[<args>]`, are separated by commas. A list can end
+//! with `..`, to allow additional nodes after the listed ones.
+
+use marker_api::{ast::ty::SemTyKind, AstContext};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use marker_api::ast::{
+        expr::{BinaryOpKind, ExprKind, UnaryOpKind},
+        pat::PatKind,
+        ty::SynTyKind,
+    };
+    pub use std::option::Option::{None, Some};
+
+    pub use super::is_ty_of;
+    pub use crate::paths::is_path_to;
+}
+
+/// Returns `true`, if the given type is the ADT with the given path, after
+/// references have been peeled.
+#[doc(hidden)]
+#[must_use]
+pub fn is_ty_of(cx: &AstContext<'_>, ty: SemTyKind<'_>, target: &str) -> bool {
+    matches!(ty.peel_refs(), SemTyKind::Adt(adt) if cx.resolve_ty_ids(target).contains(&adt.def_id()))
+}
+
+/// Matches a node against a pattern and evaluates the body with the bound
+/// captures. The macro evaluates to `Some(<body>)` if the pattern matched and
+/// to `None` otherwise. See the [module documentation](crate::pattern) for the
+/// supported patterns.
+///
+/// ```ignore
+/// match_ast!(cx, expr, method_call!(recv: _, "unwrap", []) => {
+///     // `recv` is bound to the receiver expression
+/// });
+/// ```
+#[macro_export]
+macro_rules! match_ast {
+    ($cx:expr, $node:expr, $($rest:tt)+) => {
+        $crate::__match_ast!(@top [$cx] [$node] [] $($rest)+)
+    };
+}
+
+/// Checks if a node matches a pattern. See the
+/// [module documentation](crate::pattern) for the supported patterns.
+///
+/// ```ignore
+/// if matches_ast!(cx, expr, method_call!(_, "unwrap", [])) {
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! matches_ast {
+    ($cx:expr, $node:expr, $($pat:tt)+) => {
+        $crate::match_ast!($cx, $node, $($pat)+ => {}).is_some()
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __match_ast {
+    // =============================================
+    // Entry: Collect the pattern until the `=>`
+    // =============================================
+    (@top [$cx:expr] [$node:expr] [$($pat:tt)*] => $body:block) => {{
+        let __cx = $cx;
+        let __node = $node;
+        $crate::__match_ast!(@pat __cx __node ($($pat)*) { $crate::pattern::__private::Some($body) })
+    }};
+    (@top [$cx:expr] [$node:expr] [$($pat:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::__match_ast!(@top [$cx] [$node] [$($pat)* $t] $($rest)*)
+    };
+
+    // =============================================
+    // Utility: Split the tokens at commas and pass
+    // the groups to the given callback
+    // =============================================
+    (@split [$($cb:tt)*] [$($done:tt)*] []) => {
+        $crate::__match_ast!(@$($cb)* $($done)*)
+    };
+    (@split [$($cb:tt)*] [$($done:tt)*] [$($cur:tt)+]) => {
+        $crate::__match_ast!(@$($cb)* $($done)* ($($cur)+))
+    };
+    (@split $cb:tt [$($done:tt)*] [$($cur:tt)*] , $($rest:tt)*) => {
+        $crate::__match_ast!(@split $cb [$($done)* ($($cur)*)] [] $($rest)*)
+    };
+    (@split $cb:tt $done:tt [$($cur:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::__match_ast!(@split $cb $done [$($cur)* $t] $($rest)*)
+    };
+
+    // =============================================
+    // Utility: Match a slice of nodes
+    // =============================================
+    (@slice $cx:ident $val:ident ($($pats:tt)*) $body:tt) => {
+        $crate::__match_ast!(@split [slice_items $cx $val $body] [] [] $($pats)*)
+    };
+    (@slice_items $cx:ident $val:ident $body:tt) => {
+        if $val.is_empty() { $body } else { $crate::pattern::__private::None }
+    };
+    (@slice_items $cx:ident $val:ident $body:tt (..)) => {
+        $body
+    };
+    (@slice_items $cx:ident $val:ident $body:tt ($($first:tt)*) $($rest:tt)*) => {
+        if let [__first, __rest @ ..] = $val {
+            let __first = *__first;
+            $crate::__match_ast!(@pat $cx __first ($($first)*) {
+                $crate::__match_ast!(@slice_items $cx __rest $body $($rest)*)
+            })
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+
+    // =============================================
+    // General patterns
+    // =============================================
+    (@pat $cx:ident $val:ident (_) $body:tt) => {
+        $body
+    };
+    (@pat $cx:ident $val:ident ($name:ident) $body:tt) => {{
+        let $name = $val;
+        $body
+    }};
+    (@pat $cx:ident $val:ident ($name:ident : $($pat:tt)+) $body:tt) => {{
+        let $name = $val;
+        $crate::__match_ast!(@pat $cx $val ($($pat)+) $body)
+    }};
+
+    // =============================================
+    // Expression patterns
+    // =============================================
+    (@pat $cx:ident $val:ident (method_call ! ($($args:tt)*)) $body:tt) => {
+        $crate::__match_ast!(@split [method_call $cx $val $body] [] [] $($args)*)
+    };
+    (@method_call $cx:ident $val:ident $body:tt ($($recv:tt)*) ($name:literal) ([$($args:tt)*])) => {
+        if let $crate::pattern::__private::ExprKind::Method(__call) = $val {
+            if __call.method().ident().name() == $name {
+                let __recv = __call.receiver();
+                let __args = __call.args();
+                $crate::__match_ast!(@pat $cx __recv ($($recv)*) {
+                    $crate::__match_ast!(@slice $cx __args ($($args)*) $body)
+                })
+            } else {
+                $crate::pattern::__private::None
+            }
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+    (@pat $cx:ident $val:ident (call ! ($($args:tt)*)) $body:tt) => {
+        $crate::__match_ast!(@split [call $cx $val $body] [] [] $($args)*)
+    };
+    (@pat $cx:ident $val:ident (path ! ($path:literal) ($($args:tt)*)) $body:tt) => {
+        $crate::__match_ast!(@call $cx $val $body (path!($path)) ([$($args)*]))
+    };
+    (@call $cx:ident $val:ident $body:tt ($($operand:tt)*) ([$($args:tt)*])) => {
+        if let $crate::pattern::__private::ExprKind::Call(__call) = $val {
+            let __operand = __call.operand();
+            let __args = __call.args();
+            $crate::__match_ast!(@pat $cx __operand ($($operand)*) {
+                $crate::__match_ast!(@slice $cx __args ($($args)*) $body)
+            })
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+    (@pat $cx:ident $val:ident (path ! ($path:literal)) $body:tt) => {
+        if let $crate::pattern::__private::ExprKind::Path(__path) = $val {
+            if $crate::pattern::__private::is_path_to($cx, __path.path(), $path) {
+                $body
+            } else {
+                $crate::pattern::__private::None
+            }
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+    (@pat $cx:ident $val:ident (binary ! ($($args:tt)*)) $body:tt) => {
+        $crate::__match_ast!(@split [binary $cx $val $body] [] [] $($args)*)
+    };
+    (@binary $cx:ident $val:ident $body:tt ($($left:tt)*) ($op:ident) ($($right:tt)*)) => {
+        if let $crate::pattern::__private::ExprKind::BinaryOp(__op) = $val {
+            if ::std::matches!(__op.kind(), $crate::pattern::__private::BinaryOpKind::$op) {
+                let __left = __op.left();
+                let __right = __op.right();
+                $crate::__match_ast!(@pat $cx __left ($($left)*) {
+                    $crate::__match_ast!(@pat $cx __right ($($right)*) $body)
+                })
+            } else {
+                $crate::pattern::__private::None
+            }
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+    (@pat $cx:ident $val:ident (unary ! ($op:ident , $($expr:tt)*)) $body:tt) => {
+        if let $crate::pattern::__private::ExprKind::UnaryOp(__op) = $val {
+            if ::std::matches!(__op.kind(), $crate::pattern::__private::UnaryOpKind::$op) {
+                let __expr = __op.expr();
+                $crate::__match_ast!(@pat $cx __expr ($($expr)*) $body)
+            } else {
+                $crate::pattern::__private::None
+            }
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+    (@pat $cx:ident $val:ident (borrow ! ($($expr:tt)*)) $body:tt) => {
+        if let $crate::pattern::__private::ExprKind::Ref(__ref) = $val {
+            let __expr = __ref.expr();
+            $crate::__match_ast!(@pat $cx __expr ($($expr)*) $body)
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+    (@pat $cx:ident $val:ident (field ! ($($args:tt)*)) $body:tt) => {
+        $crate::__match_ast!(@split [field $cx $val $body] [] [] $($args)*)
+    };
+    (@field $cx:ident $val:ident $body:tt ($($operand:tt)*) ($name:literal)) => {
+        if let $crate::pattern::__private::ExprKind::Field(__field) = $val {
+            if __field.field().name() == $name {
+                let __operand = __field.operand();
+                $crate::__match_ast!(@pat $cx __operand ($($operand)*) $body)
+            } else {
+                $crate::pattern::__private::None
+            }
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+    (@pat $cx:ident $val:ident (ty ! ($($args:tt)*)) $body:tt) => {
+        $crate::__match_ast!(@split [ty $cx $val $body] [] [] $($args)*)
+    };
+    (@ty $cx:ident $val:ident $body:tt ($($pat:tt)*) ($path:literal)) => {
+        if $crate::pattern::__private::is_ty_of($cx, $val.ty(), $path) {
+            $crate::__match_ast!(@pat $cx $val ($($pat)*) $body)
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+
+    // =============================================
+    // Pattern patterns
+    // =============================================
+    (@pat $cx:ident $val:ident (ident_pat ! ($($ident:tt)*)) $body:tt) => {
+        if let $crate::pattern::__private::PatKind::Ident(__ident) = $val {
+            $crate::__match_ast!(@pat $cx __ident ($($ident)*) $body)
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+    (@pat $cx:ident $val:ident (ref_pat ! ($($pat:tt)*)) $body:tt) => {
+        if let $crate::pattern::__private::PatKind::Ref(__ref) = $val {
+            let __pat = __ref.pattern();
+            $crate::__match_ast!(@pat $cx __pat ($($pat)*) $body)
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+    (@pat $cx:ident $val:ident (tuple_pat ! ($($pats:tt)*)) $body:tt) => {
+        if let $crate::pattern::__private::PatKind::Tuple(__tuple) = $val {
+            let __elements = __tuple.elements();
+            $crate::__match_ast!(@slice $cx __elements ($($pats)*) $body)
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+
+    // =============================================
+    // Type patterns
+    // =============================================
+    (@pat $cx:ident $val:ident (ref_ty ! ($($ty:tt)*)) $body:tt) => {
+        if let $crate::pattern::__private::SynTyKind::Ref(__ref) = $val {
+            let __ty = __ref.inner_ty();
+            $crate::__match_ast!(@pat $cx __ty ($($ty)*) $body)
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+    (@pat $cx:ident $val:ident (slice_ty ! ($($ty:tt)*)) $body:tt) => {
+        if let $crate::pattern::__private::SynTyKind::Slice(__slice) = $val {
+            let __ty = __slice.inner_ty();
+            $crate::__match_ast!(@pat $cx __ty ($($ty)*) $body)
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+    (@pat $cx:ident $val:ident (tuple_ty ! ($($tys:tt)*)) $body:tt) => {
+        if let $crate::pattern::__private::SynTyKind::Tuple(__tuple) = $val {
+            let __types = __tuple.types();
+            $crate::__match_ast!(@slice $cx __types ($($tys)*) $body)
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+    (@pat $cx:ident $val:ident (path_ty ! ($path:literal)) $body:tt) => {
+        if let $crate::pattern::__private::SynTyKind::Path(__path) = $val {
+            if $crate::pattern::__private::is_path_to($cx, __path.path(), $path) {
+                $body
+            } else {
+                $crate::pattern::__private::None
+            }
+        } else {
+            $crate::pattern::__private::None
+        }
+    };
+
+    // =============================================
+    // Fallback
+    // =============================================
+    (@pat $cx:ident $val:ident ($($pat:tt)*) $body:tt) => {
+        ::std::compile_error!(::std::concat!("unsupported AST pattern: `", ::std::stringify!($($pat)*), "`"))
+    };
+}
@@ -0,0 +1,420 @@
+//! A builder for code suggestions, which respects operator precedence.
+//!
+//! Suggestions are usually assembled from snippets of the linted code. Simply
+//! pasting these snippets into a new expression can change the meaning of the
+//! code. Replacing `a == b` with `!a == b` doesn't negate the comparison, it
+//! negates `a`. A [`Sugg`] remembers the precedence of the wrapped snippet and
+//! only adds parentheses, if they are actually needed:
+//!
+//! ```ignore
+//! let mut app = Applicability::MachineApplicable;
+//! let lhs = Sugg::expr(bin.left(), "<lhs>", &mut app);
+//! let rhs = Sugg::expr(bin.right(), "<rhs>", &mut app);
+//! // `(a + b).pow(2)` or `x.pow(2)`
+//! let sugg = lhs.bin_op(BinaryOpKind::Add, rhs).method_call("pow", &["2"]);
+//! diag.span_suggestion("try", expr.span(), sugg, app);
+//! ```
+//!
+//! Snippets, which originate from macro expansions, automatically lower the
+//! [`Applicability`] like [`Span::snippet_with_applicability`] does.
+//!
+//! [`Span::snippet_with_applicability`]: marker_api::ast::Span::snippet_with_applicability
+
+use std::{borrow::Cow, fmt::Display};
+
+use marker_api::{
+    ast::expr::{BinaryOpKind, ExprKind, ExprPrecedence},
+    diagnostic::Applicability,
+};
+
+/// The precedence level of postfix expressions, like `x?`, `x.field` or
+/// `x.method()`. Expressions with this level or higher can be used as the
+/// receiver of a method call, without parentheses.
+const POSTFIX_LEVEL: u8 = 0x0F;
+/// The precedence level of the unary operators `-`, `!`, `*` and `&`, and of
+/// block-like expressions.
+const UNARY_LEVEL: u8 = 0x0E;
+
+/// A code snippet with the precedence of the expression it represents. See the
+/// [module documentation](self) for more information.
+#[derive(Debug, Clone)]
+pub struct Sugg<'a> {
+    text: Cow<'a, str>,
+    precedence: ExprPrecedence,
+    /// `true`, if the snippet ends with the type of an `as` cast. A following
+    /// `<` or `<<` would be parsed as the start of generic arguments.
+    ends_with_cast: bool,
+}
+
+impl<'a> Sugg<'a> {
+    /// Creates a new [`Sugg`] from the snippet of the given expression. The
+    /// placeholder is used, if the snippet is unavailable. The [`Applicability`]
+    /// is adjusted accordingly, see [`Span::snippet_with_applicability`] for
+    /// more information.
+    ///
+    /// [`Span::snippet_with_applicability`]: marker_api::ast::Span::snippet_with_applicability
+    #[must_use]
+    pub fn expr<'ast: 'a>(expr: ExprKind<'ast>, placeholder: &'a str, applicability: &mut Applicability) -> Self {
+        let precedence = expr.precedence();
+        let text = expr.span().snippet_with_applicability(placeholder, applicability);
+        Self {
+            text: Cow::Borrowed(text),
+            precedence,
+            ends_with_cast: ends_with_cast(expr),
+        }
+    }
+
+    /// Creates a new [`Sugg`] from a snippet with the given precedence.
+    #[must_use]
+    pub fn new(text: impl Into<Cow<'a, str>>, precedence: ExprPrecedence) -> Self {
+        Self {
+            text: text.into(),
+            precedence,
+            ends_with_cast: matches!(precedence, ExprPrecedence::As),
+        }
+    }
+
+    /// Creates a new [`Sugg`] from a snippet, which never requires parentheses,
+    /// like a literal or a path.
+    #[must_use]
+    pub fn atom(text: impl Into<Cow<'a, str>>) -> Self {
+        Self::new(text, ExprPrecedence::Lit)
+    }
+
+    /// The precedence of this suggestion.
+    #[must_use]
+    pub fn precedence(&self) -> ExprPrecedence {
+        self.precedence
+    }
+
+    /// The code of this suggestion.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Wraps this suggestion in parentheses, unless it's a postfix expression or
+    /// an atom. This is useful, if the suggestion is inserted into code, which
+    /// isn't constructed with this builder.
+    #[must_use]
+    pub fn maybe_paren(self) -> Self {
+        if level(self.precedence) >= POSTFIX_LEVEL {
+            self
+        } else {
+            self.paren()
+        }
+    }
+
+    /// Dereferences this suggestion, like `*x`.
+    #[must_use]
+    pub fn deref(self) -> Sugg<'static> {
+        self.unary("*", ExprPrecedence::Deref)
+    }
+
+    /// Borrows this suggestion, like `&x`.
+    #[must_use]
+    pub fn addr(self) -> Sugg<'static> {
+        self.unary("&", ExprPrecedence::Ref)
+    }
+
+    /// Borrows this suggestion mutably, like `&mut x`.
+    #[must_use]
+    pub fn mut_addr(self) -> Sugg<'static> {
+        self.unary("&mut ", ExprPrecedence::Ref)
+    }
+
+    /// Combines this suggestion and `rhs` with the given binary operator, like
+    /// `a + b`.
+    #[must_use]
+    pub fn bin_op(self, op: BinaryOpKind, rhs: Sugg<'_>) -> Sugg<'static> {
+        let (op_str, precedence) = bin_op_info(op);
+        let op_level = level(precedence);
+        let is_comparison = matches!(precedence, ExprPrecedence::Comparison);
+
+        // Binary operators are left associative, except comparisons, which
+        // can't be chained at all.
+        let lhs = if level(self.precedence) < op_level
+            || (is_comparison && level(self.precedence) == op_level)
+            || (self.ends_with_cast && matches!(op, BinaryOpKind::Lesser | BinaryOpKind::Shl))
+        {
+            self.paren()
+        } else {
+            self
+        };
+        let rhs = if level(rhs.precedence) <= op_level {
+            rhs.paren()
+        } else {
+            rhs
+        };
+
+        Sugg {
+            text: Cow::Owned(format!("{lhs} {op_str} {rhs}")),
+            precedence,
+            ends_with_cast: rhs.ends_with_cast,
+        }
+    }
+
+    /// Casts this suggestion to the given type, like `x as u32`.
+    #[must_use]
+    pub fn cast(self, ty: impl Display) -> Sugg<'static> {
+        let lhs = if level(self.precedence) < level(ExprPrecedence::As) {
+            self.paren()
+        } else {
+            self
+        };
+        Sugg {
+            text: Cow::Owned(format!("{lhs} as {ty}")),
+            precedence: ExprPrecedence::As,
+            ends_with_cast: true,
+        }
+    }
+
+    /// Calls a method with the given arguments on this suggestion, like
+    /// `x.method(a, b)`. The arguments are inserted as is.
+    #[must_use]
+    pub fn method_call(self, name: &str, args: &[impl Display]) -> Sugg<'static> {
+        let recv = self.maybe_paren();
+        let args = args.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+        Sugg {
+            text: Cow::Owned(format!("{recv}.{name}({args})")),
+            precedence: ExprPrecedence::Method,
+            ends_with_cast: false,
+        }
+    }
+
+    /// Accesses a field of this suggestion, like `x.field`.
+    #[must_use]
+    pub fn field(self, name: &str) -> Sugg<'static> {
+        let recv = self.maybe_paren();
+        Sugg {
+            text: Cow::Owned(format!("{recv}.{name}")),
+            precedence: ExprPrecedence::Field,
+            ends_with_cast: false,
+        }
+    }
+
+    fn unary(self, op_str: &str, precedence: ExprPrecedence) -> Sugg<'static> {
+        let operand = if level(self.precedence) < UNARY_LEVEL {
+            self.paren()
+        } else {
+            self
+        };
+        Sugg {
+            text: Cow::Owned(format!("{op_str}{operand}")),
+            precedence,
+            ends_with_cast: operand.ends_with_cast,
+        }
+    }
+
+    fn paren(self) -> Self {
+        Self {
+            text: Cow::Owned(format!("({})", self.text)),
+            precedence: ExprPrecedence::Lit,
+            ends_with_cast: false,
+        }
+    }
+}
+
+impl std::ops::Not for Sugg<'_> {
+    type Output = Sugg<'static>;
+
+    /// Negates this suggestion with the `!` operator, like `!x`.
+    fn not(self) -> Self::Output {
+        self.unary("!", ExprPrecedence::Not)
+    }
+}
+
+impl std::ops::Neg for Sugg<'_> {
+    type Output = Sugg<'static>;
+
+    /// Negates this suggestion with the unary `-` operator, like `-x`.
+    fn neg(self) -> Self::Output {
+        self.unary("-", ExprPrecedence::Neg)
+    }
+}
+
+impl Display for Sugg<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Returns `true`, if the code of the given expression ends with the type of
+/// an `as` cast, like `x + a as u8`. This follows the rightmost operand of
+/// operators without closing delimiters.
+fn ends_with_cast(expr: ExprKind<'_>) -> bool {
+    match expr {
+        ExprKind::As(_) => true,
+        ExprKind::BinaryOp(bin) => ends_with_cast(bin.right()),
+        ExprKind::UnaryOp(unary) => ends_with_cast(unary.expr()),
+        ExprKind::Ref(re) => ends_with_cast(re.expr()),
+        _ => false,
+    }
+}
+
+/// Returns the precedence level of the given [`ExprPrecedence`]. Higher levels
+/// bind stronger.
+fn level(precedence: ExprPrecedence) -> u8 {
+    match precedence {
+        ExprPrecedence::Lit | ExprPrecedence::Ctor | ExprPrecedence::Await => 0x14,
+        ExprPrecedence::Path => 0x13,
+        ExprPrecedence::Method | ExprPrecedence::Call | ExprPrecedence::Let => 0x12,
+        ExprPrecedence::Field => 0x11,
+        ExprPrecedence::Fn | ExprPrecedence::Index => 0x10,
+        ExprPrecedence::QuestionMark => POSTFIX_LEVEL,
+        // Block-like expressions are complete operands, but a following `.`
+        // would end them at the start of a statement, like in `if a { b } else
+        // { c }.len()`. They therefore require parentheses as receivers.
+        ExprPrecedence::Neg
+        | ExprPrecedence::Not
+        | ExprPrecedence::Deref
+        | ExprPrecedence::Ref
+        | ExprPrecedence::Block
+        | ExprPrecedence::If
+        | ExprPrecedence::Match
+        | ExprPrecedence::For
+        | ExprPrecedence::Loop
+        | ExprPrecedence::While => UNARY_LEVEL,
+        ExprPrecedence::As => 0x0D,
+        ExprPrecedence::Mul | ExprPrecedence::Div | ExprPrecedence::Rem => 0x0C,
+        ExprPrecedence::Add | ExprPrecedence::Sub => 0x0B,
+        ExprPrecedence::Shr | ExprPrecedence::Shl => 0x0A,
+        ExprPrecedence::BitAnd => 0x09,
+        ExprPrecedence::BitXor => 0x08,
+        ExprPrecedence::BitOr => 0x07,
+        ExprPrecedence::Comparison => 0x06,
+        ExprPrecedence::And => 0x05,
+        ExprPrecedence::Or => 0x04,
+        ExprPrecedence::Range => 0x03,
+        // Assignments are listed with the atoms, but they bind as weak as
+        // compound assignments.
+        ExprPrecedence::Assign | ExprPrecedence::AssignOp => 0x02,
+        ExprPrecedence::Closure | ExprPrecedence::Break | ExprPrecedence::Return | ExprPrecedence::Continue => 0x01,
+        // The precedence of unstable expressions is unknown. Treating them
+        // as the weakest ensures that they're always wrapped in parentheses.
+        _ => 0x00,
+    }
+}
+
+fn bin_op_info(op: BinaryOpKind) -> (&'static str, ExprPrecedence) {
+    match op {
+        BinaryOpKind::Mul => ("*", ExprPrecedence::Mul),
+        BinaryOpKind::Div => ("/", ExprPrecedence::Div),
+        BinaryOpKind::Rem => ("%", ExprPrecedence::Rem),
+        BinaryOpKind::Add => ("+", ExprPrecedence::Add),
+        BinaryOpKind::Sub => ("-", ExprPrecedence::Sub),
+        BinaryOpKind::Shr => (">>", ExprPrecedence::Shr),
+        BinaryOpKind::Shl => ("<<", ExprPrecedence::Shl),
+        BinaryOpKind::BitAnd => ("&", ExprPrecedence::BitAnd),
+        BinaryOpKind::BitXor => ("^", ExprPrecedence::BitXor),
+        BinaryOpKind::BitOr => ("|", ExprPrecedence::BitOr),
+        BinaryOpKind::Eq => ("==", ExprPrecedence::Comparison),
+        BinaryOpKind::NotEq => ("!=", ExprPrecedence::Comparison),
+        BinaryOpKind::Greater => (">", ExprPrecedence::Comparison),
+        BinaryOpKind::GreaterEq => (">=", ExprPrecedence::Comparison),
+        BinaryOpKind::Lesser => ("<", ExprPrecedence::Comparison),
+        BinaryOpKind::LesserEq => ("<=", ExprPrecedence::Comparison),
+        BinaryOpKind::And => ("&&", ExprPrecedence::And),
+        BinaryOpKind::Or => ("||", ExprPrecedence::Or),
+        _ => unreachable!("unknown binary operator `{op:?}`"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sugg(text: &str, precedence: ExprPrecedence) -> Sugg<'_> {
+        Sugg::new(text, precedence)
+    }
+
+    #[test]
+    fn test_unary() {
+        assert_eq!((!sugg("a == b", ExprPrecedence::Comparison)).as_str(), "!(a == b)");
+        assert_eq!((!Sugg::atom("a")).as_str(), "!a");
+        assert_eq!((-sugg("a.len()", ExprPrecedence::Method)).as_str(), "-a.len()");
+        assert_eq!(sugg("*a", ExprPrecedence::Deref).deref().as_str(), "**a");
+        assert_eq!(sugg("a as u8", ExprPrecedence::As).addr().as_str(), "&(a as u8)");
+        assert_eq!(Sugg::atom("a").mut_addr().as_str(), "&mut a");
+    }
+
+    #[test]
+    fn test_bin_op() {
+        let add = || sugg("a + b", ExprPrecedence::Add);
+        assert_eq!(add().bin_op(BinaryOpKind::Mul, Sugg::atom("c")).as_str(), "(a + b) * c");
+        assert_eq!(Sugg::atom("c").bin_op(BinaryOpKind::Sub, add()).as_str(), "c - (a + b)");
+        assert_eq!(add().bin_op(BinaryOpKind::Add, Sugg::atom("c")).as_str(), "a + b + c");
+        assert_eq!(add().bin_op(BinaryOpKind::Eq, Sugg::atom("c")).as_str(), "a + b == c");
+
+        let cmp = || sugg("a < b", ExprPrecedence::Comparison);
+        assert_eq!(cmp().bin_op(BinaryOpKind::Eq, Sugg::atom("c")).as_str(), "(a < b) == c");
+        assert_eq!(cmp().bin_op(BinaryOpKind::And, cmp()).as_str(), "a < b && a < b");
+    }
+
+    #[test]
+    fn test_cast() {
+        assert_eq!(
+            sugg("a + b", ExprPrecedence::Add).cast("u64").as_str(),
+            "(a + b) as u64"
+        );
+        assert_eq!(sugg("-a", ExprPrecedence::Neg).cast("u64").as_str(), "-a as u64");
+        assert_eq!(Sugg::atom("a").cast("u8").cast("u16").as_str(), "a as u8 as u16");
+
+        let cast = || Sugg::atom("a").cast("u8");
+        assert_eq!(
+            cast().bin_op(BinaryOpKind::Lesser, Sugg::atom("b")).as_str(),
+            "(a as u8) < b"
+        );
+        assert_eq!(
+            cast().bin_op(BinaryOpKind::Shl, Sugg::atom("b")).as_str(),
+            "(a as u8) << b"
+        );
+        assert_eq!(
+            cast().bin_op(BinaryOpKind::Greater, Sugg::atom("b")).as_str(),
+            "a as u8 > b"
+        );
+        let sum = Sugg::atom("x").bin_op(BinaryOpKind::Add, cast());
+        assert_eq!(
+            sum.bin_op(BinaryOpKind::Lesser, Sugg::atom("b")).as_str(),
+            "(x + a as u8) < b"
+        );
+    }
+
+    #[test]
+    fn test_postfix() {
+        let args: &[&str] = &["2"];
+        assert_eq!(
+            sugg("a + b", ExprPrecedence::Add).method_call("pow", args).as_str(),
+            "(a + b).pow(2)"
+        );
+        assert_eq!(
+            sugg("a?", ExprPrecedence::QuestionMark)
+                .method_call("pow", args)
+                .as_str(),
+            "a?.pow(2)"
+        );
+        assert_eq!(sugg("*a", ExprPrecedence::Deref).field("b").as_str(), "(*a).b");
+        assert_eq!(
+            Sugg::atom("a").field("b").method_call("c", &[] as &[&str]).as_str(),
+            "a.b.c()"
+        );
+        assert_eq!(sugg("0..10", ExprPrecedence::Range).maybe_paren().as_str(), "(0..10)");
+        assert_eq!(sugg("a.b", ExprPrecedence::Field).maybe_paren().as_str(), "a.b");
+
+        let if_expr = || sugg("if a { b } else { c }", ExprPrecedence::If);
+        assert_eq!(
+            if_expr().method_call("len", &[] as &[&str]).as_str(),
+            "(if a { b } else { c }).len()"
+        );
+        assert_eq!(sugg("{ a }", ExprPrecedence::Block).field("b").as_str(), "({ a }).b");
+        assert_eq!(
+            sugg("match a { _ => b }", ExprPrecedence::Match).field("c").as_str(),
+            "(match a { _ => b }).c"
+        );
+        assert_eq!((!if_expr()).as_str(), "!if a { b } else { c }");
+        assert_eq!(
+            Sugg::atom("x").bin_op(BinaryOpKind::Add, if_expr()).as_str(),
+            "x + if a { b } else { c }"
+        );
+    }
+}
@@ -96,7 +96,11 @@ pub fn traverse_item<'ast, B>(
                 traverse_item(cx, visitor, ext_item.as_item())?;
             }
         },
-        ItemKind::ExternCrate(_) | ItemKind::Use(_) | ItemKind::Unstable(_) | ItemKind::TyAlias(_) => {
+        ItemKind::ExternCrate(_)
+        | ItemKind::Use(_)
+        | ItemKind::Unstable(_)
+        | ItemKind::TyAlias(_)
+        | ItemKind::ForeignTy(_) => {
             // These items have no sub nodes, which are visited by this visitor
         },
         _ => unreachable!("all items are covered"),